@@ -14,11 +14,14 @@ use crate::{
         auth::{Login, Register},
         discord_callback::DiscordCallback,
         events::Events,
+        github_callback::GitHubCallback,
         google_callback::GoogleCallback,
         home::Home,
         layout::AppLayout,
         learn::Learn,
+        magic_link::MagicLinkLogin,
         microsoft_callback::MicrosoftCallback,
+        workos_callback::WorkosCallback,
     },
     server_functions::auth::fetch_me,
 };
@@ -77,6 +80,9 @@ pub fn App() -> impl IntoView {
                     <Route path=path!("/auth/callback/google") view=GoogleCallback/>
                     <Route path=path!("/auth/callback/discord") view=DiscordCallback/>
                     <Route path=path!("/auth/callback/microsoft") view=MicrosoftCallback/>
+                    <Route path=path!("/auth/callback/github") view=GitHubCallback/>
+                    <Route path=path!("/auth/callback/workos") view=WorkosCallback/>
+                    <Route path=path!("/auth/magic-link") view=MagicLinkLogin/>
                     <Route path=WildcardSegment("any") view=NotFound/>
                 </Routes>
             </main>
@@ -1,38 +1,38 @@
+use crate::auth::password::{argon2_from_env, hash_password};
 use crate::errors::auth::AuthError;
-use crate::models::auth::LoginFormData;
-use crate::models::user::{Identifier, User, UserIdentifierWithUser};
+use crate::models::auth::{LoginFormData, validate_identifier_uniqueness};
+use crate::models::user::{
+    CreateUserIdentifier, Identifier, User, UserIdentifierWithUser, normalize_mobile,
+};
 use crate::models::{auth::RegistrationFormData, user::CreateUser};
 use anyhow::{Context, Result, anyhow};
-use argon2::{
-    Argon2,
-    password_hash::{PasswordHasher, PasswordVerifier, SaltString},
-};
+use argon2::password_hash::PasswordVerifier;
 use garde::Validate;
-use rand::rngs::OsRng;
+use serde::Deserialize;
 use surrealdb::engine::remote::ws::Client;
 use surrealdb::{RecordId, Surreal};
 
+#[derive(Debug, Deserialize)]
+struct LinkedIdentifier {
+    id: RecordId,
+    identifier_value: String,
+}
+
 pub async fn register_user(form: RegistrationFormData, db: &Surreal<Client>) -> Result<RecordId> {
     form.validate()
         .map_err(AuthError::InvalidData)
         .with_context(|| "The form validation for registration failed")?;
     form.validate_uniqueness(db).await?;
 
-    let password_bytes = form.password.as_bytes();
-    let salt = SaltString::generate(&mut OsRng);
-    let argon2 = Argon2::default();
-
-    let password_hash = argon2
-        .hash_password(password_bytes, &salt)
-        .map_err(AuthError::PasswordHashError)?;
-    let password_hash_str = password_hash.to_string();
+    let password_hash = hash_password(&form.password).map_err(AuthError::PasswordHashError)?;
 
     let user = CreateUser {
         display_name: form.name,
-        password_hash: password_hash_str,
+        password_hash,
+        verified: false,
     };
 
-    let identifier_data = form.identifier;
+    let identifier_data = form.identifier.normalized();
 
     let surql = r#"
             BEGIN TRANSACTION;
@@ -69,8 +69,11 @@ pub async fn register_user(form: RegistrationFormData, db: &Surreal<Client>) ->
 pub async fn authenticate(form: LoginFormData, db: &Surreal<Client>) -> Result<RecordId> {
     let (identifier_type, identifier_value) = match form.identifier {
         Identifier::Email(email) => ("email", email),
-        Identifier::Mobile(mobile) => ("mobile", mobile),
-        Identifier::Google(_) | Identifier::Meta(_) | Identifier::Instagram(_) => {
+        Identifier::Mobile(mobile) => ("mobile", normalize_mobile(&mobile)),
+        Identifier::Google(_)
+        | Identifier::Meta(_)
+        | Identifier::Instagram(_)
+        | Identifier::Workos(_) => {
             return Err(anyhow!(AuthError::UserNotFound));
         }
     };
@@ -97,11 +100,175 @@ pub async fn authenticate(form: LoginFormData, db: &Surreal<Client>) -> Result<R
     let parsed_hash = argon2::password_hash::PasswordHash::new(&requested_user.password_hash)
         .map_err(AuthError::PasswordHashError)?;
 
-    let argon2 = Argon2::default();
-    argon2
+    // The hash carries the parameters it was made with, so the current settings only matter
+    // for new hashes.
+    argon2_from_env()
         .verify_password(form.password.as_bytes(), &parsed_hash)
         .map_err(AuthError::PasswordVerificationError)
         .with_context(|| "Password verification failed")?;
 
     Ok(requested_user.id)
 }
+
+/// Replaces the password of `user` once `current_password` checks out against the stored
+/// hash. Every session other than `current_session_token` is ended so that other devices
+/// have to log in again with the new password.
+pub async fn change_password(
+    user: &User,
+    current_password: &str,
+    new_password: &str,
+    current_session_token: &str,
+    db: &Surreal<Client>,
+) -> Result<()> {
+    verify_user_password(user, current_password)?;
+
+    let password_hash = hash_password(new_password).map_err(AuthError::PasswordHashError)?;
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            UPDATE $user SET password_hash = $password_hash, updated_at = time::now();
+            DELETE sessions WHERE user = $user AND session_token != $session_token;
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("user", user.id.clone()))
+    .bind(("password_hash", password_hash))
+    .bind(("session_token", current_session_token.to_string()))
+    .await
+    .map_err(|e| AuthError::DatabaseError(Box::new(e)))
+    .with_context(|| "Failed to update the password")?
+    .check()
+    .map_err(|e| AuthError::DatabaseError(Box::new(e)))
+    .with_context(|| "Failed to update the password")?;
+
+    Ok(())
+}
+
+/// Deletes the account of `user` once `password` checks out against the stored hash. The
+/// user's identifiers, sessions and pending tokens go with it, as do the `favorited`,
+/// `attending` and `handles` edges starting at the user.
+pub async fn delete_account(user: &User, password: &str, db: &Surreal<Client>) -> Result<()> {
+    verify_user_password(user, password)?;
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            DELETE mobile_otps WHERE identifier.user = $user;
+            DELETE user_identifier WHERE user = $user;
+            DELETE sessions, password_resets, verification_tokens, magic_links
+                WHERE user = $user;
+            DELETE favorited, attending, handles WHERE in = $user;
+            DELETE $user;
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("user", user.id.clone()))
+    .await
+    .map_err(|e| AuthError::DatabaseError(Box::new(e)))
+    .with_context(|| "Failed to delete the account")?
+    .check()
+    .map_err(|e| AuthError::DatabaseError(Box::new(e)))
+    .with_context(|| "Failed to delete the account")?;
+
+    Ok(())
+}
+
+/// Links another email or mobile `identifier` to `user`, so either one can be used to log in.
+/// A user can have only one identifier of each type.
+pub async fn add_identifier(
+    user: &User,
+    identifier: Identifier,
+    db: &Surreal<Client>,
+) -> Result<()> {
+    identifier.validate().map_err(AuthError::InvalidData)?;
+    let identifier_type = match &identifier {
+        Identifier::Email(_) => "email",
+        Identifier::Mobile(_) => "mobile",
+        _ => return Err(AuthError::OAuthIdentifier.into()),
+    };
+    validate_identifier_uniqueness(&identifier, db).await?;
+
+    let existing: Vec<serde_json::Value> = db
+        .query("SELECT id FROM user_identifier WHERE user = $user AND identifier_type = $type")
+        .bind(("user", user.id.clone()))
+        .bind(("type", identifier_type))
+        .await
+        .map_err(|e| AuthError::DatabaseError(Box::new(e)))?
+        .take(0)
+        .map_err(|e| AuthError::DatabaseError(Box::new(e)))?;
+    if !existing.is_empty() {
+        return Err(AuthError::IdentifierTypeTaken(identifier_type.to_string()).into());
+    }
+
+    db.query("CREATE user_identifier CONTENT $identifier")
+        .bind((
+            "identifier",
+            CreateUserIdentifier {
+                identifier: identifier.normalized(),
+                user: user.id.clone(),
+            },
+        ))
+        .await
+        .map_err(|e| AuthError::DatabaseError(Box::new(e)))
+        .with_context(|| "Failed to add the identifier")?
+        .check()
+        .map_err(|e| AuthError::DatabaseError(Box::new(e)))
+        .with_context(|| "Failed to add the identifier")?;
+
+    Ok(())
+}
+
+/// Unlinks the identifier with `identifier_value` from `user`, along with any code pending
+/// for it. The last identifier of a user is kept, as they could not log in without it.
+pub async fn remove_identifier(
+    user: &User,
+    identifier_value: &str,
+    db: &Surreal<Client>,
+) -> Result<()> {
+    let identifiers: Vec<LinkedIdentifier> = db
+        .query("SELECT id, identifier_value FROM user_identifier WHERE user = $user")
+        .bind(("user", user.id.clone()))
+        .await
+        .map_err(|e| AuthError::DatabaseError(Box::new(e)))?
+        .take(0)
+        .map_err(|e| AuthError::DatabaseError(Box::new(e)))?;
+
+    let identifier = identifiers
+        .iter()
+        .find(|identifier| identifier.identifier_value == identifier_value)
+        .ok_or(AuthError::IdentifierNotFound)?;
+    if identifiers.len() == 1 {
+        return Err(AuthError::LastIdentifier.into());
+    }
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            DELETE mobile_otps WHERE identifier = $identifier;
+            DELETE $identifier;
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("identifier", identifier.id.clone()))
+    .await
+    .map_err(|e| AuthError::DatabaseError(Box::new(e)))
+    .with_context(|| "Failed to remove the identifier")?
+    .check()
+    .map_err(|e| AuthError::DatabaseError(Box::new(e)))
+    .with_context(|| "Failed to remove the identifier")?;
+
+    Ok(())
+}
+
+fn verify_user_password(user: &User, password: &str) -> Result<()> {
+    let parsed_hash = argon2::password_hash::PasswordHash::new(&user.password_hash)
+        .map_err(AuthError::PasswordHashError)?;
+
+    argon2_from_env()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .map_err(AuthError::PasswordVerificationError)
+        .with_context(|| "Password verification failed")?;
+
+    Ok(())
+}
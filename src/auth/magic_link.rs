@@ -0,0 +1,104 @@
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client, sql::Datetime};
+
+use crate::{
+    database::expiry::Expirable, errors::magic_link::MagicLinkError, models::redacted::Redacted,
+    services::notifier::Notifier, utils::token_generator::generate_token,
+};
+
+pub const MAGIC_LINK_TTL_IN_MINUTES: i64 = 15;
+
+#[derive(Debug, Serialize)]
+struct CreateMagicLink {
+    user: RecordId,
+    token: Redacted<String>,
+    expires_at: Datetime,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MagicLink {
+    user: RecordId,
+    expires_at: Datetime,
+}
+
+impl Expirable for MagicLink {
+    fn expires_at(&self) -> &Datetime {
+        &self.expires_at
+    }
+}
+
+/// Emails a one-time login link to the account registered with `email`, replacing any link
+/// sent to it earlier. Unknown emails are ignored so the endpoint can't be used to probe
+/// for accounts.
+pub async fn request_magic_link(
+    email: &str,
+    link_url: &str,
+    notifier: &dyn Notifier,
+    db: &Surreal<Client>,
+) -> Result<(), MagicLinkError> {
+    let user: Option<RecordId> = db
+        .query(
+            "SELECT VALUE user FROM user_identifier
+                WHERE identifier_type = 'email' AND identifier_value = $email",
+        )
+        .bind(("email", email.to_string()))
+        .await?
+        .take(0)?;
+    let Some(user) = user else {
+        return Ok(());
+    };
+
+    let token = generate_token();
+    let magic_link = CreateMagicLink {
+        user: user.clone(),
+        token: token.clone().into(),
+        expires_at: Datetime::from(Utc::now() + Duration::minutes(MAGIC_LINK_TTL_IN_MINUTES)),
+    };
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            DELETE magic_links WHERE user = $user;
+            CREATE magic_links CONTENT $magic_link;
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("user", user))
+    .bind(("magic_link", magic_link))
+    .await?
+    .check()?;
+
+    let body = format!(
+        "Use this link to log in to Merzah: {}?token={}\n\nIt expires in {} minutes and works only once.",
+        link_url, token, MAGIC_LINK_TTL_IN_MINUTES
+    );
+    notifier
+        .send_email(email, "Your Merzah login link", &body)
+        .await?;
+
+    Ok(())
+}
+
+/// Redeems a login link and returns the user it was issued to. The link is deleted in the
+/// same statement that reads it, so it can only ever be redeemed once.
+pub async fn consume_magic_link(
+    token: &str,
+    db: &Surreal<Client>,
+) -> Result<RecordId, MagicLinkError> {
+    let magic_links: Vec<MagicLink> = db
+        .query("DELETE magic_links WHERE token = $token RETURN BEFORE")
+        .bind(("token", token.to_string()))
+        .await?
+        .take(0)?;
+    let magic_link = magic_links
+        .into_iter()
+        .next()
+        .ok_or(MagicLinkError::InvalidToken)?;
+
+    if magic_link.is_expired() {
+        return Err(MagicLinkError::LinkExpired);
+    }
+
+    Ok(magic_link.user)
+}
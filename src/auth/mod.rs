@@ -1,6 +1,14 @@
 #[cfg(feature = "ssr")]
 pub mod custom_auth;
 #[cfg(feature = "ssr")]
+pub mod magic_link;
+#[cfg(feature = "ssr")]
 pub mod oauth;
 #[cfg(feature = "ssr")]
+pub mod password;
+#[cfg(feature = "ssr")]
+pub mod password_reset;
+#[cfg(feature = "ssr")]
 pub mod session;
+#[cfg(feature = "ssr")]
+pub mod verification;
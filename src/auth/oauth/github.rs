@@ -0,0 +1,133 @@
+use serde::Deserialize;
+
+use crate::auth::oauth::provider::{OAuthProvider, ProviderUser};
+use crate::errors::oauth::{OAuthError, OAuthResult};
+
+/// GitHub rejects API requests without a user agent.
+const USER_AGENT: &str = "merzah";
+
+#[derive(Debug, Deserialize)]
+struct GitHubUser {
+    id: i64,
+    login: String,
+    name: Option<String>,
+    avatar_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitHubEmail {
+    pub email: String,
+    pub primary: bool,
+    pub verified: bool,
+}
+
+/// Picks the user's primary email, provided GitHub has verified it.
+pub fn primary_verified_email(emails: Vec<GitHubEmail>) -> OAuthResult<String> {
+    emails
+        .into_iter()
+        .find(|email| email.primary && email.verified)
+        .map(|email| email.email)
+        .ok_or(OAuthError::InvalidResponse)
+}
+
+pub struct GitHubProvider;
+
+impl GitHubProvider {
+    pub fn new() -> Self {
+        Self
+    }
+
+    async fn get_json<T: for<'de> Deserialize<'de>>(
+        &self,
+        url: &str,
+        access_token: &str,
+    ) -> OAuthResult<T> {
+        let client = reqwest::Client::new();
+
+        let response = client
+            .get(url)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", USER_AGENT)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(OAuthError::InvalidResponse);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| OAuthError::ParseError(e.to_string()))
+    }
+}
+
+impl Default for GitHubProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OAuthProvider for GitHubProvider {
+    fn provider_name(&self) -> &str {
+        "github"
+    }
+
+    fn identifier_type(&self) -> &str {
+        "github"
+    }
+
+    fn client_id(&self) -> OAuthResult<String> {
+        std::env::var("GITHUB_CLIENT_ID")
+            .map_err(|_| OAuthError::MissingEnvVar("GITHUB_CLIENT_ID".to_string()))
+    }
+
+    fn client_secret(&self) -> OAuthResult<String> {
+        std::env::var("GITHUB_CLIENT_SECRET")
+            .map_err(|_| OAuthError::MissingEnvVar("GITHUB_CLIENT_SECRET".to_string()))
+    }
+
+    fn redirect_uri(&self) -> OAuthResult<String> {
+        std::env::var("GITHUB_REDIRECT_URI")
+            .map_err(|_| OAuthError::MissingEnvVar("GITHUB_REDIRECT_URI".to_string()))
+    }
+
+    fn tenant_id(&self) -> OAuthResult<String> {
+        Ok(String::new())
+    }
+
+    fn authorization_endpoint(&self) -> String {
+        "https://github.com/login/oauth/authorize".to_string()
+    }
+
+    fn token_endpoint(&self) -> String {
+        "https://github.com/login/oauth/access_token".to_string()
+    }
+
+    fn userinfo_endpoint(&self) -> String {
+        "https://api.github.com/user".to_string()
+    }
+
+    fn scopes(&self) -> String {
+        "read:user user:email".to_string()
+    }
+
+    /// The profile only carries the email when the user made it public, so the verified
+    /// primary email is always read from the emails endpoint.
+    async fn get_user_info(&self, access_token: &str) -> OAuthResult<ProviderUser> {
+        let github_user: GitHubUser = self
+            .get_json(&self.userinfo_endpoint(), access_token)
+            .await?;
+        let emails: Vec<GitHubEmail> = self
+            .get_json("https://api.github.com/user/emails", access_token)
+            .await?;
+
+        Ok(ProviderUser {
+            id: github_user.id.to_string(),
+            email: primary_verified_email(emails)?,
+            name: github_user.name.or(Some(github_user.login)),
+            picture: github_user.avatar_url,
+        })
+    }
+}
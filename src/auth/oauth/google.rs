@@ -1,143 +1,109 @@
-use surrealdb::engine::remote::ws::Client;
-use surrealdb::{RecordId, Surreal};
-
+use crate::auth::oauth::provider::{OAuthProvider, ProviderUser};
 use crate::errors::oauth::{OAuthError, OAuthResult};
-use crate::models::oauth::{GoogleTokenResponse, GoogleUser};
-use crate::models::user::{CreateUser, User, UserIdentifier};
-use crate::utils::token_generator::generate_token;
-
-pub fn get_authorization_url(state: &str) -> OAuthResult<String> {
-    let client_id = std::env::var("GOOGLE_CLIENT_ID")
-        .map_err(|_| OAuthError::MissingEnvVar("GOOGLE_CLIENT_ID".to_string()))?;
-    let redirect_uri = std::env::var("GOOGLE_REDIRECT_URI")
-        .map_err(|_| OAuthError::MissingEnvVar("GOOGLE_REDIRECT_URI".to_string()))?;
-
-    let params = [
-        ("client_id", client_id),
-        ("redirect_uri", redirect_uri),
-        ("response_type", "code".to_string()),
-        ("scope", "openid email profile".to_string()),
-        ("state", state.to_string()),
-    ];
-
-    let url =
-        reqwest::Url::parse_with_params("https://accounts.google.com/o/oauth2/v2/auth", &params)
-            .map_err(|e| OAuthError::UrlBuildError(e.to_string()))?;
-
-    Ok(url.to_string())
+use crate::models::{auth::Platform, oauth::GoogleUser};
+
+pub struct GoogleProvider {
+    /// Env var holding the URI Google sends the user back to after signing in.
+    redirect_uri_var: &'static str,
 }
 
-pub async fn exchange_code(code: &str) -> OAuthResult<GoogleTokenResponse> {
-    let client_id = std::env::var("GOOGLE_CLIENT_ID")
-        .map_err(|_| OAuthError::MissingEnvVar("GOOGLE_CLIENT_ID".to_string()))?;
-    let client_secret = std::env::var("GOOGLE_CLIENT_SECRET")
-        .map_err(|_| OAuthError::MissingEnvVar("GOOGLE_CLIENT_SECRET".to_string()))?;
-    let redirect_uri = std::env::var("GOOGLE_REDIRECT_URI")
-        .map_err(|_| OAuthError::MissingEnvVar("GOOGLE_REDIRECT_URI".to_string()))?;
-
-    let client = reqwest::Client::new();
-
-    let response = client
-        .post("https://oauth2.googleapis.com/token")
-        .form(&[
-            ("client_id", client_id.as_str()),
-            ("client_secret", client_secret.as_str()),
-            ("code", code),
-            ("grant_type", "authorization_code"),
-            ("redirect_uri", redirect_uri.as_str()),
-        ])
-        .send()
-        .await?;
-
-    if !response.status().is_success() {
-        return Err(OAuthError::InvalidResponse);
+impl GoogleProvider {
+    pub fn new() -> Self {
+        Self {
+            redirect_uri_var: "GOOGLE_REDIRECT_URI",
+        }
     }
 
-    let token_response = response
-        .json()
-        .await
-        .map_err(|e| OAuthError::ParseError(e.to_string()))?;
+    /// Sends the user back to the native app through a deep link instead of the web app.
+    pub fn mobile() -> Self {
+        Self {
+            redirect_uri_var: "GOOGLE_MOBILE_REDIRECT_URI",
+        }
+    }
 
-    Ok(token_response)
+    /// The right provider for `platform`. The code exchange has to repeat the redirect URI
+    /// the login was started with, so both halves of a login must agree on it.
+    pub fn for_platform(platform: Platform) -> Self {
+        match platform {
+            Platform::Web => Self::new(),
+            Platform::Mobile => Self::mobile(),
+        }
+    }
 }
 
-pub async fn get_user_info(access_token: &str) -> OAuthResult<GoogleUser> {
-    let client = reqwest::Client::new();
+impl Default for GoogleProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-    let response = client
-        .get("https://www.googleapis.com/oauth2/v2/userinfo")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
-        .await?;
+impl OAuthProvider for GoogleProvider {
+    fn provider_name(&self) -> &str {
+        "google"
+    }
 
-    if !response.status().is_success() {
-        return Err(OAuthError::InvalidResponse);
+    fn identifier_type(&self) -> &str {
+        "google"
     }
 
-    let user = response
-        .json()
-        .await
-        .map_err(|e| OAuthError::ParseError(e.to_string()))?;
+    fn client_id(&self) -> OAuthResult<String> {
+        std::env::var("GOOGLE_CLIENT_ID")
+            .map_err(|_| OAuthError::MissingEnvVar("GOOGLE_CLIENT_ID".to_string()))
+    }
 
-    Ok(user)
-}
+    fn client_secret(&self) -> OAuthResult<String> {
+        std::env::var("GOOGLE_CLIENT_SECRET")
+            .map_err(|_| OAuthError::MissingEnvVar("GOOGLE_CLIENT_SECRET".to_string()))
+    }
 
-pub async fn find_or_create_user(
-    profile: GoogleUser,
-    db: &Surreal<Client>,
-) -> OAuthResult<RecordId> {
-    let existing: Option<UserIdentifier> = db
-        .query("SELECT * FROM user_identifier WHERE identifier_type = 'google' AND identifier_value = $id")
-        .bind(("id", profile.id.clone()))
-        .await?
-        .take(0)?;
-
-    if let Some(record) = existing {
-        return Ok(record.user);
+    fn redirect_uri(&self) -> OAuthResult<String> {
+        std::env::var(self.redirect_uri_var)
+            .map_err(|_| OAuthError::MissingEnvVar(self.redirect_uri_var.to_string()))
     }
 
-    let display_name = profile.name.unwrap_or_else(|| {
-        profile
-            .email
-            .split('@')
-            .next()
-            .unwrap_or("User")
-            .to_string()
-    });
-
-    let placeholder_password = format!("oauth_google_{}", generate_token());
-
-    let user = CreateUser {
-        display_name,
-        password_hash: placeholder_password,
-    };
-
-    let surql = r#"
-        BEGIN TRANSACTION;
-
-        LET $created_user = (CREATE ONLY users CONTENT $user_data);
-
-        CREATE user_identifier CONTENT {
-            user: $created_user.id,
-            identifier_type: 'google',
-            identifier_value: $provider_id
-        };
-
-        RETURN $created_user;
-        COMMIT TRANSACTION;
-    "#;
-
-    let mut result = db
-        .query(surql)
-        .bind(("user_data", user))
-        .bind(("provider_id", profile.id))
-        .await
-        .map_err(|e| OAuthError::DatabaseError(Box::new(e)))?;
-
-    let created_user: Option<User> = result
-        .take(0)
-        .map_err(|e| OAuthError::DatabaseError(Box::new(e)))?;
-    let user_id = created_user.ok_or(OAuthError::UserNotFound)?.id;
-
-    Ok(user_id)
+    fn tenant_id(&self) -> OAuthResult<String> {
+        Ok(String::new())
+    }
+
+    fn authorization_endpoint(&self) -> String {
+        "https://accounts.google.com/o/oauth2/v2/auth".to_string()
+    }
+
+    fn token_endpoint(&self) -> String {
+        "https://oauth2.googleapis.com/token".to_string()
+    }
+
+    fn userinfo_endpoint(&self) -> String {
+        "https://www.googleapis.com/oauth2/v2/userinfo".to_string()
+    }
+
+    fn scopes(&self) -> String {
+        "openid email profile".to_string()
+    }
+
+    async fn get_user_info(&self, access_token: &str) -> OAuthResult<ProviderUser> {
+        let client = reqwest::Client::new();
+
+        let response = client
+            .get(self.userinfo_endpoint())
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(OAuthError::InvalidResponse);
+        }
+
+        let google_user: GoogleUser = response
+            .json()
+            .await
+            .map_err(|e| OAuthError::ParseError(e.to_string()))?;
+
+        Ok(ProviderUser {
+            id: google_user.id,
+            email: google_user.email,
+            name: google_user.name,
+            picture: google_user.picture,
+        })
+    }
 }
@@ -4,16 +4,34 @@ use leptos::prelude::ServerFnError;
 use crate::auth::oauth::provider::OAuthProvider;
 use crate::auth::oauth::state::{generate_state, validate_state};
 use crate::auth::session::create_session;
-use crate::models::api_responses::ApiResponse;
-use crate::utils::ssr::get_server_context;
+use crate::models::{
+    api_responses::{ApiResponse, ErrorCode},
+    auth::Platform,
+};
+use crate::utils::ssr::{check_platform_consistency, current_request, get_server_context};
 use tracing::error;
 
 #[derive(Clone, Copy)]
 pub struct OAuthCallback;
 
+/// What a finished OAuth login hands back: the response data and the cookies to set.
+#[derive(Debug, PartialEq)]
+pub struct OAuthLogin {
+    pub data: String,
+    pub cookies: Vec<String>,
+}
+
 impl OAuthCallback {
     pub async fn get_url<P: OAuthProvider + Default + 'static>(
         cookie_name: &str,
+    ) -> Result<ApiResponse<String>, ServerFnError> {
+        Self::get_url_with(P::default(), cookie_name).await
+    }
+
+    /// Same as `get_url`, for providers that need to be configured before use.
+    pub async fn get_url_with<P: OAuthProvider + 'static>(
+        provider: P,
+        cookie_name: &str,
     ) -> Result<ApiResponse<String>, ServerFnError> {
         let (response_option, _db) = match get_server_context().await {
             Ok(ctx) => ctx,
@@ -25,29 +43,30 @@ impl OAuthCallback {
             Err(e) => {
                 error!(?e, "Failed to generate state");
                 response_option.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Ok(ApiResponse::error(
+                return Ok(ApiResponse::error_with_code(
+                    ErrorCode::Internal,
                     "Failed to generate authentication state".to_string(),
                 ));
             }
         };
 
-        let provider = P::default();
         let url = match provider.authorization_url(&state) {
             Ok(u) => u,
             Err(e) => {
                 error!(error = %e, "Failed to get authorization URL");
                 response_option.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Ok(ApiResponse::error(format!(
-                    "Failed to create authorization URL: {}",
-                    e
-                )));
+                return Ok(ApiResponse::error_with_code(
+                    ErrorCode::Internal,
+                    format!("Failed to create authorization URL: {}", e),
+                ));
             }
         };
 
         let cookie = format!(
-            "{}={}; Path=/; Secure; HttpOnly; SameSite=Lax; Max-Age={}",
+            "{}={}; Path=/; Secure; HttpOnly; SameSite={}; Max-Age={}",
             cookie_name,
             state,
+            provider.state_cookie_same_site(),
             10 * 60
         );
 
@@ -58,7 +77,10 @@ impl OAuthCallback {
             Err(e) => {
                 error!(?e, "Failed to create header value");
                 response_option.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Ok(ApiResponse::error("Failed to set cookie".to_string()));
+                return Ok(ApiResponse::error_with_code(
+                    ErrorCode::Internal,
+                    "Failed to set cookie".to_string(),
+                ));
             }
         };
 
@@ -67,26 +89,42 @@ impl OAuthCallback {
         Ok(ApiResponse {
             data: Some(url),
             error: None,
+            code: None,
         })
     }
 
+    /// Completes the login. Web clients get the session in a cookie, mobile clients get the
+    /// session token in the response data and no cookies at all.
     pub async fn handle<P: OAuthProvider + Default + 'static>(
         code: String,
         state: String,
         cookie_name: &str,
+        platform: Platform,
+    ) -> Result<ApiResponse<String>, ServerFnError> {
+        Self::handle_with(P::default(), code, state, cookie_name, platform).await
+    }
+
+    /// Same as `handle`, for providers that need request data to be built.
+    pub async fn handle_with<P: OAuthProvider + 'static>(
+        provider: P,
+        code: String,
+        state: String,
+        cookie_name: &str,
+        platform: Platform,
     ) -> Result<ApiResponse<String>, ServerFnError> {
         let (response_option, db) = match get_server_context().await {
             Ok(ctx) => ctx,
             Err(e) => return Ok(e),
         };
 
-        let req = match leptos_actix::extract::<actix_web::HttpRequest>().await {
+        if let Err(message) = check_platform_consistency(platform).await {
+            response_option.set_status(StatusCode::BAD_REQUEST);
+            return Ok(ApiResponse::error_with_code(ErrorCode::BadRequest, message));
+        }
+
+        let req = match current_request().await {
             Ok(req) => req,
-            Err(e) => {
-                error!(?e, "Failed to extract request");
-                response_option.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-                return Ok(ApiResponse::error("Internal server error".to_string()));
-            }
+            Err(e) => return Ok(e),
         };
 
         let stored_state = req
@@ -97,34 +135,33 @@ impl OAuthCallback {
         if !validate_state(&state, &stored_state) {
             error!("State validation failed");
             response_option.set_status(StatusCode::BAD_REQUEST);
-            return Ok(ApiResponse::error(
+            return Ok(ApiResponse::error_with_code(
+                ErrorCode::BadRequest,
                 "Invalid authentication state".to_string(),
             ));
         }
 
-        let provider = P::default();
-
         let token_response = match provider.exchange_code(&code).await {
             Ok(token) => token,
             Err(e) => {
                 error!(error = %e, "Failed to exchange code");
                 response_option.set_status(StatusCode::BAD_REQUEST);
-                return Ok(ApiResponse::error(format!(
-                    "Failed to exchange authorization code: {}",
-                    e
-                )));
+                return Ok(ApiResponse::error_with_code(
+                    ErrorCode::BadRequest,
+                    format!("Failed to exchange authorization code: {}", e),
+                ));
             }
         };
 
-        let user_info = match provider.get_user_info(&token_response.access_token).await {
+        let user_info = match provider.user_from_token(&token_response).await {
             Ok(user) => user,
             Err(e) => {
                 error!(error = %e, "Failed to get user info");
                 response_option.set_status(StatusCode::BAD_REQUEST);
-                return Ok(ApiResponse::error(format!(
-                    "Failed to get user information: {}",
-                    e
-                )));
+                return Ok(ApiResponse::error_with_code(
+                    ErrorCode::BadRequest,
+                    format!("Failed to get user information: {}", e),
+                ));
             }
         };
 
@@ -151,29 +188,48 @@ impl OAuthCallback {
 
         use actix_web::http::header::{HeaderValue, SET_COOKIE};
 
-        let session_cookie = format!(
-            "__Host-session={}; Path=/; Secure; HttpOnly; SameSite=Lax; Max-Age={}",
-            session_token,
-            24 * 60 * 60
-        );
-
-        let clear_state_cookie = format!(
-            "{}={}; Path=/; Secure; HttpOnly; SameSite=Lax; Max-Age=0",
-            cookie_name, ""
+        let login = Self::login_response(
+            platform,
+            &session_token,
+            cookie_name,
+            provider.provider_name(),
         );
 
-        if let Ok(session_header) = HeaderValue::from_str(&session_cookie) {
-            response_option.append_header(SET_COOKIE, session_header);
+        for cookie in &login.cookies {
+            if let Ok(header) = HeaderValue::from_str(cookie) {
+                response_option.append_header(SET_COOKIE, header);
+            }
         }
 
-        if let Ok(clear_header) = HeaderValue::from_str(&clear_state_cookie) {
-            response_option.append_header(SET_COOKIE, clear_header);
-        }
+        Ok(ApiResponse::data(login.data))
+    }
 
-        let provider_name = provider.provider_name();
-        Ok(ApiResponse::data(format!(
-            "Successfully authenticated with {}",
-            provider_name
-        )))
+    /// Builds the response for a user who just logged in with `provider_name`.
+    pub fn login_response(
+        platform: Platform,
+        session_token: &str,
+        cookie_name: &str,
+        provider_name: &str,
+    ) -> OAuthLogin {
+        match platform {
+            Platform::Web => OAuthLogin {
+                data: format!("Successfully authenticated with {}", provider_name),
+                cookies: vec![
+                    format!(
+                        "__Host-session={}; Path=/; Secure; HttpOnly; SameSite=Lax; Max-Age={}",
+                        session_token,
+                        24 * 60 * 60
+                    ),
+                    format!(
+                        "{}=; Path=/; Secure; HttpOnly; SameSite=Lax; Max-Age=0",
+                        cookie_name
+                    ),
+                ],
+            },
+            Platform::Mobile => OAuthLogin {
+                data: session_token.to_string(),
+                cookies: Vec::new(),
+            },
+        }
     }
 }
@@ -1,6 +1,10 @@
 #[cfg(feature = "ssr")]
+pub mod apple;
+#[cfg(feature = "ssr")]
 pub mod discord;
 #[cfg(feature = "ssr")]
+pub mod github;
+#[cfg(feature = "ssr")]
 pub mod google;
 #[cfg(feature = "ssr")]
 pub mod helpers;
@@ -10,3 +14,5 @@ pub mod microsoft;
 pub mod provider;
 #[cfg(feature = "ssr")]
 pub mod state;
+#[cfg(feature = "ssr")]
+pub mod workos;
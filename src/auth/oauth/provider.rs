@@ -3,16 +3,25 @@ use surrealdb::engine::remote::ws::Client;
 use surrealdb::{RecordId, Surreal};
 
 use crate::errors::oauth::{OAuthError, OAuthResult};
-use crate::models::user::{CreateUser, User, UserIdentifier};
+use crate::models::{
+    redacted::Redacted,
+    user::{CreateUser, User, UserIdentifier},
+};
 use crate::utils::token_generator::generate_token;
 
 #[derive(Debug, Deserialize)]
 pub struct TokenResponse {
-    pub access_token: String,
+    pub access_token: Redacted<String>,
+    #[serde(default)]
     pub expires_in: i64,
+    /// WorkOS leaves this out of its SSO token response.
+    #[serde(default)]
     pub token_type: String,
+    #[serde(default)]
     pub scope: String,
-    pub refresh_token: Option<String>,
+    pub refresh_token: Option<Redacted<String>>,
+    /// Only sent by OpenID Connect providers such as Apple.
+    pub id_token: Option<Redacted<String>>,
 }
 
 #[derive(Debug, Clone)]
@@ -51,6 +60,12 @@ pub trait OAuthProvider: Send + Sync {
         Ok(url.to_string())
     }
 
+    /// `SameSite` attribute of the state cookie. Providers that post the callback back
+    /// cross-site need `None`, otherwise the browser drops the cookie.
+    fn state_cookie_same_site(&self) -> &str {
+        "Lax"
+    }
+
     fn authorization_endpoint(&self) -> String;
     fn token_endpoint(&self) -> String;
     fn userinfo_endpoint(&self) -> String;
@@ -65,6 +80,7 @@ pub trait OAuthProvider: Send + Sync {
 
         let response = client
             .post(self.token_endpoint())
+            .header("Accept", "application/json")
             .form(&[
                 ("client_id", client_id.as_str()),
                 ("client_secret", client_secret.as_str()),
@@ -89,6 +105,13 @@ pub trait OAuthProvider: Send + Sync {
 
     async fn get_user_info(&self, access_token: &str) -> OAuthResult<ProviderUser>;
 
+    /// Resolves the signed-in user once the code has been exchanged. Defaults to querying
+    /// the userinfo endpoint with the access token.
+    async fn user_from_token(&self, token_response: &TokenResponse) -> OAuthResult<ProviderUser> {
+        self.get_user_info(token_response.access_token.expose())
+            .await
+    }
+
     async fn find_or_create_user(
         &self,
         profile: ProviderUser,
@@ -103,7 +126,12 @@ pub trait OAuthProvider: Send + Sync {
             .await?
             .take(0)?;
 
+        // Accounts made before verification existed become verified on their next sign-in
         if let Some(record) = existing {
+            db.query("UPDATE $user SET verified = true WHERE verified != true")
+                .bind(("user", record.user.clone()))
+                .await?
+                .check()?;
             return Ok(record.user);
         }
 
@@ -121,6 +149,7 @@ pub trait OAuthProvider: Send + Sync {
         let user = CreateUser {
             display_name,
             password_hash: placeholder_password,
+            verified: true,
         };
 
         let surql = format!(
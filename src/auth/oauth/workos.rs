@@ -0,0 +1,134 @@
+use serde::Deserialize;
+
+use crate::auth::oauth::provider::{OAuthProvider, ProviderUser};
+use crate::errors::oauth::{OAuthError, OAuthResult};
+
+/// The SSO profile WorkOS returns for a signed-in user.
+#[derive(Debug, Deserialize)]
+pub struct WorkosProfile {
+    pub id: String,
+    pub email: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+}
+
+impl From<WorkosProfile> for ProviderUser {
+    fn from(profile: WorkosProfile) -> Self {
+        let name = [profile.first_name, profile.last_name]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        ProviderUser {
+            id: profile.id,
+            email: profile.email,
+            name: (!name.is_empty()).then_some(name),
+            picture: None,
+        }
+    }
+}
+
+pub struct WorkosProvider;
+
+impl WorkosProvider {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn organization_id(&self) -> OAuthResult<String> {
+        std::env::var("WORKOS_ORGANIZATION_ID")
+            .map_err(|_| OAuthError::MissingEnvVar("WORKOS_ORGANIZATION_ID".to_string()))
+    }
+}
+
+impl Default for WorkosProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OAuthProvider for WorkosProvider {
+    fn provider_name(&self) -> &str {
+        "workos"
+    }
+
+    fn identifier_type(&self) -> &str {
+        "workos"
+    }
+
+    fn client_id(&self) -> OAuthResult<String> {
+        std::env::var("WORKOS_CLIENT_ID")
+            .map_err(|_| OAuthError::MissingEnvVar("WORKOS_CLIENT_ID".to_string()))
+    }
+
+    /// WorkOS authenticates the code exchange with the API key.
+    fn client_secret(&self) -> OAuthResult<String> {
+        std::env::var("WORKOS_API_KEY")
+            .map_err(|_| OAuthError::MissingEnvVar("WORKOS_API_KEY".to_string()))
+    }
+
+    fn redirect_uri(&self) -> OAuthResult<String> {
+        std::env::var("WORKOS_REDIRECT_URI")
+            .map_err(|_| OAuthError::MissingEnvVar("WORKOS_REDIRECT_URI".to_string()))
+    }
+
+    fn tenant_id(&self) -> OAuthResult<String> {
+        Ok(String::new())
+    }
+
+    /// SSO logins have to name the organization whose identity provider signs the user in,
+    /// and WorkOS takes no scopes.
+    fn authorization_url(&self, state: &str) -> OAuthResult<String> {
+        let params = [
+            ("client_id", self.client_id()?),
+            ("redirect_uri", self.redirect_uri()?),
+            ("response_type", "code".to_string()),
+            ("organization", self.organization_id()?),
+            ("state", state.to_string()),
+        ];
+
+        let url = reqwest::Url::parse_with_params(&self.authorization_endpoint(), &params)
+            .map_err(|e| OAuthError::UrlBuildError(e.to_string()))?;
+
+        Ok(url.to_string())
+    }
+
+    fn authorization_endpoint(&self) -> String {
+        "https://api.workos.com/sso/authorize".to_string()
+    }
+
+    fn token_endpoint(&self) -> String {
+        "https://api.workos.com/sso/token".to_string()
+    }
+
+    fn userinfo_endpoint(&self) -> String {
+        "https://api.workos.com/sso/profile".to_string()
+    }
+
+    fn scopes(&self) -> String {
+        String::new()
+    }
+
+    async fn get_user_info(&self, access_token: &str) -> OAuthResult<ProviderUser> {
+        let client = reqwest::Client::new();
+
+        let response = client
+            .get(self.userinfo_endpoint())
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(OAuthError::InvalidResponse);
+        }
+
+        let profile: WorkosProfile = response
+            .json()
+            .await
+            .map_err(|e| OAuthError::ParseError(e.to_string()))?;
+
+        Ok(profile.into())
+    }
+}
@@ -0,0 +1,56 @@
+use argon2::{
+    Algorithm, Argon2, Params, Version,
+    password_hash::{self, PasswordHasher, SaltString},
+};
+use rand::rngs::OsRng;
+use tracing::warn;
+
+/// Builds the Argon2 hasher from `ARGON2_MEMORY_KIB`, `ARGON2_ITERATIONS` and
+/// `ARGON2_PARALLELISM`. Unset variables keep the argon2 defaults, and a combination argon2
+/// rejects falls back to `Argon2::default()`.
+pub fn argon2_from_env() -> Argon2<'static> {
+    fn env_u32(name: &str, default: u32) -> u32 {
+        std::env::var(name)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default)
+    }
+
+    let memory_kib = env_u32("ARGON2_MEMORY_KIB", Params::DEFAULT_M_COST);
+    let iterations = env_u32("ARGON2_ITERATIONS", Params::DEFAULT_T_COST);
+    let parallelism = env_u32("ARGON2_PARALLELISM", Params::DEFAULT_P_COST);
+
+    match argon2_with_params(memory_kib, iterations, parallelism) {
+        Ok(argon2) => argon2,
+        Err(e) => {
+            warn!(
+                ?e,
+                memory_kib,
+                iterations,
+                parallelism,
+                "Invalid argon2 parameters, using the defaults"
+            );
+            Argon2::default()
+        }
+    }
+}
+
+pub fn argon2_with_params(
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+) -> Result<Argon2<'static>, argon2::Error> {
+    let params = Params::new(memory_kib, iterations, parallelism, None)?;
+    Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+}
+
+/// Hashes a password with Argon2 and a fresh random salt, ready to store as `password_hash`.
+pub fn hash_password(password: &str) -> Result<String, password_hash::Error> {
+    hash_password_with(&argon2_from_env(), password)
+}
+
+pub fn hash_password_with(argon2: &Argon2, password: &str) -> Result<String, password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = argon2.hash_password(password.as_bytes(), &salt)?;
+    Ok(password_hash.to_string())
+}
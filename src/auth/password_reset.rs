@@ -0,0 +1,124 @@
+use chrono::{Duration, Utc};
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client, sql::Datetime};
+
+use crate::{
+    auth::password::hash_password,
+    database::expiry::Expirable,
+    errors::password_reset::PasswordResetError,
+    models::{
+        password_reset::{CreatePasswordReset, PasswordReset},
+        user::{Identifier, normalize_mobile},
+    },
+    services::notifier::Notifier,
+    utils::token_generator::generate_token,
+};
+
+pub const PASSWORD_RESET_TTL_IN_MINUTES: i64 = 30;
+
+/// Sends a single-use password reset link to the email or mobile number of an account,
+/// replacing any link sent earlier. Unknown identifiers are ignored so the endpoint can't
+/// be used to probe for accounts.
+pub async fn create_password_reset(
+    identifier: &Identifier,
+    reset_url: &str,
+    notifier: &dyn Notifier,
+    db: &Surreal<Client>,
+) -> Result<(), PasswordResetError> {
+    let (identifier_type, identifier_value) = match identifier {
+        Identifier::Email(email) => ("email", email.trim().to_string()),
+        Identifier::Mobile(mobile) => ("mobile", normalize_mobile(mobile)),
+        Identifier::Google(_)
+        | Identifier::Meta(_)
+        | Identifier::Instagram(_)
+        | Identifier::Workos(_) => {
+            return Err(PasswordResetError::UnsupportedIdentifier);
+        }
+    };
+
+    let user: Option<RecordId> = db
+        .query(
+            "SELECT VALUE user FROM user_identifier
+                WHERE identifier_type = $identifier_type AND identifier_value = $identifier_value",
+        )
+        .bind(("identifier_type", identifier_type))
+        .bind(("identifier_value", identifier_value.clone()))
+        .await?
+        .take(0)?;
+    let Some(user) = user else {
+        return Ok(());
+    };
+
+    let token = generate_token();
+    let password_reset = CreatePasswordReset {
+        user: user.clone(),
+        token: token.clone().into(),
+        expires_at: Datetime::from(Utc::now() + Duration::minutes(PASSWORD_RESET_TTL_IN_MINUTES)),
+    };
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            DELETE password_resets WHERE user = $user;
+            CREATE password_resets CONTENT $password_reset;
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("user", user))
+    .bind(("password_reset", password_reset))
+    .await?
+    .check()?;
+
+    let message = format!(
+        "Use this link to reset your Merzah password: {}?token={}\n\nIt expires in {} minutes and works only once.",
+        reset_url, token, PASSWORD_RESET_TTL_IN_MINUTES
+    );
+    if identifier_type == "email" {
+        notifier
+            .send_email(&identifier_value, "Reset your Merzah password", &message)
+            .await?;
+    } else {
+        notifier.send_sms(&identifier_value, &message).await?;
+    }
+
+    Ok(())
+}
+
+/// Sets a new password for the user a reset token was issued to. The token is deleted as it
+/// is read so it only ever works once, and every session of the user is ended since whoever
+/// held them may have known the old password.
+pub async fn redeem_password_reset(
+    token: &str,
+    new_password: &str,
+    db: &Surreal<Client>,
+) -> Result<RecordId, PasswordResetError> {
+    let password_resets: Vec<PasswordReset> = db
+        .query("DELETE password_resets WHERE token = $token RETURN BEFORE")
+        .bind(("token", token.to_string()))
+        .await?
+        .take(0)?;
+    let password_reset = password_resets
+        .into_iter()
+        .next()
+        .ok_or(PasswordResetError::InvalidToken)?;
+
+    if password_reset.is_expired() {
+        return Err(PasswordResetError::TokenExpired);
+    }
+
+    let password_hash = hash_password(new_password).map_err(PasswordResetError::HashError)?;
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            UPDATE $user SET password_hash = $password_hash, updated_at = time::now();
+            DELETE sessions WHERE user = $user;
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("user", password_reset.user.clone()))
+    .bind(("password_hash", password_hash))
+    .await?
+    .check()?;
+
+    Ok(password_reset.user)
+}
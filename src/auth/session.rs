@@ -8,6 +8,7 @@ use surrealdb::sql::Datetime;
 use surrealdb::{RecordId, Surreal};
 
 use crate::{
+    database::{expiry::delete_expired, instrumented::CountingDb},
     errors::session::SessionError,
     models::{
         session::{CreateSession, Session, UpdateSession},
@@ -17,6 +18,14 @@ use crate::{
 };
 
 static SESSION_DURATION_IN_HOURS: i64 = 1;
+/// Sessions used within this many minutes of expiring get their expiry pushed back.
+static SESSION_REFRESH_WINDOW_IN_MINUTES: i64 = 15;
+
+/// The user behind a session token, and whether resolving it extended the session.
+pub struct ResolvedSession {
+    pub user: User,
+    pub refreshed: bool,
+}
 
 pub async fn create_session(user: RecordId, db: &Surreal<Client>) -> Result<String> {
     let session_token = generate_token();
@@ -24,7 +33,7 @@ pub async fn create_session(user: RecordId, db: &Surreal<Client>) -> Result<Stri
 
     let session = CreateSession {
         user,
-        session_token: session_token.clone(),
+        session_token: session_token.clone().into(),
         expires_at,
     };
 
@@ -39,6 +48,13 @@ pub async fn create_session(user: RecordId, db: &Surreal<Client>) -> Result<Stri
 }
 
 pub async fn get_user_by_session(session_token: &str, db: &Surreal<Client>) -> Result<User> {
+    let db = CountingDb::uncounted(db.clone());
+    Ok(resolve_session(session_token, &db).await?.user)
+}
+
+/// Looks up the user of a session, sliding its expiry forward when it is about to run out
+/// so that active users stay logged in.
+pub async fn resolve_session(session_token: &str, db: &CountingDb) -> Result<ResolvedSession> {
     validate_session_token(session_token)?;
 
     let result_from_sessions_table: Option<crate::models::session::SessionWithUser> = db
@@ -50,11 +66,21 @@ pub async fn get_user_by_session(session_token: &str, db: &Surreal<Client>) -> R
         .take(0)?;
 
     if let Some(session) = result_from_sessions_table {
-        if session.expires_at <= Datetime::from(Utc::now()) {
+        let now = Utc::now();
+        if session.expires_at <= Datetime::from(now) {
             Err(SessionError::SessionExpired(session.expires_at))?;
         }
 
-        Ok(session.user)
+        let expires_at: chrono::DateTime<Utc> = session.expires_at.into();
+        let refreshed = expires_at - now <= Duration::minutes(SESSION_REFRESH_WINDOW_IN_MINUTES);
+        if refreshed {
+            update_session_expiry(session.id, db).await?;
+        }
+
+        Ok(ResolvedSession {
+            user: session.user,
+            refreshed,
+        })
     } else {
         Err(SessionError::SessionNotFound)?
     }
@@ -96,7 +122,7 @@ pub async fn update_session_token(user_id: RecordId, db: &Surreal<Client>) -> Re
     let new_session_token = generate_token();
 
     let updated_session = UpdateSession {
-        session_token: Some(new_session_token.clone()),
+        session_token: Some(new_session_token.clone().into()),
         expires_at: None,
     };
 
@@ -110,30 +136,24 @@ pub async fn update_session_token(user_id: RecordId, db: &Surreal<Client>) -> Re
     Ok(new_session_token)
 }
 
-pub async fn update_session_expiry(user_id: RecordId, db: &Surreal<Client>) -> Result<()> {
-    let session: Option<Session> = db
-        .select(user_id.clone())
-        .await
-        .map_err(|e| SessionError::DatabaseError(Box::new(e)))
-        .with_context(|| "Failed to fetch session for it to update")?;
-
-    let session = session.ok_or(SessionError::SessionNotFound)?;
-    let old_expired_at: chrono::DateTime<Utc> = session.expires_at.into();
-    let new_expired_at =
-        Datetime::from(old_expired_at + Duration::hours(SESSION_DURATION_IN_HOURS));
+/// Extends the session to a full lifetime counted from now.
+pub async fn update_session_expiry(session_id: RecordId, db: &CountingDb) -> Result<()> {
+    let new_expired_at = Datetime::from(Utc::now() + Duration::hours(SESSION_DURATION_IN_HOURS));
 
     let updated_session = UpdateSession {
         session_token: None,
         expires_at: Some(new_expired_at),
     };
 
-    let _: Option<Session> = db
-        .update(user_id)
+    let session: Option<Session> = db
+        .update(session_id)
         .merge(updated_session)
         .await
         .map_err(|e| SessionError::DatabaseError(Box::new(e)))
         .with_context(|| "Failed to fetch session record to update the expiry time")?;
 
+    session.ok_or(SessionError::SessionNotFound)?;
+
     Ok(())
 }
 
@@ -157,7 +177,7 @@ pub async fn update_session_expiry_and_token(
     let new_session_token = generate_token();
 
     let updated_session = UpdateSession {
-        session_token: Some(new_session_token.clone()),
+        session_token: Some(new_session_token.clone().into()),
         expires_at: Some(new_expired_at),
     };
 
@@ -172,9 +192,8 @@ pub async fn update_session_expiry_and_token(
 }
 
 pub async fn cleanup_expired_sessions(db: &Surreal<Client>) -> Result<()> {
-    db.query("DELETE sessions WHERE expires_at <= time::now()")
+    delete_expired::<Session>("sessions", db)
         .await
-        .map_err(|e| SessionError::DatabaseError(Box::new(e)))
         .with_context(|| "Failed to deleted expired sessions")?;
 
     Ok(())
@@ -0,0 +1,129 @@
+use chrono::{Duration, Utc};
+use serde::Deserialize;
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client, sql::Datetime};
+
+use crate::{
+    database::expiry::Expirable,
+    errors::verification::VerificationError,
+    models::{
+        user::{Identifier, normalize_mobile},
+        verification::{CreateVerificationToken, VerificationToken},
+    },
+    services::notifier::Notifier,
+    utils::token_generator::generate_token,
+};
+
+pub const VERIFICATION_TTL_IN_HOURS: i64 = 24;
+
+#[derive(Debug, Deserialize)]
+struct OwnedIdentifier {
+    id: RecordId,
+    verified: bool,
+}
+
+/// Sends a single-use verification link to one of `user`'s own identifiers, replacing any
+/// link sent to it earlier.
+pub async fn send_verification(
+    user: &RecordId,
+    identifier: &Identifier,
+    link_url: &str,
+    notifier: &dyn Notifier,
+    db: &Surreal<Client>,
+) -> Result<(), VerificationError> {
+    let (identifier_type, identifier_value) = match identifier {
+        Identifier::Email(email) => ("email", email.trim().to_string()),
+        Identifier::Mobile(mobile) => ("mobile", normalize_mobile(mobile)),
+        Identifier::Google(_)
+        | Identifier::Meta(_)
+        | Identifier::Instagram(_)
+        | Identifier::Workos(_) => {
+            return Err(VerificationError::UnsupportedIdentifier);
+        }
+    };
+
+    let owned: Option<OwnedIdentifier> = db
+        .query(
+            "SELECT id, verified FROM user_identifier WHERE user = $user
+                AND identifier_type = $identifier_type AND identifier_value = $identifier_value",
+        )
+        .bind(("user", user.clone()))
+        .bind(("identifier_type", identifier_type))
+        .bind(("identifier_value", identifier_value.clone()))
+        .await?
+        .take(0)?;
+    let owned = owned.ok_or(VerificationError::IdentifierNotFound)?;
+    if owned.verified {
+        return Err(VerificationError::AlreadyVerified);
+    }
+
+    let token = generate_token();
+    let verification_token = CreateVerificationToken {
+        user: user.clone(),
+        identifier: owned.id.clone(),
+        token: token.clone().into(),
+        expires_at: Datetime::from(Utc::now() + Duration::hours(VERIFICATION_TTL_IN_HOURS)),
+    };
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            DELETE verification_tokens WHERE identifier = $identifier;
+            CREATE verification_tokens CONTENT $verification_token;
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("identifier", owned.id))
+    .bind(("verification_token", verification_token))
+    .await?
+    .check()?;
+
+    let message = format!(
+        "Use this link to verify your Merzah account: {}?token={}\n\nIt expires in {} hours and works only once.",
+        link_url, token, VERIFICATION_TTL_IN_HOURS
+    );
+    if identifier_type == "email" {
+        notifier
+            .send_email(&identifier_value, "Verify your Merzah account", &message)
+            .await?;
+    } else {
+        notifier.send_sms(&identifier_value, &message).await?;
+    }
+
+    Ok(())
+}
+
+/// Redeems a verification link, marking both the identifier it was sent to and its user
+/// verified. The token is deleted as it is read so it only ever works once.
+pub async fn confirm_verification(
+    token: &str,
+    db: &Surreal<Client>,
+) -> Result<RecordId, VerificationError> {
+    let verification_tokens: Vec<VerificationToken> = db
+        .query("DELETE verification_tokens WHERE token = $token RETURN BEFORE")
+        .bind(("token", token.to_string()))
+        .await?
+        .take(0)?;
+    let verification_token = verification_tokens
+        .into_iter()
+        .next()
+        .ok_or(VerificationError::InvalidToken)?;
+
+    if verification_token.is_expired() {
+        return Err(VerificationError::TokenExpired);
+    }
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            UPDATE $identifier SET verified = true, updated_at = time::now();
+            UPDATE $user SET verified = true, updated_at = time::now();
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("identifier", verification_token.identifier))
+    .bind(("user", verification_token.user.clone()))
+    .await?
+    .check()?;
+
+    Ok(verification_token.user)
+}
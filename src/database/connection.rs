@@ -7,11 +7,17 @@ use surrealdb::opt::auth::Root;
 pub async fn init_db() -> Surreal<Client> {
     dotenv().ok();
 
+    let db_name = env::var("SURREAL_DB").expect("SURREAL_DB must be set");
+    let db_ns = env::var("SURREAL_NS").expect("SURREAL_NS must be set");
+
+    connect(&db_ns, &db_name).await
+}
+
+/// Opens a new connection to `SURREAL_URL` and selects `namespace` and `database` on it.
+pub async fn connect(namespace: &str, database: &str) -> Surreal<Client> {
     let db_url = env::var("SURREAL_URL").expect("SURREAL_URL must be set");
     let db_user = env::var("SURREAL_USER").expect("SURREAL_USER must be set");
     let db_pass = env::var("SURREAL_PASS").expect("SURREAL_PASS must be set");
-    let db_name = env::var("SURREAL_DB").expect("SURREAL_DB must be set");
-    let db_ns = env::var("SURREAL_NS").expect("SURREAL_NS must be set");
 
     println!("Connecting to: {}", db_url);
 
@@ -26,8 +32,8 @@ pub async fn init_db() -> Surreal<Client> {
     .await
     .expect("Failed to sign in to database");
 
-    db.use_ns(&db_ns)
-        .use_db(&db_name)
+    db.use_ns(namespace)
+        .use_db(database)
         .await
         .expect("Failed to use namespace or database");
 
@@ -0,0 +1,49 @@
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use surrealdb::{Surreal, engine::remote::ws::Client, sql::Datetime};
+
+use crate::{
+    database::query::take_checked,
+    errors::app::AppError,
+    models::{password_reset::PasswordReset, session::Session, verification::VerificationToken},
+};
+
+/// A record that stops being useful once its `expires_at` has passed.
+pub trait Expirable: DeserializeOwned {
+    fn expires_at(&self) -> &Datetime;
+
+    fn is_expired(&self) -> bool {
+        *self.expires_at() <= Datetime::from(Utc::now())
+    }
+}
+
+impl Expirable for Session {
+    fn expires_at(&self) -> &Datetime {
+        &self.expires_at
+    }
+}
+
+impl Expirable for PasswordReset {
+    fn expires_at(&self) -> &Datetime {
+        &self.expires_at
+    }
+}
+
+impl Expirable for VerificationToken {
+    fn expires_at(&self) -> &Datetime {
+        &self.expires_at
+    }
+}
+
+/// Deletes the rows of `table` whose `expires_at` has passed and returns them.
+pub async fn delete_expired<T: Expirable>(
+    table: &str,
+    db: &Surreal<Client>,
+) -> Result<Vec<T>, AppError> {
+    let response = db
+        .query("DELETE type::table($table) WHERE expires_at <= time::now() RETURN BEFORE")
+        .bind(("table", table.to_string()))
+        .await?;
+
+    take_checked(response, 0)
+}
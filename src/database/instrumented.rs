@@ -0,0 +1,96 @@
+use std::{
+    ops::Deref,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use actix_web::web;
+use surrealdb::{
+    Surreal,
+    engine::remote::ws::Client,
+    method::{Create, Query, Select, Update},
+    opt::{IntoQuery, IntoResource},
+};
+
+/// Counts the requests sent through a `CountingDb`. Tests register one as `web::Data` to
+/// assert how many round trips a server function makes.
+#[derive(Debug, Clone, Default)]
+pub struct QueryCounter(Arc<AtomicUsize>);
+
+impl QueryCounter {
+    pub fn record(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn reset(&self) {
+        self.0.store(0, Ordering::Relaxed);
+    }
+}
+
+/// The database client, with `query`, `select`, `create` and `update` counted when a
+/// `QueryCounter` is set. Everything else goes straight to the client through `Deref`.
+#[derive(Clone)]
+pub struct CountingDb {
+    db: Surreal<Client>,
+    counter: Option<QueryCounter>,
+}
+
+impl CountingDb {
+    pub fn new(db: Surreal<Client>, counter: Option<QueryCounter>) -> Self {
+        Self { db, counter }
+    }
+
+    pub fn uncounted(db: Surreal<Client>) -> Self {
+        Self::new(db, None)
+    }
+
+    /// Counts into the `QueryCounter` registered with the app, if there is one.
+    pub async fn from_request(db: Surreal<Client>) -> Self {
+        let counter = leptos_actix::extract::<web::Data<QueryCounter>>()
+            .await
+            .ok()
+            .map(|counter| counter.get_ref().clone());
+
+        Self::new(db, counter)
+    }
+
+    fn record(&self) {
+        if let Some(counter) = &self.counter {
+            counter.record();
+        }
+    }
+
+    pub fn query(&self, query: impl IntoQuery) -> Query<'_, Client> {
+        self.record();
+        self.db.query(query)
+    }
+
+    pub fn select<O>(&self, resource: impl IntoResource<O>) -> Select<'_, Client, O> {
+        self.record();
+        self.db.select(resource)
+    }
+
+    pub fn create<R>(&self, resource: impl IntoResource<R>) -> Create<'_, Client, R> {
+        self.record();
+        self.db.create(resource)
+    }
+
+    pub fn update<O>(&self, resource: impl IntoResource<O>) -> Update<'_, Client, O> {
+        self.record();
+        self.db.update(resource)
+    }
+}
+
+impl Deref for CountingDb {
+    type Target = Surreal<Client>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.db
+    }
+}
@@ -1 +1,6 @@
 pub mod connection;
+pub mod expiry;
+pub mod instrumented;
+pub mod query;
+#[cfg(feature = "multi_tenant")]
+pub mod tenants;
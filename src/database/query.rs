@@ -0,0 +1,28 @@
+use surrealdb::{Response, opt::QueryResult};
+
+use crate::errors::app::AppError;
+
+/// Index of the result of a transaction whose statements are wrapped in a single
+/// `RETURN { ... }` block, which evaluates to its last expression. Statements can be added to
+/// or removed from the block without the index moving.
+pub const TRANSACTION_RESULT: usize = 0;
+
+/// Checks every statement of `response` for errors and takes the result at `index`.
+///
+/// Unlike `Response::take`, an `index` past the last statement is an error instead of an empty
+/// result, which catches `take` indexes left stale after a transaction gains or loses a
+/// statement.
+pub fn take_checked<T>(response: Response, index: usize) -> Result<T, AppError>
+where
+    usize: QueryResult<T>,
+{
+    let statements = response.num_statements();
+    if index >= statements {
+        return Err(AppError::MissingStatement { index, statements });
+    }
+
+    response
+        .check()?
+        .take(index)
+        .map_err(|source| AppError::UnexpectedResult { index, source })
+}
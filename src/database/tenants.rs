@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use actix_web::{HttpRequest, web};
+use surrealdb::{Surreal, engine::remote::ws::Client};
+use tracing::error;
+
+use crate::{
+    database::connection::connect, errors::tenant::TenantError, utils::ssr::current_request,
+};
+
+/// Header naming the tenant of a request. Without it the tenant is read from the subdomain.
+pub const TENANT_HEADER: &str = "X-Tenant";
+
+/// A client per tenant, each on its own namespace and database. Clones of a client share the
+/// namespace they use, so every tenant needs a connection of its own.
+#[derive(Clone, Default)]
+pub struct TenantConnections {
+    connections: HashMap<String, Surreal<Client>>,
+}
+
+impl TenantConnections {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_tenant(mut self, tenant: &str, db: Surreal<Client>) -> Self {
+        self.connections.insert(tenant.to_ascii_lowercase(), db);
+        self
+    }
+
+    /// Connects every tenant of `SURREAL_TENANTS`, see `parse_tenant_entries`.
+    pub async fn from_env() -> Self {
+        let mut tenants = Self::new();
+        let Ok(entries) = std::env::var("SURREAL_TENANTS") else {
+            return tenants;
+        };
+
+        for target in parse_tenant_entries(&entries) {
+            let db = connect(&target.namespace, &target.database).await;
+            tenants = tenants.with_tenant(&target.tenant, db);
+        }
+
+        tenants
+    }
+
+    /// Every tenant's database, for jobs that have to run on each of them.
+    pub fn databases(&self) -> impl Iterator<Item = Surreal<Client>> + '_ {
+        self.connections.values().cloned()
+    }
+
+    pub fn get(&self, tenant: &str) -> Option<Surreal<Client>> {
+        self.connections.get(&tenant.to_ascii_lowercase()).cloned()
+    }
+
+    /// The database of the tenant `req` names, `None` when it names none. A tenant named in
+    /// `TENANT_HEADER` has to be known, while unknown subdomains such as `www` are ignored.
+    pub fn for_request(&self, req: &HttpRequest) -> Result<Option<Surreal<Client>>, TenantError> {
+        if let Some(tenant) = req.headers().get(TENANT_HEADER) {
+            let tenant = tenant.to_str().unwrap_or("").trim();
+            return match self.get(tenant) {
+                Some(db) => Ok(Some(db)),
+                None => Err(TenantError::UnknownTenant(tenant.to_string())),
+            };
+        }
+
+        let connection_info = req.connection_info();
+        let host = connection_info.host();
+        let host = host.split(':').next().unwrap_or(host);
+        let labels: Vec<&str> = host.split('.').collect();
+        if labels.len() < 3 {
+            return Ok(None);
+        }
+
+        Ok(self.get(labels[0]))
+    }
+}
+
+/// Where a tenant's data lives.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TenantTarget {
+    pub tenant: String,
+    pub namespace: String,
+    pub database: String,
+}
+
+/// Parses a comma separated list of `tenant=namespace/database` entries such as
+/// `north=merzah_north/merzah`. An entry that doesn't look like that is logged and skipped,
+/// so a typo costs one tenant rather than stopping the app from serving the others.
+pub fn parse_tenant_entries(entries: &str) -> Vec<TenantTarget> {
+    entries
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            let target = entry.split_once('=').and_then(|(tenant, target)| {
+                let (namespace, database) = target.split_once('/')?;
+                let (tenant, namespace, database) =
+                    (tenant.trim(), namespace.trim(), database.trim());
+                if tenant.is_empty() || namespace.is_empty() || database.is_empty() {
+                    return None;
+                }
+                Some(TenantTarget {
+                    tenant: tenant.to_string(),
+                    namespace: namespace.to_string(),
+                    database: database.to_string(),
+                })
+            });
+            if target.is_none() {
+                error!(
+                    entry,
+                    "Skipping a SURREAL_TENANTS entry that doesn't look like tenant=namespace/database"
+                );
+            }
+            target
+        })
+        .collect()
+}
+
+/// The database the current request should use: its tenant's when the app has tenants and
+/// the request names one, `default` otherwise.
+pub async fn db_for_request(default: Surreal<Client>) -> Result<Surreal<Client>, TenantError> {
+    let Ok(tenants) = leptos_actix::extract::<web::Data<TenantConnections>>().await else {
+        return Ok(default);
+    };
+    let req = current_request::<()>()
+        .await
+        .map_err(|_| TenantError::RequestUnavailable)?;
+
+    Ok(tenants.for_request(&req)?.unwrap_or(default))
+}
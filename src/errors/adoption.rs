@@ -0,0 +1,24 @@
+#[cfg(feature = "ssr")]
+use thiserror::Error;
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Error)]
+pub enum AdoptionError {
+    #[error("Database operation failed")]
+    DatabaseError(#[from] surrealdb::Error),
+
+    #[error("The mosque to adopt was not found")]
+    MosqueNotFound,
+
+    #[error("The user already administers this mosque")]
+    AlreadyAdmin,
+
+    #[error("The user already has a pending adoption request for this mosque")]
+    AlreadyRequested,
+
+    #[error("The adoption request was not found")]
+    RequestNotFound,
+
+    #[error("The adoption request has already been reviewed")]
+    AlreadyReviewed,
+}
@@ -0,0 +1,40 @@
+#[cfg(feature = "ssr")]
+use thiserror::Error;
+
+/// How SurrealDB ends the message of a transaction that lost a read or write conflict with a
+/// concurrent one. Over the WebSocket protocol errors arrive as text, so this is all there is
+/// to tell them apart by.
+#[cfg(feature = "ssr")]
+const TRANSACTION_CONFLICT_MESSAGE: &str = "This transaction can be retried";
+
+/// Errors from reading the results of a SurrealDB query, shared by the server functions.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("Some db error occurred during the transaction: {0}")]
+    QueryFailed(#[from] surrealdb::Error),
+
+    #[error("The query has {statements} statements, there is no result at index {index}")]
+    MissingStatement { index: usize, statements: usize },
+
+    #[error("Some db error occurred while reading the result at index {index}: {source}")]
+    UnexpectedResult {
+        index: usize,
+        #[source]
+        source: surrealdb::Error,
+    },
+}
+
+#[cfg(feature = "ssr")]
+impl AppError {
+    /// Whether the query failed only because a concurrent transaction touched the same
+    /// records, so running it again can succeed.
+    pub fn is_transaction_conflict(&self) -> bool {
+        match self {
+            AppError::QueryFailed(source) | AppError::UnexpectedResult { source, .. } => {
+                source.to_string().contains(TRANSACTION_CONFLICT_MESSAGE)
+            }
+            AppError::MissingStatement { .. } => false,
+        }
+    }
+}
@@ -13,6 +13,18 @@ pub enum AuthError {
     #[error("{0} already registered")]
     NotUniqueError(String),
 
+    #[error("You already have a {0} linked to your account")]
+    IdentifierTypeTaken(String),
+
+    #[error("OAuth identifiers cannot be manually registered")]
+    OAuthIdentifier,
+
+    #[error("The identifier is not linked to your account")]
+    IdentifierNotFound,
+
+    #[error("You can't remove the only identifier you log in with")]
+    LastIdentifier,
+
     #[error("Failed to hash the password")]
     PasswordHashError(argon2::password_hash::Error),
 
@@ -21,4 +33,10 @@ pub enum AuthError {
 
     #[error("Requested user was not found")]
     UserNotFound,
+
+    #[error("Email addresses from {0} are not accepted")]
+    BlockedEmailDomain(String),
+
+    #[error("{0} is not a valid email domain")]
+    InvalidEmailDomain(String),
 }
@@ -0,0 +1,45 @@
+#[cfg(feature = "ssr")]
+use thiserror::Error;
+#[cfg(feature = "ssr")]
+use tracing::error;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    errors::app::AppError, models::api_responses::ApiResponse, utils::ssr::ServerResponse,
+};
+
+/// Failures of the event endpoints. The detail is only logged, clients get a generic message.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Error)]
+pub enum EventError {
+    #[error("Some db error occurred: {0}")]
+    DatabaseError(#[from] surrealdb::Error),
+
+    #[error(transparent)]
+    QueryError(#[from] AppError),
+
+    #[error("The transaction returned no data")]
+    MissingResult,
+
+    #[error("No event found with the provided ID")]
+    EventNotFound,
+}
+
+#[cfg(feature = "ssr")]
+impl EventError {
+    /// Logs the error with `context` and sets the matching status, without leaking database
+    /// details into the response.
+    pub fn respond<T>(self, context: &str, responder: &ServerResponse) -> ApiResponse<T> {
+        match self {
+            EventError::EventNotFound => responder.not_found(self.to_string()),
+            EventError::DatabaseError(_)
+            | EventError::QueryError(_)
+            | EventError::MissingResult => {
+                error!(error = ?self, "{context}");
+                responder.internal_server_error(
+                    "Something went wrong with the event, please try again later".to_string(),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+#[cfg(feature = "ssr")]
+use thiserror::Error;
+
+#[cfg(feature = "ssr")]
+use crate::errors::notifier::NotifierError;
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Error)]
+pub enum MagicLinkError {
+    #[error("Database operation failed")]
+    DatabaseError(#[from] surrealdb::Error),
+
+    #[error("Failed to send the login link")]
+    NotifierError(#[from] NotifierError),
+
+    #[error("The login link is invalid or has already been used")]
+    InvalidToken,
+
+    #[error("The login link has expired")]
+    LinkExpired,
+}
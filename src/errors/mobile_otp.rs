@@ -0,0 +1,36 @@
+#[cfg(feature = "ssr")]
+use thiserror::Error;
+
+#[cfg(feature = "ssr")]
+use crate::errors::notifier::NotifierError;
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Error)]
+pub enum MobileOtpError {
+    #[error("Database operation failed")]
+    DatabaseError(#[from] surrealdb::Error),
+
+    #[error("Failed to hash the verification code")]
+    HashError(argon2::password_hash::Error),
+
+    #[error("Failed to send the verification code")]
+    NotifierError(#[from] NotifierError),
+
+    #[error("The user has no mobile identifier")]
+    NoMobileIdentifier,
+
+    #[error("The mobile number is already verified")]
+    AlreadyVerified,
+
+    #[error("No verification code has been requested")]
+    NoPendingCode,
+
+    #[error("The verification code has expired")]
+    CodeExpired,
+
+    #[error("Too many wrong attempts, request a new code")]
+    TooManyAttempts,
+
+    #[error("The verification code is incorrect")]
+    InvalidCode,
+}
@@ -1,10 +1,28 @@
 #[cfg(feature = "ssr")]
+pub mod adoption;
+#[cfg(feature = "ssr")]
+pub mod app;
+#[cfg(feature = "ssr")]
 pub mod auth;
 #[cfg(feature = "ssr")]
 pub mod education;
 #[cfg(feature = "ssr")]
+pub mod events;
+#[cfg(feature = "ssr")]
+pub mod magic_link;
+#[cfg(feature = "ssr")]
+pub mod mobile_otp;
+#[cfg(feature = "ssr")]
+pub mod notifier;
+#[cfg(feature = "ssr")]
 pub mod oauth;
 #[cfg(feature = "ssr")]
+pub mod password_reset;
+#[cfg(feature = "ssr")]
 pub mod session;
+#[cfg(feature = "multi_tenant")]
+pub mod tenant;
 #[cfg(feature = "ssr")]
 pub mod user_elevation;
+#[cfg(feature = "ssr")]
+pub mod verification;
@@ -0,0 +1,9 @@
+#[cfg(feature = "ssr")]
+use thiserror::Error;
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Error)]
+pub enum NotifierError {
+    #[error("Failed to deliver the message: {0}")]
+    DeliveryFailed(String),
+}
@@ -22,6 +22,12 @@ pub enum OAuthError {
 
     #[error("Invalid response from OAuth provider")]
     InvalidResponse,
+
+    #[error("Failed to sign the client secret: {0}")]
+    ClientSecretError(String),
+
+    #[error("Invalid ID token: {0}")]
+    InvalidIdToken(String),
 }
 
 impl From<surrealdb::Error> for OAuthError {
@@ -0,0 +1,27 @@
+#[cfg(feature = "ssr")]
+use thiserror::Error;
+
+#[cfg(feature = "ssr")]
+use crate::errors::notifier::NotifierError;
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Error)]
+pub enum PasswordResetError {
+    #[error("Database operation failed")]
+    DatabaseError(#[from] surrealdb::Error),
+
+    #[error("Failed to hash the new password")]
+    HashError(argon2::password_hash::Error),
+
+    #[error("Failed to send the password reset link")]
+    NotifierError(#[from] NotifierError),
+
+    #[error("Passwords can only be reset through an email address or mobile number")]
+    UnsupportedIdentifier,
+
+    #[error("The password reset link is invalid or has already been used")]
+    InvalidToken,
+
+    #[error("The password reset link has expired")]
+    TokenExpired,
+}
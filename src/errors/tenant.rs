@@ -0,0 +1,13 @@
+#[cfg(feature = "multi_tenant")]
+use thiserror::Error;
+
+#[cfg(feature = "multi_tenant")]
+#[derive(Debug, Error)]
+pub enum TenantError {
+    #[error("Unknown tenant: {0}")]
+    UnknownTenant(String),
+
+    /// Why is logged by `current_request`.
+    #[error("Failed to extract the request")]
+    RequestUnavailable,
+}
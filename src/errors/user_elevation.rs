@@ -1,6 +1,9 @@
 #[cfg(feature = "ssr")]
 use thiserror::Error;
 
+#[cfg(feature = "ssr")]
+use crate::models::user::Role;
+
 #[cfg(feature = "ssr")]
 #[derive(Debug, Error)]
 pub enum UserElevationError {
@@ -21,4 +24,10 @@ pub enum UserElevationError {
 
     #[error("Cannot elevate self")]
     SelfElevationNotAllowed,
+
+    #[error("Users can't be elevated to {} this way", .0.as_str())]
+    InvalidTargetRole(Role),
+
+    #[error("The user's {0} role can't be changed this way")]
+    UnmanagedRole(String),
 }
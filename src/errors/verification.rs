@@ -0,0 +1,30 @@
+#[cfg(feature = "ssr")]
+use thiserror::Error;
+
+#[cfg(feature = "ssr")]
+use crate::errors::notifier::NotifierError;
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Error)]
+pub enum VerificationError {
+    #[error("Database operation failed")]
+    DatabaseError(#[from] surrealdb::Error),
+
+    #[error("Failed to send the verification link")]
+    NotifierError(#[from] NotifierError),
+
+    #[error("Only email addresses and mobile numbers can be verified")]
+    UnsupportedIdentifier,
+
+    #[error("This identifier does not belong to your account")]
+    IdentifierNotFound,
+
+    #[error("This identifier is already verified")]
+    AlreadyVerified,
+
+    #[error("The verification link is invalid or has already been used")]
+    InvalidToken,
+
+    #[error("The verification link has expired")]
+    TokenExpired,
+}
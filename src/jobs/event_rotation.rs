@@ -3,17 +3,28 @@ use anyhow::Result;
 #[cfg(feature = "ssr")]
 use surrealdb::{Surreal, engine::remote::ws::Client};
 
+/// Rotates events at the top of every hour.
 #[cfg(feature = "ssr")]
-pub async fn start_scheduler(db: Surreal<Client>) -> Result<()> {
+pub const HOURLY: &str = "0 0 * * * *";
+
+/// Reads the rotation schedule from `ROTATION_CRON`, see `schedule_or_default`. A missing or
+/// invalid schedule leaves events rotating `HOURLY`.
+#[cfg(feature = "ssr")]
+pub fn rotation_schedule_from_env() -> String {
+    use crate::jobs::schedule::schedule_or_default;
+
+    schedule_or_default("ROTATION_CRON", std::env::var("ROTATION_CRON").ok(), HOURLY)
+}
+
+#[cfg(feature = "ssr")]
+pub async fn start_scheduler_with_schedule(db: Surreal<Client>, schedule: &str) -> Result<()> {
     use tokio_cron_scheduler::{Job, JobScheduler};
     use tracing::{error, info};
 
     use crate::services::recurrence::check_and_rotate_events;
 
-    let scheduler = JobScheduler::new().await?;
-
     let db_clone = db.clone();
-    let job = Job::new_async("0 0 * * * *", move |_uuid, _lock| {
+    let job = Job::new_async(schedule, move |_uuid, _lock| {
         let db = db_clone.clone();
         Box::pin(async move {
             match check_and_rotate_events(&db).await {
@@ -30,8 +41,28 @@ pub async fn start_scheduler(db: Surreal<Client>) -> Result<()> {
         })
     })?;
 
+    // Created after the job so that a bad schedule never starts a scheduler
+    let scheduler = JobScheduler::new().await?;
     scheduler.add(job).await?;
     scheduler.start().await?;
 
     Ok(())
 }
+
+/// Starts the rotation job without letting a failure reach the web server. The error is
+/// logged and `false` returned, and the app keeps serving requests with events left
+/// unrotated until the scheduler is fixed.
+#[cfg(feature = "ssr")]
+pub async fn start_scheduler_or_log(db: Surreal<Client>, schedule: &str) -> bool {
+    match start_scheduler_with_schedule(db, schedule).await {
+        Ok(()) => true,
+        Err(e) => {
+            tracing::error!(
+                ?e,
+                schedule,
+                "The event rotation scheduler failed to start, events will not rotate automatically"
+            );
+            false
+        }
+    }
+}
@@ -1,2 +1,6 @@
 #[cfg(feature = "ssr")]
 pub mod event_rotation;
+#[cfg(feature = "ssr")]
+pub mod schedule;
+#[cfg(feature = "ssr")]
+pub mod token_cleanup;
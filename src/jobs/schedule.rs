@@ -0,0 +1,20 @@
+use tracing::error;
+
+/// Picks the cron schedule of a job from the value of its `variable` environment variable.
+/// Unset, the job runs on `default`. A value the scheduler can't parse is logged and
+/// replaced by `default` too, so a typo never stops the app from serving.
+pub fn schedule_or_default(variable: &str, schedule: Option<String>, default: &str) -> String {
+    use tokio_cron_scheduler::Job;
+
+    let Some(schedule) = schedule else {
+        return default.to_string();
+    };
+
+    match Job::new(schedule.as_str(), |_uuid, _lock| {}) {
+        Ok(_) => schedule,
+        Err(e) => {
+            error!(?e, variable, %schedule, "Invalid cron schedule, using {default} instead");
+            default.to_string()
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+use crate::{
+    auth::magic_link::MagicLink,
+    database::expiry::{Expirable, delete_expired},
+    jobs::schedule::schedule_or_default,
+    models::{password_reset::PasswordReset, session::Session, verification::VerificationToken},
+    services::mobile_otp::MobileOtp,
+};
+
+/// Tables whose rows stop being useful once their `expires_at` has passed.
+pub const EXPIRING_TOKEN_TABLES: [&str; 5] = [
+    "sessions",
+    "password_resets",
+    "verification_tokens",
+    "mobile_otps",
+    "magic_links",
+];
+
+/// Clears expired tokens at half past every hour, out of step with the event rotation.
+pub const DEFAULT_CLEANUP_SCHEDULE: &str = "0 30 * * * *";
+
+/// Reads the cleanup schedule from `TOKEN_CLEANUP_CRON`, see `schedule_or_default`. A missing
+/// or invalid schedule leaves the cleanup running on `DEFAULT_CLEANUP_SCHEDULE`.
+pub fn cleanup_schedule_from_env() -> String {
+    schedule_or_default(
+        "TOKEN_CLEANUP_CRON",
+        std::env::var("TOKEN_CLEANUP_CRON").ok(),
+        DEFAULT_CLEANUP_SCHEDULE,
+    )
+}
+
+/// Deletes the expired rows of every table in `EXPIRING_TOKEN_TABLES`, returning how many
+/// were deleted from each.
+pub async fn clear_expired_tokens(db: &Surreal<Client>) -> Result<BTreeMap<&'static str, usize>> {
+    let [
+        sessions,
+        password_resets,
+        verification_tokens,
+        mobile_otps,
+        magic_links,
+    ] = EXPIRING_TOKEN_TABLES;
+
+    Ok(BTreeMap::from([
+        (sessions, count_expired::<Session>(sessions, db).await?),
+        (
+            password_resets,
+            count_expired::<PasswordReset>(password_resets, db).await?,
+        ),
+        (
+            verification_tokens,
+            count_expired::<VerificationToken>(verification_tokens, db).await?,
+        ),
+        (
+            mobile_otps,
+            count_expired::<MobileOtp>(mobile_otps, db).await?,
+        ),
+        (
+            magic_links,
+            count_expired::<MagicLink>(magic_links, db).await?,
+        ),
+    ]))
+}
+
+/// Deletes the expired rows of `table`, returning how many there were.
+async fn count_expired<T: Expirable>(table: &str, db: &Surreal<Client>) -> Result<usize> {
+    let deleted = delete_expired::<T>(table, db)
+        .await
+        .with_context(|| format!("Failed to delete the expired rows of {table}"))?;
+    Ok(deleted.len())
+}
+
+/// Starts the cleanup job, logging instead of failing so the web server keeps running
+/// without it.
+pub async fn start_token_cleanup_or_log(db: Surreal<Client>, schedule: &str) -> bool {
+    use tokio_cron_scheduler::{Job, JobScheduler};
+    use tracing::{error, info};
+
+    let start = async move {
+        let scheduler = JobScheduler::new().await?;
+        let job = Job::new_async(schedule, move |_uuid, _lock| {
+            let db = db.clone();
+            Box::pin(async move {
+                match clear_expired_tokens(&db).await {
+                    Ok(cleared) => info!(?cleared, "Cleared expired tokens"),
+                    Err(e) => error!(?e, "Error clearing expired tokens"),
+                }
+            })
+        })?;
+        scheduler.add(job).await?;
+        scheduler.start().await?;
+
+        anyhow::Ok(())
+    };
+
+    match start.await {
+        Ok(()) => true,
+        Err(e) => {
+            error!(
+                ?e,
+                schedule,
+                "The token cleanup scheduler failed to start, expired tokens will pile up"
+            );
+            false
+        }
+    }
+}
@@ -1,12 +1,14 @@
 #[cfg(feature = "ssr")]
 use std::net::TcpListener;
+#[cfg(feature = "ssr")]
+use std::sync::Arc;
 
 #[cfg(feature = "ssr")]
 use actix_files::Files;
 #[cfg(feature = "ssr")]
 use actix_web::dev::Server;
 #[cfg(feature = "ssr")]
-use actix_web::{App, HttpServer, web};
+use actix_web::{App, HttpServer, middleware, web};
 #[cfg(feature = "ssr")]
 use leptos::config::{ConfFile, get_configuration};
 #[cfg(feature = "ssr")]
@@ -22,6 +24,20 @@ use surrealdb::engine::remote::ws::Client;
 
 #[cfg(feature = "ssr")]
 use crate::app::App;
+#[cfg(feature = "ssr")]
+use crate::database::instrumented::QueryCounter;
+#[cfg(feature = "multi_tenant")]
+use crate::database::tenants::TenantConnections;
+#[cfg(feature = "ssr")]
+use crate::server_functions::event_feed::export_mosque_events_rss;
+#[cfg(feature = "ssr")]
+use crate::services::mosque_cache::MosqueCache;
+#[cfg(feature = "ssr")]
+use crate::services::notifier::{LogNotifier, Notifier};
+#[cfg(feature = "ssr")]
+use crate::utils::rate_limit::RateLimiters;
+#[cfg(feature = "ssr")]
+use crate::utils::ssr::request_id_middleware;
 
 pub mod app;
 #[cfg(feature = "ssr")]
@@ -32,6 +48,7 @@ pub mod database;
 pub mod errors;
 #[cfg(feature = "ssr")]
 pub mod jobs;
+pub mod messages;
 pub mod models;
 pub mod pages;
 #[cfg(feature = "ssr")]
@@ -42,7 +59,19 @@ pub mod utils;
 pub mod server_functions;
 
 #[cfg(feature = "ssr")]
-fn run(addr: TcpListener, conf: ConfFile, db: Surreal<Client>) -> std::io::Result<Server> {
+fn run<F>(
+    addr: TcpListener,
+    conf: ConfFile,
+    db: Surreal<Client>,
+    configure: F,
+) -> std::io::Result<Server>
+where
+    F: Fn(&mut web::ServiceConfig) + Clone + Send + 'static,
+{
+    let rate_limiters = web::Data::new(RateLimiters::from_env());
+    let mosque_cache = web::Data::new(MosqueCache::from_env());
+    let notifier: Arc<dyn Notifier> = Arc::new(LogNotifier);
+
     let server = HttpServer::new(move || {
         // Generate the list of routes in your Leptos App
         let routes = generate_route_list(App);
@@ -50,12 +79,14 @@ fn run(addr: TcpListener, conf: ConfFile, db: Surreal<Client>) -> std::io::Resul
         let site_root = leptos_options.site_root.clone().to_string();
 
         App::new()
+            .wrap(middleware::from_fn(request_id_middleware))
             // serve JS/WASM/CSS from `pkg`
             .service(Files::new("/pkg", format!("{site_root}/pkg")))
             // serve other assets from the `assets` directory
             .service(Files::new("/assets", &site_root))
             // serve the favicon from /favicon.ico
             .service(favicon)
+            .service(export_mosque_events_rss)
             .leptos_routes(routes, {
                 let leptos_options = leptos_options.clone();
                 move || {
@@ -78,6 +109,10 @@ fn run(addr: TcpListener, conf: ConfFile, db: Surreal<Client>) -> std::io::Resul
             })
             .app_data(web::Data::new(leptos_options.to_owned()))
             .app_data(web::Data::new(db.clone()))
+            .app_data(rate_limiters.clone())
+            .app_data(mosque_cache.clone())
+            .app_data(web::Data::from(notifier.clone()))
+            .configure(configure.clone())
     })
     .listen(addr)?
     .run();
@@ -99,6 +134,31 @@ async fn favicon(
 
 #[cfg(feature = "ssr")]
 pub fn spawn_app(db: Surreal<Client>) -> String {
+    spawn_app_with(db, |_| {})
+}
+
+/// Same as `spawn_app`, counting the database requests of every server function into
+/// `query_counter`.
+#[cfg(feature = "ssr")]
+pub fn spawn_app_with_query_counter(db: Surreal<Client>, query_counter: QueryCounter) -> String {
+    spawn_app_with(db, move |config| {
+        config.app_data(web::Data::new(query_counter.clone()));
+    })
+}
+
+/// Same as `spawn_app`, routing requests that name one of `tenants` to its database.
+#[cfg(feature = "multi_tenant")]
+pub fn spawn_app_with_tenants(db: Surreal<Client>, tenants: TenantConnections) -> String {
+    spawn_app_with(db, move |config| {
+        config.app_data(web::Data::new(tenants.clone()));
+    })
+}
+
+#[cfg(feature = "ssr")]
+fn spawn_app_with<F>(db: Surreal<Client>, configure: F) -> String
+where
+    F: Fn(&mut web::ServiceConfig) + Clone + Send + 'static,
+{
     let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind to a available port");
     let port = listener
         .local_addr()
@@ -106,7 +166,7 @@ pub fn spawn_app(db: Surreal<Client>) -> String {
         .port();
     let conf = get_configuration(Some("Cargo.toml")).unwrap();
 
-    let server = run(listener, conf, db).expect("Failed to bind the address");
+    let server = run(listener, conf, db, configure).expect("Failed to bind the address");
     let _handle = tokio::spawn(server);
 
     format!("http://127.0.0.1:{}", port)
@@ -1,3 +1,6 @@
+#[cfg(feature = "ssr")]
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
 #[cfg(feature = "ssr")]
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
@@ -9,25 +12,38 @@ async fn main() -> std::io::Result<()> {
     use leptos_meta::MetaTags;
     use merzah::app::*;
     use merzah::database::connection::init_db;
-    use merzah::jobs::event_rotation::start_scheduler;
+    #[cfg(feature = "multi_tenant")]
+    use merzah::database::tenants::TenantConnections;
+    use merzah::jobs::event_rotation::rotation_schedule_from_env;
+    use merzah::jobs::token_cleanup::cleanup_schedule_from_env;
+    use merzah::server_functions::event_feed::export_mosque_events_rss;
+    use merzah::services::mosque_cache::MosqueCache;
+    use merzah::services::notifier::{LogNotifier, Notifier};
+    use merzah::utils::rate_limit::RateLimiters;
+    use merzah::utils::ssr::request_id_middleware;
+    use std::sync::Arc;
+
+    let rotation_schedule = rotation_schedule_from_env();
+    let cleanup_schedule = cleanup_schedule_from_env();
 
     let db = init_db().await;
-    let db_for_scheduler = db.clone();
+    #[cfg(feature = "multi_tenant")]
+    let tenants = web::Data::new(TenantConnections::from_env().await);
 
-    tokio::spawn(async move {
-        loop {
-            match start_scheduler(db_for_scheduler.clone()).await {
-                Ok(()) => break,
-                Err(e) => {
-                    tracing::error!("Scheduler failed, retrying in 5s: {:?}", e);
-                    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                }
-            }
-        }
-    });
+    // Tenant databases hold events and sessions of their own, so each one gets the jobs too
+    #[cfg_attr(not(feature = "multi_tenant"), allow(unused_mut))]
+    let mut databases = vec![db.clone()];
+    #[cfg(feature = "multi_tenant")]
+    databases.extend(tenants.databases());
+    for database in databases {
+        start_background_jobs(database, &rotation_schedule, &cleanup_schedule);
+    }
 
     let conf = get_configuration(None).unwrap();
     let addr = conf.leptos_options.site_addr;
+    let rate_limiters = web::Data::new(RateLimiters::from_env());
+    let mosque_cache = web::Data::new(MosqueCache::from_env());
+    let notifier: Arc<dyn Notifier> = Arc::new(LogNotifier);
 
     HttpServer::new(move || {
         // Generate the list of routes in your Leptos App
@@ -37,13 +53,15 @@ async fn main() -> std::io::Result<()> {
 
         println!("listening on http://{}", &addr);
 
-        App::new()
+        let app = App::new()
+            .wrap(middleware::from_fn(request_id_middleware))
             // serve JS/WASM/CSS from `pkg`
             .service(Files::new("/pkg", format!("{site_root}/pkg")))
             // serve other assets from the `assets` directory
             .service(Files::new("/assets", &site_root))
             // serve the favicon from /favicon.ico
             .service(favicon)
+            .service(export_mosque_events_rss)
             .leptos_routes(routes, {
                 let leptos_options = leptos_options.clone();
                 move || {
@@ -69,12 +87,56 @@ async fn main() -> std::io::Result<()> {
             })
             .app_data(web::Data::new(leptos_options.to_owned()))
             .app_data(web::Data::new(db.clone()))
+            .app_data(rate_limiters.clone())
+            .app_data(mosque_cache.clone())
+            .app_data(web::Data::from(notifier.clone()));
+        // Requests naming a tenant go to its database instead of `db`
+        #[cfg(feature = "multi_tenant")]
+        let app = app.app_data(tenants.clone());
+
+        app
     })
     .bind(&addr)?
     .run()
     .await
 }
 
+/// Starts the scheduled jobs and the backfills on `db`. None of them may keep the server from
+/// starting, so their failures are only logged.
+#[cfg(feature = "ssr")]
+fn start_background_jobs(db: Surreal<Client>, rotation_schedule: &str, cleanup_schedule: &str) {
+    use merzah::jobs::event_rotation::start_scheduler_or_log;
+    use merzah::jobs::token_cleanup::start_token_cleanup_or_log;
+    use merzah::services::mosque_names::backfill_normalized_names;
+    use merzah::services::user_verification::backfill_verified_users;
+
+    let db_for_scheduler = db.clone();
+    let rotation_schedule = rotation_schedule.to_string();
+    tokio::spawn(async move {
+        start_scheduler_or_log(db_for_scheduler, &rotation_schedule).await;
+    });
+    let db_for_cleanup = db.clone();
+    let cleanup_schedule = cleanup_schedule.to_string();
+    tokio::spawn(async move {
+        start_token_cleanup_or_log(db_for_cleanup, &cleanup_schedule).await;
+    });
+    // Mosques saved before name search existed have nothing for it to match on
+    let db_for_backfill = db.clone();
+    tokio::spawn(async move {
+        match backfill_normalized_names(&db_for_backfill).await {
+            Ok(updated) => tracing::info!(updated, "Backfilled normalized mosque names"),
+            Err(e) => tracing::error!(?e, "Failed to backfill normalized mosque names"),
+        }
+    });
+    // Users saved before verification existed would otherwise lose favorites and RSVPs
+    tokio::spawn(async move {
+        match backfill_verified_users(&db).await {
+            Ok(updated) => tracing::info!(updated, "Backfilled verified users"),
+            Err(e) => tracing::error!(?e, "Failed to backfill verified users"),
+        }
+    });
+}
+
 #[cfg(feature = "ssr")]
 #[actix_web::get("favicon.ico")]
 async fn favicon(
@@ -0,0 +1,60 @@
+//! Success messages returned in `ApiResponse::data`, shared by the server functions and the
+//! tests that assert on them.
+
+// Auth
+pub const USER_REGISTERED: &str = "The user has been registered successfully";
+pub const USER_LOGGED_IN: &str = "The user has been logged in successfully";
+pub const USER_LOGGED_OUT: &str = "Successfully logged out the user";
+pub const MOBILE_OTP_SENT: &str = "A verification code has been sent to your mobile";
+pub const MOBILE_VERIFIED: &str = "Your mobile number has been verified";
+pub const MAGIC_LINK_SENT: &str =
+    "If an account exists for this email, a login link has been sent to it";
+pub const PASSWORD_RESET_SENT: &str =
+    "If an account exists for this identifier, a password reset link has been sent to it";
+pub const PASSWORD_RESET: &str = "Your password has been reset, please log in again";
+pub const PASSWORD_CHANGED: &str = "Your password has been changed";
+pub const VERIFICATION_SENT: &str = "A verification link has been sent";
+pub const ACCOUNT_VERIFIED: &str = "Your account has been verified";
+pub const ACCOUNT_DELETED: &str = "Your account has been deleted";
+pub const IDENTIFIER_ADDED: &str = "The identifier has been added to your account";
+pub const IDENTIFIER_REMOVED: &str = "The identifier has been removed from your account";
+
+// Mosques
+pub const PRAYER_TIMES_UPDATED: &str = "Successfully updated jamat and adhan times";
+pub const MOSQUE_DETAILS_UPDATED: &str = "Successfully updated the mosque details";
+pub const USER_ELEVATED_TO_REQUESTED_USER: &str = "Elevated the user to a requested_user";
+pub const MOSQUE_ADMIN_REMOVED: &str = "Removed the user as an admin of the mosque";
+pub const ADOPTION_REQUEST_APPROVED: &str =
+    "Approved the adoption request and made the user a mosque admin";
+pub const ADOPTION_REQUEST_REJECTED: &str = "Rejected the adoption request";
+pub const FAVORITE_MOSQUE_ADDED: &str = "Successfully added the mosque to user's favorite list";
+pub const FAVORITE_MOSQUE_REMOVED: &str =
+    "Successfully removed the mosque from favorite list of the user";
+
+// Events
+pub const EVENT_CREATED: &str = "Successfully created the event record Alhadulillah!";
+pub const EVENT_UPDATED: &str = "Successfully updated the event record";
+pub const EVENT_DELETED: &str = "Successfully deleted the event record";
+pub const EVENT_RESTORED: &str = "Successfully restored the event";
+pub const EVENT_OCCURRENCE_DELETED: &str = "Successfully deleted this occurrence of the event";
+pub const EVENT_RSVPED: &str = "Successfully RSVP'd to the event";
+pub const EVENT_RSVP_CANCELLED: &str = "Successfully cancelled the RSVP";
+pub const EVENT_WAITLISTED: &str = "The event is full, you have been added to its waitlist";
+pub const EVENT_EXCEPTION_ADDED: &str = "Successfully cancelled the occurrence";
+pub const EVENT_EXCEPTION_REMOVED: &str = "Successfully restored the occurrence";
+pub const ATTENDEES_NOTIFIED: &str = "Successfully notified the attendees";
+
+// Education
+pub const ROADMAP_STARTED: &str = "Roadmap started";
+pub const COURSE_ENROLLED: &str = "Enrolled successfully";
+pub const COURSE_UNENROLLED: &str = "Unenrolled successfully";
+pub const LESSON_COMPLETED: &str = "Lesson marked as completed";
+pub const COURSE_CREATED: &str = "Course created";
+pub const COURSE_UPDATED: &str = "Course updated";
+pub const COURSE_PUBLISHED: &str = "Course published";
+pub const MODULE_CREATED: &str = "Module created";
+pub const MODULE_UPDATED: &str = "Module updated";
+pub const MODULE_DELETED: &str = "Module deleted";
+pub const LESSON_CREATED: &str = "Lesson created";
+pub const LESSON_UPDATED: &str = "Lesson updated";
+pub const LESSON_DELETED: &str = "Lesson deleted";
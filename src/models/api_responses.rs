@@ -11,6 +11,9 @@ pub struct ApiResponse<T = String> {
     pub data: Option<T>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// What went wrong, for clients to branch on. `error` is only meant to be shown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<ErrorCode>,
 }
 
 impl<T> ApiResponse<T> {
@@ -18,6 +21,7 @@ impl<T> ApiResponse<T> {
         Self {
             data: Some(data),
             error: None,
+            code: None,
         }
     }
 
@@ -25,10 +29,36 @@ impl<T> ApiResponse<T> {
         Self {
             data: None,
             error: Some(error),
+            code: None,
+        }
+    }
+
+    pub fn error_with_code(code: ErrorCode, error: String) -> Self {
+        Self {
+            data: None,
+            error: Some(error),
+            code: Some(code),
         }
     }
 }
 
+/// The kind of failure behind an error response, serialized to a stable string such as
+/// `unauthenticated` or `validation_failed`. Each maps to the status the response is sent with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    BadRequest,
+    Unauthenticated,
+    Forbidden,
+    NotFound,
+    MethodNotAllowed,
+    ValidationFailed,
+    Conflict,
+    RateLimited,
+    Internal,
+    Unavailable,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct MosqueResponse {
     pub id: String,
@@ -42,4 +72,43 @@ pub struct MosqueResponse {
     pub muazzin: Option<UserOnClient>,
     pub imam_contact: Vec<UserIdentifierOnClient>,
     pub muazzin_contact: Vec<UserIdentifierOnClient>,
+    /// How far the mosque is from the searched point, for endpoints that search around one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distance_meters: Option<f64>,
+}
+
+/// The logged in user along with every identifier they can log in with.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct CurrentUserResponse {
+    pub user: UserOnClient,
+    pub identifiers: Vec<UserIdentifierOnClient>,
+}
+
+/// A mosque's prayer times on their own. Either set is `None` until the mosque's admins set it.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct PrayerTimesResponse {
+    pub adhan_times: Option<PrayerTimes>,
+    pub jamat_times: Option<PrayerTimes>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct MosqueListPage {
+    pub mosques: Vec<MosqueResponse>,
+    /// Number of mosques matching the filter across all pages.
+    pub total: usize,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+/// Outcome of importing the mosques of a bounding box from OpenStreetMap.
+///
+/// Replaced the sentence `add_mosques_of_region` used to return, which was a breaking change
+/// for clients reading `data` as a string.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct AddMosquesResult {
+    pub added: usize,
+    /// Mosques that were already stored and were left untouched.
+    pub skipped: usize,
+    /// The imported box as `[south, west, north, east]`.
+    pub region: [f64; 4],
 }
@@ -1,16 +1,27 @@
 use crate::models::user::Identifier;
+#[cfg(feature = "ssr")]
+use crate::models::user::normalize_mobile;
 use garde::Validate;
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "ssr")]
 use crate::errors::auth::AuthError;
 #[cfg(feature = "ssr")]
+use crate::utils::email_domain::{EmailDomainPolicy, email_domain, is_valid_email_domain};
+#[cfg(feature = "ssr")]
 use anyhow::{Result, anyhow};
 #[cfg(feature = "ssr")]
 use surrealdb::Surreal;
 #[cfg(feature = "ssr")]
 use surrealdb::engine::remote::ws::Client;
 
+/// The kind of client logging in, which decides how it receives its session.
+///
+/// Web clients get the session token in an `HttpOnly` `__Host-session` cookie and never see
+/// it. Mobile clients get the raw token in `ApiResponse::data` and send it back in an
+/// `Authorization: Bearer` header. Clients should also name themselves in an
+/// `X-Client: web|mobile` header; a request whose header names the other platform is
+/// rejected, while weaker hints such as a bearer token on a web login are only logged.
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
 pub enum Platform {
     #[serde(rename = "web")]
@@ -19,6 +30,24 @@ pub enum Platform {
     Mobile,
 }
 
+impl Platform {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Platform::Web => "web",
+            Platform::Mobile => "mobile",
+        }
+    }
+
+    /// Parses the value of the `X-Client` header.
+    pub fn from_client_header(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "web" => Some(Platform::Web),
+            "mobile" => Some(Platform::Mobile),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Validate, Deserialize, Serialize, Clone)]
 pub struct RegistrationFormData {
     #[garde(length(min = 2, max = 100))]
@@ -31,6 +60,13 @@ pub struct RegistrationFormData {
     pub platform: Platform,
 }
 
+/// A password being set on an existing account, held to the same rules as registration.
+#[derive(Debug, Validate, Deserialize, Serialize, Clone)]
+pub struct NewPassword {
+    #[garde(length(min = 8))]
+    pub password: String,
+}
+
 #[derive(Debug, Validate, Deserialize, Serialize, Clone)]
 pub struct LoginFormData {
     #[garde(dive)]
@@ -52,30 +88,67 @@ impl RegistrationFormData {
         }
     }
 
-    pub async fn validate_uniqueness(&self, db: &Surreal<Client>) -> Result<()> {
-        let (identifier_type, identifier_value) = match &self.identifier {
-            Identifier::Email(email) => ("email", email.to_string()),
-            Identifier::Mobile(mobile) => ("mobile", mobile.to_string()),
-            Identifier::Google(_) | Identifier::Meta(_) | Identifier::Instagram(_) => {
-                return Err(anyhow!("OAuth identifiers cannot be manually registered"));
-            }
+    /// Same as `new`, for the common case of a registration coming from the web app.
+    pub fn new_web(name: String, identifier: Identifier, password: String) -> Self {
+        Self::new(name, identifier, password, Platform::Web)
+    }
+
+    /// Rejects email addresses whose domain is malformed or blocked by `policy`. Mobile
+    /// numbers always pass.
+    pub fn validate_email_domain(&self, policy: &EmailDomainPolicy) -> Result<(), AuthError> {
+        let Identifier::Email(email) = &self.identifier else {
+            return Ok(());
         };
 
-        let mut result = db
-            .query("SELECT * FROM user_identifier WHERE identifier_type = $type AND identifier_value = $value")
-            .bind(("type", identifier_type))
-            .bind(("value", identifier_value))
-            .await
-            .map_err(|e| AuthError::DatabaseError(Box::new(e)))?;
-
-        let res: Vec<serde_json::Value> = result
-            .take(0)
-            .map_err(|_| anyhow!("Failed to parse query result"))?;
-
-        if !res.is_empty() {
-            Err(AuthError::NotUniqueError(identifier_type.to_string()))?
-        } else {
-            Ok(())
+        let domain = email_domain(email).unwrap_or_default();
+        if !is_valid_email_domain(domain) {
+            return Err(AuthError::InvalidEmailDomain(domain.to_string()));
+        }
+
+        if policy.is_blocked(domain) {
+            return Err(AuthError::BlockedEmailDomain(domain.to_lowercase()));
         }
+
+        Ok(())
+    }
+
+    pub async fn validate_uniqueness(&self, db: &Surreal<Client>) -> Result<()> {
+        validate_identifier_uniqueness(&self.identifier, db).await
+    }
+}
+
+/// Makes sure no account is using `identifier` yet. Only email and mobile identifiers can be
+/// taken on by hand, OAuth ones are linked by signing in with the provider.
+#[cfg(feature = "ssr")]
+pub async fn validate_identifier_uniqueness(
+    identifier: &Identifier,
+    db: &Surreal<Client>,
+) -> Result<()> {
+    let (identifier_type, identifier_value) = match identifier {
+        Identifier::Email(email) => ("email", email.to_string()),
+        Identifier::Mobile(mobile) => ("mobile", normalize_mobile(mobile)),
+        Identifier::Google(_)
+        | Identifier::Meta(_)
+        | Identifier::Instagram(_)
+        | Identifier::Workos(_) => {
+            return Err(AuthError::OAuthIdentifier.into());
+        }
+    };
+
+    let mut result = db
+        .query("SELECT * FROM user_identifier WHERE identifier_type = $type AND identifier_value = $value")
+        .bind(("type", identifier_type))
+        .bind(("value", identifier_value))
+        .await
+        .map_err(|e| AuthError::DatabaseError(Box::new(e)))?;
+
+    let res: Vec<serde_json::Value> = result
+        .take(0)
+        .map_err(|_| anyhow!("Failed to parse query result"))?;
+
+    if !res.is_empty() {
+        Err(AuthError::NotUniqueError(identifier_type.to_string()))?
+    } else {
+        Ok(())
     }
 }
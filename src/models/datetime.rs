@@ -0,0 +1,20 @@
+//! Dates are kept in one of two shapes. Timestamps the database sets or compares, such as
+//! `created_at` and token expiries, are `datetime` fields read as `surrealdb::Datetime`,
+//! which is always UTC. Event dates are stored as RFC 3339 strings read as
+//! `DateTime<FixedOffset>`, so the offset the organizer picked survives the round trip, and
+//! queries cast them with `<datetime>` where they need to compare them.
+//!
+//! The helpers here convert between the two where a `Datetime` is handed to the client.
+
+use chrono::{DateTime, FixedOffset, Utc};
+use surrealdb::Datetime;
+
+/// The same instant as `datetime`, in UTC.
+pub fn to_fixed_offset(datetime: Datetime) -> DateTime<FixedOffset> {
+    DateTime::<Utc>::from(datetime).fixed_offset()
+}
+
+/// The same instant as `date`, dropping its offset.
+pub fn to_datetime(date: DateTime<FixedOffset>) -> Datetime {
+    Datetime::from(date.with_timezone(&Utc))
+}
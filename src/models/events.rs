@@ -1,13 +1,17 @@
 #[cfg(feature = "ssr")]
-use crate::models::api_responses::ApiResponse;
-use chrono::{DateTime, FixedOffset};
+use crate::models::api_responses::{ApiResponse, ErrorCode};
+use chrono::{DateTime, FixedOffset, Utc};
 use garde::Validate;
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "ssr")]
-use surrealdb::RecordId;
+use surrealdb::{Datetime, RecordId};
 
+#[cfg(feature = "ssr")]
+use crate::services::recurrence::end_date_after_occurrences_in_zone;
 #[cfg(feature = "ssr")]
 use crate::utils::parsing::parse_record_id;
+#[cfg(feature = "ssr")]
+use chrono_tz::Tz;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -29,7 +33,7 @@ pub enum EventCategory {
 }
 
 #[cfg(feature = "ssr")]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Event {
     pub id: RecordId,
     pub title: String,
@@ -40,6 +44,25 @@ pub struct Event {
     pub speaker: Option<String>,
     pub recurrence_pattern: Option<EventRecurrence>,
     pub recurrence_end_date: Option<DateTime<FixedOffset>>,
+    /// Number of occurrences in the series, for series bounded by a count.
+    pub recurrence_count: Option<u32>,
+    /// Number of times the event has moved on to its next occurrence.
+    #[serde(default)]
+    pub occurrences_rotated: u32,
+    /// Cancelled occurrences. Rotation skips any occurrence falling on one of these days.
+    #[serde(default)]
+    pub exception_dates: Vec<DateTime<FixedOffset>>,
+    pub timezone: Option<String>,
+    /// RSVPs are refused once this has passed.
+    #[serde(default)]
+    pub rsvp_deadline: Option<DateTime<FixedOffset>>,
+    /// Most people who can attend, later RSVPs join the waitlist.
+    #[serde(default)]
+    pub capacity: Option<u32>,
+    /// Set once the event is deleted. Deleted events are hidden but keep their RSVPs, so an
+    /// admin can restore them.
+    #[serde(default)]
+    pub deleted_at: Option<Datetime>,
 }
 
 // To be used on client side, where we don't have access to RecordId
@@ -49,8 +72,35 @@ pub struct EventDetails {
     pub title: String,
     pub description: String,
     pub category: EventCategory,
+    /// Carries the offset the mosque set the event in, so it reads as the mosque's local time.
     pub date: DateTime<FixedOffset>,
     pub speaker: Option<String>,
+    /// IANA name of the event's zone, falling back to the hosting mosque's.
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// Clients disable RSVPing once this has passed.
+    #[serde(default)]
+    pub rsvp_deadline: Option<DateTime<FixedOffset>>,
+}
+
+#[cfg(feature = "ssr")]
+impl EventDetails {
+    /// The cursor for a page that ends on this event.
+    pub fn cursor(&self) -> EventCursor {
+        EventCursor {
+            date: self.date,
+            id: self.id.clone(),
+        }
+    }
+
+    /// Moves `date` to the offset of `zone`. The instant it refers to stays the same.
+    pub fn with_date_in(mut self, zone: Tz) -> Self {
+        self.date = self.date.with_timezone(&zone).fixed_offset();
+        self.rsvp_deadline = self
+            .rsvp_deadline
+            .map(|deadline| deadline.with_timezone(&zone).fixed_offset());
+        self
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -64,6 +114,20 @@ pub enum EventRecurrence {
     Monthly,
     Quaterly,
     Yearly,
+    /// Every `every` days, weeks, months or years, e.g. every 3 weeks.
+    Custom {
+        unit: RecurrenceUnit,
+        every: u32,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RecurrenceUnit {
+    Day,
+    Week,
+    Month,
+    Year,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -73,6 +137,23 @@ pub enum Interval {
     SixMonths,
     OneYear,
     Indefinite,
+    /// Ends the series after this many occurrences, the first one included. The count is
+    /// stored with the event and enforced on rotation, alongside the matching end date.
+    Count(u32),
+}
+
+/// Most occurrences `Interval::Count` accepts. Its end date is worked out one occurrence at
+/// a time while handling the request, so the count has to stay small.
+pub const MAX_OCCURRENCES: u32 = 1000;
+
+/// What `delete_event` removes. A recurring series is stored as a single row that moves to
+/// its next date, so deleting `This` occurrence moves the row on instead of deleting it.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DeleteScope {
+    This,
+    #[default]
+    Series,
 }
 
 #[derive(Debug, Validate, Deserialize, Serialize, Clone)]
@@ -83,7 +164,8 @@ pub struct CreateEvent {
     pub description: String,
     #[garde(skip)]
     pub category: EventCategory,
-    #[garde(skip)]
+    /// The date of the event, or of the first occurrence of a recurring one.
+    #[garde(custom(is_in_the_future))]
     pub date: DateTime<FixedOffset>,
     #[garde(skip)]
     pub mosque: String,
@@ -95,6 +177,28 @@ pub struct CreateEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[garde(skip)]
     pub recurrence_duration: Option<Interval>,
+    /// IANA name of the zone the event is held in, e.g. `America/Detroit`. Recurring events
+    /// with one keep their local time across DST changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[garde(skip)]
+    pub timezone: Option<String>,
+    /// When RSVPs close, for events that need a headcount ahead of time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[garde(skip)]
+    pub rsvp_deadline: Option<DateTime<FixedOffset>>,
+    /// Most people who can attend, for events with limited space.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[garde(range(min = 1))]
+    pub capacity: Option<u32>,
+}
+
+/// Past dates would have the rotation job move the event on as soon as it is created.
+fn is_in_the_future(date: &DateTime<FixedOffset>, _context: &()) -> garde::Result {
+    if *date <= Utc::now() {
+        return Err(garde::Error::new("The event's date must be in the future"));
+    }
+
+    Ok(())
 }
 
 #[cfg(feature = "ssr")]
@@ -102,15 +206,62 @@ impl TryFrom<CreateEvent> for EventRecord {
     type Error = ApiResponse<String>;
 
     fn try_from(create: CreateEvent) -> Result<Self, Self::Error> {
+        let zone = match create.timezone.as_deref().map(str::parse::<Tz>).transpose() {
+            Ok(zone) => zone,
+            Err(_) => {
+                return Err(invalid_recurrence(
+                    "The timezone must be an IANA name such as America/Detroit",
+                ));
+            }
+        };
+
+        if let Some(EventRecurrence::Custom { every: 0, .. }) = create.recurrence_pattern {
+            return Err(invalid_recurrence(
+                "A custom recurrence must repeat at least every 1 unit",
+            ));
+        }
+
         let recurrence_end_date = match create.recurrence_duration {
             Some(Interval::OneMonth) => Some(create.date + chrono::Duration::days(30)),
             Some(Interval::ThreeMonths) => Some(create.date + chrono::Duration::days(90)),
             Some(Interval::SixMonths) => Some(create.date + chrono::Duration::days(180)),
             Some(Interval::OneYear) => Some(create.date + chrono::Duration::days(365)),
             Some(Interval::Indefinite) => Some(create.date + chrono::Duration::days(365 * 100)),
+            Some(Interval::Count(occurrences)) => {
+                let Some(pattern) = create.recurrence_pattern.clone() else {
+                    return Err(invalid_recurrence(
+                        "A recurrence pattern is required when limiting the number of occurrences",
+                    ));
+                };
+
+                if occurrences == 0 {
+                    return Err(invalid_recurrence(
+                        "The number of occurrences must be at least 1",
+                    ));
+                }
+                if occurrences > MAX_OCCURRENCES {
+                    return Err(invalid_recurrence(&format!(
+                        "The number of occurrences must be at most {MAX_OCCURRENCES}"
+                    )));
+                }
+
+                match end_date_after_occurrences_in_zone(create.date, pattern, occurrences, zone) {
+                    Some(end_date) => Some(end_date),
+                    None => {
+                        return Err(invalid_recurrence(
+                            "One of the occurrences falls on a date that cannot be scheduled",
+                        ));
+                    }
+                }
+            }
             None => None,
         };
 
+        let recurrence_count = match create.recurrence_duration {
+            Some(Interval::Count(occurrences)) => Some(occurrences),
+            _ => None,
+        };
+
         let mosque = parse_record_id::<String>(&create.mosque, "mosque")?;
 
         Ok(Self {
@@ -122,10 +273,28 @@ impl TryFrom<CreateEvent> for EventRecord {
             speaker: create.speaker,
             recurrence_pattern: create.recurrence_pattern,
             recurrence_end_date,
+            recurrence_count,
+            timezone: create.timezone,
+            rsvp_deadline: create.rsvp_deadline,
+            capacity: create.capacity,
         })
     }
 }
 
+#[cfg(feature = "ssr")]
+fn invalid_recurrence(message: &str) -> ApiResponse<String> {
+    use actix_web::http::StatusCode;
+    use leptos::prelude::expect_context;
+    use leptos_actix::ResponseOptions;
+
+    tracing::error!("Invalid recurrence: {}", message);
+
+    let response_options = expect_context::<ResponseOptions>();
+    response_options.set_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+    ApiResponse::error_with_code(ErrorCode::ValidationFailed, message.to_string())
+}
+
 #[cfg(feature = "ssr")]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct EventRecord {
@@ -137,6 +306,29 @@ pub struct EventRecord {
     pub speaker: Option<String>,
     pub recurrence_pattern: Option<EventRecurrence>,
     pub recurrence_end_date: Option<DateTime<FixedOffset>>,
+    pub recurrence_count: Option<u32>,
+    pub timezone: Option<String>,
+    pub rsvp_deadline: Option<DateTime<FixedOffset>>,
+    pub capacity: Option<u32>,
+}
+
+/// What the scheduler did with a recurring event whose date had passed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RotationOutcome {
+    Rotated,
+    /// The series ended, so the event was removed instead of rotated.
+    Deleted,
+    Failed,
+}
+
+/// A row of `rotation_log`, kept so admins can see why a recurring event stopped moving.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RotationLogEntry {
+    pub event: RecordId,
+    pub outcome: RotationOutcome,
+    pub reason: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, Clone)]
@@ -167,6 +359,20 @@ pub struct UpdatedEvent {
     pub recurrence_end_date: Option<DateTime<FixedOffset>>,
 }
 
+impl UpdatedEvent {
+    /// Whether the update leaves every field of the event as it is.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.description.is_none()
+            && self.category.is_none()
+            && self.date.is_none()
+            && self.mosque.is_none()
+            && self.speaker.is_none()
+            && self.recurrence_pattern.is_none()
+            && self.recurrence_end_date.is_none()
+    }
+}
+
 #[cfg(feature = "ssr")]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct UpdatedEventRecord {
@@ -188,6 +394,31 @@ pub struct UpdatedEventRecord {
     pub recurrence_end_date: Option<DateTime<FixedOffset>>,
 }
 
+#[cfg(feature = "ssr")]
+impl UpdatedEventRecord {
+    /// Checks that `event` with this update applied still takes place in the future and no
+    /// later than the end of its series.
+    pub fn validate_against(&self, event: &Event) -> Result<(), ApiResponse<String>> {
+        if self.date.is_none() && self.recurrence_end_date.is_none() {
+            return Ok(());
+        }
+
+        let date = self.date.unwrap_or(event.date);
+        if self.date.is_some() && date <= Utc::now() {
+            return Err(invalid_recurrence("The event's date must be in the future"));
+        }
+
+        let end_date = self.recurrence_end_date.or(event.recurrence_end_date);
+        if end_date.is_some_and(|end_date| date > end_date) {
+            return Err(invalid_recurrence(
+                "The event's date must not be after the end of its recurrence",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "ssr")]
 impl TryFrom<UpdatedEvent> for UpdatedEventRecord {
     type Error = ApiResponse<String>;
@@ -223,12 +454,46 @@ impl PersonalEvent {
     }
 }
 
-#[cfg(feature = "ssr")]
+/// How many upcoming events of `category` a mosque hosts.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct EventCategoryCount {
+    pub category: EventCategory,
+    pub count: usize,
+}
+
+/// An event on a mosque's public page. `rsvp` is only there when a logged in user asked.
 #[derive(Debug, Deserialize, Serialize)]
+pub struct PublicEvent {
+    pub event: EventDetails,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rsvp: Option<bool>,
+}
+
+impl PublicEvent {
+    pub fn new(event: EventDetails, rsvp: Option<bool>) -> Self {
+        Self { event, rsvp }
+    }
+}
+
+/// An event in the favorite and nearby feed, or whatever was left in its place when the
+/// traversal reached a record that is gone, such as the events of a deleted mosque.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum FeedEntry {
+    Event(EventDetails),
+    Broken(serde::de::IgnoredAny),
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Deserialize)]
 pub struct FavoriteAndNearbyEventsQueryResult {
-    pub favorite_events: Vec<EventDetails>,
-    pub attending_events: Vec<String>,
-    pub nearby_events: Vec<EventDetails>,
+    #[serde(default)]
+    pub favorite_events: Vec<FeedEntry>,
+    #[serde(default)]
+    pub attending_events: Vec<Option<String>>,
+    #[serde(default)]
+    pub nearby_events: Vec<FeedEntry>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -243,6 +508,36 @@ impl EventSummary {
     }
 }
 
+/// An event the user has RSVP'd to, with the name of the mosque hosting it.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RsvpEvent {
+    pub event: EventDetails,
+    pub mosque_name: Option<String>,
+}
+
+/// One mosque's events, as returned when fetching the events of several mosques at once.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MosqueEvents {
+    pub mosque_id: String,
+    pub events: Vec<PersonalEvent>,
+}
+
+/// Where a page of events ended. Events sharing a date are ordered by id, so the id keeps a
+/// page boundary between them from skipping or repeating any.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventCursor {
+    pub date: DateTime<FixedOffset>,
+    pub id: String,
+}
+
+/// A page of events in date order. Passing `next_cursor` back as `after` fetches the next
+/// page, it is `None` on the last one.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventPage<T> {
+    pub events: T,
+    pub next_cursor: Option<EventCursor>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum FetchedEvents {
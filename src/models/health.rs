@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// What `health_check` reports when the app can serve requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthStatus {
+    pub database_connected: bool,
+    pub version: String,
+}
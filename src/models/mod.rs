@@ -1,15 +1,23 @@
 pub mod api_responses;
 pub mod auth;
+#[cfg(feature = "ssr")]
+pub mod datetime;
 pub mod education;
 pub mod events;
 pub mod form;
 pub mod gamification;
+pub mod health;
 pub mod import;
 pub mod mosque;
 #[cfg(feature = "ssr")]
 pub mod oauth;
+#[cfg(feature = "ssr")]
+pub mod password_reset;
 pub mod quiz;
+pub mod redacted;
 pub mod roadmap;
 #[cfg(feature = "ssr")]
 pub mod session;
 pub mod user;
+#[cfg(feature = "ssr")]
+pub mod verification;
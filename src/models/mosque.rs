@@ -10,17 +10,78 @@ use surrealdb::sql::Geometry;
 use crate::models::api_responses::MosqueResponse;
 #[cfg(feature = "ssr")]
 use crate::models::user::User;
+use crate::models::user::UserOnClient;
+#[cfg(feature = "ssr")]
+use crate::utils::mosque_name::normalize_mosque_name;
 
 #[cfg(feature = "ssr")]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MosqueFromOverpass {
     pub id: RecordId,
     pub name: Option<String>,
+    /// Precomputed by `normalize_mosque_name` to keep name searches cheap.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalized_name: Option<String>,
     pub location: Geometry,
     pub street: Option<String>,
     pub city: Option<String>,
 }
 
+#[cfg(feature = "ssr")]
+impl MosqueFromOverpass {
+    pub fn new(
+        id: RecordId,
+        name: Option<String>,
+        location: Geometry,
+        street: Option<String>,
+        city: Option<String>,
+    ) -> Self {
+        let normalized_name = name.as_deref().map(normalize_mosque_name);
+
+        Self {
+            id,
+            name,
+            normalized_name,
+            location,
+            street,
+            city,
+        }
+    }
+}
+
+/// A mosque added by hand rather than imported, keeping track of who added it.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Serialize)]
+pub struct ManualMosqueRecord {
+    pub name: String,
+    pub normalized_name: String,
+    pub location: Geometry,
+    pub street: Option<String>,
+    pub city: Option<String>,
+    pub created_by: RecordId,
+}
+
+/// Corrections to the details of a mosque, typically ones OpenStreetMap got wrong. Unset
+/// fields are left untouched by the merge.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Serialize, Default)]
+pub struct MosqueDetailsUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalized_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Deserialize)]
+pub struct MosqueCreator {
+    pub created_by: Option<User>,
+}
+
 #[cfg(feature = "ssr")]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MosqueSearchResult {
@@ -34,10 +95,13 @@ pub struct MosqueSearchResult {
     pub jamat_times: Option<PrayerTimes>,
     pub imam: Option<User>,
     pub muazzin: Option<User>,
+    /// The `distance` a location search selects, missing for every other query.
+    #[serde(default, rename = "distance")]
+    pub distance_meters: Option<f64>,
 }
 
 #[cfg(feature = "ssr")]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct MosqueRecord {
     pub id: RecordId,
     #[cfg_attr(feature = "ssr", serde(deserialize_with = "deserialize_surreal_point"))]
@@ -89,6 +153,7 @@ impl MosqueSearchResult {
             muazzin_contact: vec![],
             imam,
             muazzin,
+            distance_meters: self.distance_meters,
         }
     }
 }
@@ -124,6 +189,143 @@ pub struct Tags {
     pub city: Option<String>,
 }
 
+/// A GeoJSON `FeatureCollection` of mosques, used for bulk import and export.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GeoJsonFeatureCollection {
+    #[serde(rename = "type")]
+    pub collection_type: String,
+    pub features: Vec<GeoJsonFeature>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GeoJsonFeature {
+    #[serde(rename = "type")]
+    pub feature_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<GeoJsonId>,
+    pub geometry: GeoJsonGeometry,
+    #[serde(default)]
+    pub properties: GeoJsonMosqueProperties,
+}
+
+/// GeoJSON allows feature ids to be either numbers (e.g. OSM ids) or strings.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum GeoJsonId {
+    Number(i64),
+    Text(String),
+}
+
+/// Coordinates follow the GeoJSON order: `[longitude, latitude]`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GeoJsonGeometry {
+    #[serde(rename = "type")]
+    pub geometry_type: String,
+    pub coordinates: Vec<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct GeoJsonMosqueProperties {
+    pub name: Option<String>,
+    pub street: Option<String>,
+    pub city: Option<String>,
+    /// Only set on export, ignored on import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_imam: Option<bool>,
+    /// Only set on export, ignored on import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_muazzin: Option<bool>,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Deserialize)]
+pub struct MosqueExportRecord {
+    pub key: GeoJsonId,
+    #[cfg_attr(feature = "ssr", serde(deserialize_with = "deserialize_surreal_point"))]
+    pub location: (f64, f64),
+    pub name: Option<String>,
+    pub street: Option<String>,
+    pub city: Option<String>,
+    pub has_imam: bool,
+    pub has_muazzin: bool,
+}
+
+#[cfg(feature = "ssr")]
+impl From<MosqueExportRecord> for GeoJsonFeature {
+    fn from(record: MosqueExportRecord) -> Self {
+        let (lat, lon) = record.location;
+
+        GeoJsonFeature {
+            feature_type: "Feature".to_string(),
+            id: Some(record.key),
+            geometry: GeoJsonGeometry {
+                geometry_type: "Point".to_string(),
+                coordinates: vec![lon, lat],
+            },
+            properties: GeoJsonMosqueProperties {
+                name: record.name,
+                street: record.street,
+                city: record.city,
+                has_imam: Some(record.has_imam),
+                has_muazzin: Some(record.has_muazzin),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MosqueNormalizedName {
+    pub id: RecordId,
+    pub normalized_name: String,
+}
+
+#[cfg(feature = "ssr")]
+impl GeoJsonFeature {
+    /// Validates the feature and converts it into a mosque ready for insertion.
+    ///
+    /// Features without an id get one derived from their coordinates so that re-importing
+    /// the same file does not create duplicates.
+    pub fn to_mosque(&self) -> Result<MosqueFromOverpass, String> {
+        if self.feature_type != "Feature" {
+            return Err(format!(
+                "expected type 'Feature', found '{}'",
+                self.feature_type
+            ));
+        }
+
+        if self.geometry.geometry_type != "Point" {
+            return Err(format!(
+                "expected a 'Point' geometry, found '{}'",
+                self.geometry.geometry_type
+            ));
+        }
+
+        let (lon, lat) = match self.geometry.coordinates.as_slice() {
+            [lon, lat] => (*lon, *lat),
+            _ => return Err("point coordinates must be [longitude, latitude]".to_string()),
+        };
+
+        if !(-180.0..=180.0).contains(&lon) || !(-90.0..=90.0).contains(&lat) {
+            return Err(format!("coordinates ({}, {}) are out of range", lon, lat));
+        }
+
+        let id = match &self.id {
+            Some(GeoJsonId::Number(id)) => RecordId::from(("mosques", *id)),
+            Some(GeoJsonId::Text(id)) => RecordId::from(("mosques", id.as_str())),
+            None => RecordId::from(("mosques", format!("{:.6}_{:.6}", lat, lon))),
+        };
+
+        Ok(MosqueFromOverpass::new(
+            id,
+            self.properties.name.clone(),
+            Geometry::Point((lon, lat).into()),
+            self.properties.street.clone(),
+            self.properties.city.clone(),
+        ))
+    }
+}
+
 /// Prayer times stored in the database as strings ("HH:MM:SS" format)
 /// Use this for creating/updating prayer_times records
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -136,12 +338,81 @@ pub struct PrayerTimes {
     pub jummah: NaiveTime,
 }
 
+impl PrayerTimes {
+    /// Checks that the daily prayers run from fajr to isha, naming the first pair that is out
+    /// of order. Jummah replaces dhuhr on Fridays, so it is not ordered against the others.
+    pub fn check_order(&self) -> Result<(), String> {
+        let daily = [
+            ("fajr", self.fajr),
+            ("dhuhr", self.dhuhr),
+            ("asr", self.asr),
+            ("maghrib", self.maghrib),
+            ("isha", self.isha),
+        ];
+
+        for pair in daily.windows(2) {
+            let (earlier, earlier_time) = pair[0];
+            let (later, later_time) = pair[1];
+            if earlier_time >= later_time {
+                return Err(format!("{earlier} must be before {later}"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PrayerTimesUpdate {
     pub adhan_times: Option<PrayerTimes>,
     pub jamat_times: Option<PrayerTimes>,
 }
 
+impl PrayerTimesUpdate {
+    pub fn check_order(&self) -> Result<(), String> {
+        if let Some(adhan_times) = &self.adhan_times {
+            adhan_times
+                .check_order()
+                .map_err(|e| format!("adhan_times: {e}"))?;
+        }
+        if let Some(jamat_times) = &self.jamat_times {
+            jamat_times
+                .check_order()
+                .map_err(|e| format!("jamat_times: {e}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Narrows down the app-admin mosque list. Every unset field matches all mosques.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct MosqueListFilter {
+    pub has_admin: Option<bool>,
+    /// Whether the mosque has adhan or jamat times set.
+    pub has_prayer_times: Option<bool>,
+    /// Case-insensitive exact match on the city.
+    pub city: Option<String>,
+    /// Only mosques whose details have not been updated for at least this many days.
+    pub stale_for_days: Option<u32>,
+}
+
+/// A 1-based page of results.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MosquePage {
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl Default for MosquePage {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            per_page: 20,
+        }
+    }
+}
+
 #[cfg(feature = "ssr")]
 #[derive(Debug, Deserialize)]
 pub struct MosqueData {
@@ -153,3 +424,85 @@ pub struct MosqueData {
     pub adhan_times: Option<PrayerTimes>,
     pub jamat_times: Option<PrayerTimes>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AdoptionRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AdoptionRequestOnClient {
+    pub id: String,
+    pub user: UserOnClient,
+    pub mosque_id: String,
+    pub mosque_name: Option<String>,
+    pub status: AdoptionRequestStatus,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Serialize)]
+pub struct NewAdoptionRequest {
+    pub user: RecordId,
+    pub mosque: RecordId,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Deserialize)]
+pub struct AdoptionRequest {
+    pub id: RecordId,
+    pub user: RecordId,
+    pub mosque: RecordId,
+    pub status: AdoptionRequestStatus,
+}
+
+/// An adoption request with the requesting user fetched, as listed to supervisors.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Deserialize)]
+pub struct AdoptionRequestDetails {
+    pub id: RecordId,
+    pub user: User,
+    pub mosque: RecordId,
+    pub mosque_name: Option<String>,
+    pub status: AdoptionRequestStatus,
+}
+
+#[cfg(feature = "ssr")]
+impl From<AdoptionRequestDetails> for AdoptionRequestOnClient {
+    fn from(request: AdoptionRequestDetails) -> Self {
+        Self {
+            id: request.id.to_string(),
+            user: request.user.into(),
+            mosque_id: request.mosque.to_string(),
+            mosque_name: request.mosque_name,
+            status: request.status,
+        }
+    }
+}
+
+/// A user administering a mosque, and who made them an admin of it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MosqueAdminOnClient {
+    pub user: UserOnClient,
+    /// `None` once the user who granted it has been deleted.
+    pub granted_by: Option<UserOnClient>,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Deserialize)]
+pub struct MosqueAdminDetails {
+    pub user: User,
+    pub granted_by: Option<User>,
+}
+
+#[cfg(feature = "ssr")]
+impl From<MosqueAdminDetails> for MosqueAdminOnClient {
+    fn from(admin: MosqueAdminDetails) -> Self {
+        Self {
+            user: admin.user.into(),
+            granted_by: admin.granted_by.map(Into::into),
+        }
+    }
+}
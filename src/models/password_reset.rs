@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::{RecordId, sql::Datetime};
+
+use crate::models::redacted::Redacted;
+
+#[derive(Debug, Serialize)]
+pub struct CreatePasswordReset {
+    pub user: RecordId,
+    pub token: Redacted<String>,
+    pub expires_at: Datetime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PasswordReset {
+    pub id: RecordId,
+    pub user: RecordId,
+    pub expires_at: Datetime,
+    pub created_at: Datetime,
+}
@@ -1,4 +1,4 @@
-use crate::models::user::User;
+use crate::models::{redacted::Redacted, user::User};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "ssr")]
@@ -10,7 +10,7 @@ use surrealdb::sql::Datetime;
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateSession {
     pub user: RecordId,
-    pub session_token: String,
+    pub session_token: Redacted<String>,
     pub expires_at: Datetime,
 }
 
@@ -19,7 +19,7 @@ pub struct CreateSession {
 pub struct Session {
     pub id: RecordId,
     pub user: RecordId,
-    pub session_token: String,
+    pub session_token: Redacted<String>,
     pub expires_at: Datetime,
     pub created_at: Datetime,
 }
@@ -29,7 +29,7 @@ pub struct Session {
 pub struct SessionWithUser {
     pub id: RecordId,
     pub user: User,
-    pub session_token: String,
+    pub session_token: Redacted<String>,
     pub expires_at: Datetime,
     pub created_at: Datetime,
 }
@@ -38,7 +38,7 @@ pub struct SessionWithUser {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UpdateSession {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub session_token: Option<String>,
+    pub session_token: Option<Redacted<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expires_at: Option<Datetime>,
 }
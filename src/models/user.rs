@@ -9,31 +9,90 @@ use surrealdb::{Datetime, RecordId};
 pub struct CreateUser {
     pub display_name: String,
     pub password_hash: String,
+    pub verified: bool,
 }
 
 #[cfg(feature = "ssr")]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct User {
     pub id: RecordId,
     pub created_at: Datetime,
     pub display_name: String,
+    #[serde(skip_serializing)]
     pub password_hash: String,
     pub role: String,
     pub updated_at: Datetime,
+    #[serde(default)]
+    pub verified: bool,
+}
+
+/// The roles of the mosque side of the app, ordered from least to most privileged. Education
+/// roles such as `educator` are kept outside of this ladder, and administering a mosque is the
+/// `handles` edge rather than a role.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    #[serde(rename = "regular")]
+    Regular,
+    #[serde(rename = "mosque_supervisor")]
+    MosqueSupervisor,
+    #[serde(rename = "app_admin")]
+    AppAdmin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Regular => "regular",
+            Role::MosqueSupervisor => "mosque_supervisor",
+            Role::AppAdmin => "app_admin",
+        }
+    }
+
+    /// Parses a role as it is stored on `users.role`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "regular" => Some(Role::Regular),
+            "mosque_supervisor" => Some(Role::MosqueSupervisor),
+            "app_admin" => Some(Role::AppAdmin),
+            _ => None,
+        }
+    }
+
+    /// The role as it reads in a sentence, e.g. "mosque supervisor".
+    pub fn label(&self) -> String {
+        self.as_str().replace('_', " ")
+    }
+
+    /// Whether an app admin may hand out this role through `elevate_user`. Going back to
+    /// `regular` is a demotion, and app admins aren't made through the API.
+    pub fn is_elevation_target(&self) -> bool {
+        matches!(self, Role::MosqueSupervisor)
+    }
 }
 
 #[cfg(feature = "ssr")]
 impl User {
+    /// The user's role, or `None` when it is not one of the mosque roles.
+    pub fn parsed_role(&self) -> Option<Role> {
+        Role::parse(&self.role)
+    }
+
     pub fn is_app_admin(&self) -> bool {
-        self.role == "app_admin"
+        self.parsed_role() == Some(Role::AppAdmin)
     }
 
     pub fn is_mosque_supervisor(&self) -> bool {
-        self.role == "mosque_supervisor"
+        self.parsed_role() == Some(Role::MosqueSupervisor)
+    }
+
+    /// Whether the user has proven they own one of their identifiers, either by confirming
+    /// a verification link or code or by signing in through an OAuth provider.
+    pub fn is_verified(&self) -> bool {
+        self.verified
     }
 
-    pub fn elevate_to(&mut self, elevation_degree: String) {
-        self.role = elevation_degree;
+    pub fn elevate_to(&mut self, role: Role) {
+        self.role = role.as_str().to_string();
         self.refresh_updated_at();
     }
 
@@ -94,6 +153,13 @@ pub struct UpdateUser {
     pub updated_at: Datetime,
 }
 
+/// Changes a user can make to their own profile, held to the same rules as registration.
+#[derive(Debug, Validate, Deserialize, Serialize, Clone)]
+pub struct ProfileUpdate {
+    #[garde(length(min = 2, max = 100))]
+    pub display_name: Option<String>,
+}
+
 #[cfg(feature = "ssr")]
 #[derive(Debug, Serialize)]
 pub struct CreateUserIdentifier {
@@ -118,6 +184,29 @@ pub enum Identifier {
     Meta(#[garde(skip)] String),
     #[serde(rename = "instagram")]
     Instagram(#[garde(skip)] String),
+    #[serde(rename = "workos")]
+    Workos(#[garde(skip)] String),
+}
+
+impl Identifier {
+    /// The identifier in the form it is stored and looked up in. Only mobile numbers change,
+    /// see `normalize_mobile`.
+    pub fn normalized(self) -> Self {
+        match self {
+            Identifier::Mobile(mobile) => Identifier::Mobile(normalize_mobile(&mobile)),
+            identifier => identifier,
+        }
+    }
+}
+
+/// Drops the spaces, dashes, dots and parentheses people type into phone numbers, keeping the
+/// leading `+`, so that "+91 1234567890" and "+91-123-456-7890" are the same number.
+pub fn normalize_mobile(mobile: &str) -> String {
+    mobile
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+        .collect()
 }
 
 #[cfg(feature = "ssr")]
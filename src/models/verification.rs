@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::{RecordId, sql::Datetime};
+
+use crate::models::redacted::Redacted;
+
+#[derive(Debug, Serialize)]
+pub struct CreateVerificationToken {
+    pub user: RecordId,
+    pub identifier: RecordId,
+    pub token: Redacted<String>,
+    pub expires_at: Datetime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerificationToken {
+    pub id: RecordId,
+    pub user: RecordId,
+    pub identifier: RecordId,
+    pub expires_at: Datetime,
+    pub created_at: Datetime,
+}
@@ -62,8 +62,11 @@ pub fn AddMosquesOfRegion() -> impl IntoView {
                     if let Some(err_msg) = response.error {
                         set_error.set(format!("Server Error: {}", err_msg));
                         set_success.set("".to_string());
-                    } else if let Some(data_msg) = response.data {
-                        set_success.set(format!("Success: {}", data_msg));
+                    } else if let Some(result) = response.data {
+                        set_success.set(format!(
+                            "Success: added {} new mosques, skipped {} existing mosques",
+                            result.added, result.skipped
+                        ));
                         set_error.set("".to_string());
                     } else {
                         set_error.set("Received an empty response from server.".to_string());
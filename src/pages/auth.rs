@@ -8,7 +8,7 @@ use crate::models::{
     user::Identifier,
 };
 use crate::server_functions::auth::{
-    get_discord_oauth_url, get_google_oauth_url, get_microsoft_oauth_url, login, register,
+    get_discord_oauth_url, get_github_oauth_url, get_google_oauth_url, get_microsoft_oauth_url, login, register,
 };
 
 #[component]
@@ -53,6 +53,21 @@ pub fn Register() -> impl IntoView {
         });
     };
 
+    let start_github_login = move |_| {
+        spawn_local(async move {
+            match get_github_oauth_url().await {
+                Ok(response) => {
+                    if let Some(url) = response.data {
+                        window().location().set_href(&url).ok();
+                    }
+                }
+                Err(e) => {
+                    set_error.set(format!("Failed to start GitHub login: {}", e));
+                }
+            }
+        });
+    };
+
     let start_microsoft_login = move |_| {
         spawn_local(async move {
             match get_microsoft_oauth_url().await {
@@ -241,6 +256,15 @@ pub fn Register() -> impl IntoView {
                             <path fill="white" d="M12 12h10v10H12z"/>
                         </svg>
                     </button>
+
+                    <button
+                        on:click = start_github_login
+                        class = "flex-1 flex items-center justify-center gap-2 bg-[#24292F] text-white font-semibold py-2 px-2 rounded-2xl border border-[#24292F] hover:bg-[#1B1F23] transition-colors"
+                    >
+                        <svg class="w-5 h-5 text-white" fill="currentColor" viewBox="0 0 24 24">
+                            <path d="M12 .297c-6.63 0-12 5.373-12 12 0 5.303 3.438 9.8 8.205 11.385.6.113.82-.258.82-.577 0-.285-.01-1.04-.015-2.04-3.338.724-4.042-1.61-4.042-1.61C4.422 18.07 3.633 17.7 3.633 17.7c-1.087-.744.084-.729.084-.729 1.205.084 1.838 1.236 1.838 1.236 1.07 1.835 2.809 1.305 3.495.998.108-.776.417-1.305.76-1.605-2.665-.3-5.466-1.332-5.466-5.93 0-1.31.465-2.38 1.235-3.22-.135-.303-.54-1.523.105-3.176 0 0 1.005-.322 3.3 1.23.96-.267 1.98-.399 3-.405 1.02.006 2.04.138 3 .405 2.28-1.552 3.285-1.23 3.285-1.23.645 1.653.24 2.873.12 3.176.765.84 1.23 1.91 1.23 3.22 0 4.61-2.805 5.625-5.475 5.92.42.36.81 1.096.81 2.22 0 1.606-.015 2.896-.015 3.286 0 .315.21.69.825.57C20.565 22.092 24 17.592 24 12.297c0-6.627-5.373-12-12-12"/>
+                        </svg>
+                    </button>
                 </div>
 
                 <Show
@@ -311,6 +335,21 @@ pub fn Login() -> impl IntoView {
         });
     };
 
+    let start_github_login = move |_| {
+        spawn_local(async move {
+            match get_github_oauth_url().await {
+                Ok(response) => {
+                    if let Some(url) = response.data {
+                        window().location().set_href(&url).ok();
+                    }
+                }
+                Err(e) => {
+                    set_error.set(format!("Failed to start GitHub login: {}", e));
+                }
+            }
+        });
+    };
+
     let start_microsoft_login = move |_| {
         spawn_local(async move {
             match get_microsoft_oauth_url().await {
@@ -500,6 +539,15 @@ pub fn Login() -> impl IntoView {
                                 <path fill="white" d="M12 12h10v10H12z"/>
                             </svg>
                         </button>
+
+                        <button
+                            on:click = start_github_login
+                            class = "flex-1 flex items-center justify-center gap-2 bg-[#24292F] text-white font-semibold py-2 px-2 rounded-2xl border border-[#24292F] hover:bg-[#1B1F23] transition-colors"
+                        >
+                            <svg class="w-5 h-5 text-white" fill="currentColor" viewBox="0 0 24 24">
+                                <path d="M12 .297c-6.63 0-12 5.373-12 12 0 5.303 3.438 9.8 8.205 11.385.6.113.82-.258.82-.577 0-.285-.01-1.04-.015-2.04-3.338.724-4.042-1.61-4.042-1.61C4.422 18.07 3.633 17.7 3.633 17.7c-1.087-.744.084-.729.084-.729 1.205.084 1.838 1.236 1.838 1.236 1.07 1.835 2.809 1.305 3.495.998.108-.776.417-1.305.76-1.605-2.665-.3-5.466-1.332-5.466-5.93 0-1.31.465-2.38 1.235-3.22-.135-.303-.54-1.523.105-3.176 0 0 1.005-.322 3.3 1.23.96-.267 1.98-.399 3-.405 1.02.006 2.04.138 3 .405 2.28-1.552 3.285-1.23 3.285-1.23.645 1.653.24 2.873.12 3.176.765.84 1.23 1.91 1.23 3.22 0 4.61-2.805 5.625-5.475 5.92.42.36.81 1.096.81 2.22 0 1.606-.015 2.896-.015 3.286 0 .315.21.69.825.57C20.565 22.092 24 17.592 24 12.297c0-6.627-5.373-12-12-12"/>
+                            </svg>
+                        </button>
                     </div>
 
                     <Show
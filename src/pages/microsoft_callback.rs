@@ -1,3 +1,4 @@
+use crate::models::auth::Platform;
 use crate::server_functions::auth::handle_microsoft_callback;
 use leptos::{prelude::*, reactive::spawn_local};
 use leptos_router::hooks::use_query_map;
@@ -21,7 +22,7 @@ pub fn MicrosoftCallback() -> impl IntoView {
         }
 
         spawn_local(async move {
-            match handle_microsoft_callback(code, state).await {
+            match handle_microsoft_callback(code, state, Platform::Web).await {
                 Ok(response) => {
                     if let Some(err_msg) = response.error {
                         set_error.set(err_msg);
@@ -2,8 +2,11 @@ pub mod add_mosques_of_region;
 pub mod auth;
 pub mod discord_callback;
 pub mod events;
+pub mod github_callback;
 pub mod google_callback;
 pub mod home;
 pub mod layout;
 pub mod learn;
+pub mod magic_link;
 pub mod microsoft_callback;
+pub mod workos_callback;
@@ -1,29 +1,37 @@
 #[cfg(feature = "ssr")]
+use crate::auth::oauth::apple::AppleProvider;
+#[cfg(feature = "ssr")]
 use crate::auth::oauth::discord::DiscordProvider;
 #[cfg(feature = "ssr")]
+use crate::auth::oauth::github::GitHubProvider;
+#[cfg(feature = "ssr")]
+use crate::auth::oauth::google::GoogleProvider;
+#[cfg(feature = "ssr")]
 use crate::auth::oauth::helpers::OAuthCallback;
 #[cfg(feature = "ssr")]
 use crate::auth::oauth::microsoft::MicrosoftProvider;
-use crate::models::auth::LoginFormData;
 #[cfg(feature = "ssr")]
+use crate::auth::oauth::workos::WorkosProvider;
+use crate::models::auth::LoginFormData;
 use crate::models::auth::Platform;
-#[cfg(feature = "ssr")]
-use crate::models::oauth::GoogleUser;
-use crate::models::{api_responses::ApiResponse, auth::RegistrationFormData, user::UserOnClient};
+use crate::models::{
+    api_responses::{ApiResponse, CurrentUserResponse},
+    auth::RegistrationFormData,
+    user::{Identifier, UserOnClient},
+};
 #[cfg(feature = "ssr")]
 use garde::Validate;
 use leptos::prelude::ServerFnError;
-use leptos::server_fn::codec::{DeleteUrl, Json};
+use leptos::server_fn::codec::{DeleteUrl, Json, PatchJson, PostUrl};
 use leptos::*;
 
 #[cfg(feature = "ssr")]
-use crate::auth::custom_auth::{authenticate, register_user};
-#[cfg(feature = "ssr")]
-use crate::auth::oauth::google::{
-    exchange_code, find_or_create_user, get_authorization_url, get_user_info,
+use crate::auth::custom_auth::{
+    add_identifier as link_identifier, authenticate, change_password as update_password,
+    delete_account as remove_account, register_user, remove_identifier as unlink_identifier,
 };
 #[cfg(feature = "ssr")]
-use crate::auth::oauth::state::{generate_state, validate_state};
+use crate::auth::magic_link::{consume_magic_link, request_magic_link as send_magic_link};
 #[cfg(feature = "ssr")]
 use crate::auth::session::{
     create_session, delete_session, remove_session_cookie, set_session_cookie,
@@ -31,22 +39,57 @@ use crate::auth::session::{
 #[cfg(feature = "ssr")]
 use crate::errors::auth::AuthError;
 #[cfg(feature = "ssr")]
+use crate::errors::magic_link::MagicLinkError;
+#[cfg(feature = "ssr")]
+use crate::errors::mobile_otp::MobileOtpError;
+#[cfg(feature = "ssr")]
 use crate::errors::session::SessionError;
 #[cfg(feature = "ssr")]
-use crate::utils::ssr::{ServerResponse, get_authenticated_user, get_server_context};
+use crate::messages;
+#[cfg(feature = "ssr")]
+use crate::models::auth::NewPassword;
+#[cfg(feature = "ssr")]
+use crate::models::user::{ProfileUpdate, UpdateUser, User, UserIdentifierOnClient};
 #[cfg(feature = "ssr")]
-use actix_web::HttpRequest;
+use crate::services::{mobile_otp, notifier::Notifier};
+#[cfg(feature = "ssr")]
+use crate::utils::email_domain::EmailDomainPolicy;
+#[cfg(feature = "ssr")]
+use crate::utils::rate_limit::{
+    check_client_rate_limit, check_rate_limit, identifier_rate_limit_key,
+};
+#[cfg(feature = "ssr")]
+use crate::utils::ssr::{
+    ServerResponse, check_platform_consistency, current_request, get_authenticated_user,
+    get_server_context,
+};
+#[cfg(feature = "ssr")]
+use actix_web::web;
+#[cfg(feature = "ssr")]
+use chrono::Utc;
 #[cfg(feature = "ssr")]
 use tracing::error;
 
 #[server(input = Json, output = Json, prefix = "/auth", endpoint = "register")]
 pub async fn register(form: RegistrationFormData) -> Result<ApiResponse<String>, ServerFnError> {
-    let (response_options, db, _user) = match get_authenticated_user::<String>().await {
+    let (response_options, db) = match get_server_context::<String>().await {
         Ok(ctx) => ctx,
         Err(e) => return Ok(e),
     };
     let responder = ServerResponse::new(response_options);
 
+    if let Err(retry_after) = check_client_rate_limit(|limiters| &limiters.registration).await {
+        error!("Registration rate limit exceeded");
+        return Ok(responder.rate_limited(
+            retry_after,
+            "Too many registration attempts, please try again later".to_string(),
+        ));
+    }
+
+    if let Err(message) = check_platform_consistency(form.platform).await {
+        return Ok(responder.bad_request(message));
+    }
+
     let validation_result = form.validate();
 
     if let Err(error) = validation_result {
@@ -58,6 +101,11 @@ pub async fn register(form: RegistrationFormData) -> Result<ApiResponse<String>,
         return Ok(responder.unprocessable_entity(errors.join("\n")));
     }
 
+    if let Err(error) = form.validate_email_domain(&EmailDomainPolicy::from_env()) {
+        error!(?error, "Rejected the registration email domain");
+        return Ok(responder.unprocessable_entity(error.to_string()));
+    }
+
     let validation_result_for_uniqueness = form.validate_uniqueness(&db).await;
     if let Err(error) = validation_result_for_uniqueness {
         error!(?error);
@@ -94,7 +142,7 @@ pub async fn register(form: RegistrationFormData) -> Result<ApiResponse<String>,
             ));
         }
 
-        Ok(responder.ok("The user has been registered successfully".to_string()))
+        Ok(responder.ok(messages::USER_REGISTERED.to_string()))
     } else {
         Ok(responder.ok(session_token))
     }
@@ -102,12 +150,27 @@ pub async fn register(form: RegistrationFormData) -> Result<ApiResponse<String>,
 
 #[server(input = Json, output = Json, prefix = "/auth", endpoint = "login")]
 pub async fn login(form: LoginFormData) -> Result<ApiResponse<String>, ServerFnError> {
-    let (response_options, db, _user) = match get_authenticated_user::<String>().await {
+    let (response_options, db) = match get_server_context::<String>().await {
         Ok(ctx) => ctx,
         Err(e) => return Ok(e),
     };
     let responder = ServerResponse::new(response_options);
 
+    // Keyed by the account rather than the IP, so a password can't be guessed from many
+    // addresses and users behind a shared NAT don't lock each other out
+    let login_key = identifier_rate_limit_key(&form.identifier);
+    if let Err(retry_after) = check_rate_limit(|limiters| &limiters.login, &login_key).await {
+        error!("Login rate limit exceeded");
+        return Ok(responder.rate_limited(
+            retry_after,
+            "Too many login attempts, please try again later".to_string(),
+        ));
+    }
+
+    if let Err(message) = check_platform_consistency(form.platform).await {
+        return Ok(responder.bad_request(message));
+    }
+
     let user_id = match authenticate(form.clone(), &db).await {
         Ok(id) => id,
         Err(error) => {
@@ -155,7 +218,7 @@ pub async fn login(form: LoginFormData) -> Result<ApiResponse<String>, ServerFnE
             return Ok(responder.internal_server_error("Failed to set session cookie.".to_string()));
         }
 
-        Ok(responder.ok("The user has been logged in successfully".to_string()))
+        Ok(responder.ok(messages::USER_LOGGED_IN.to_string()))
     } else {
         Ok(responder.ok(session_token))
     }
@@ -172,6 +235,78 @@ pub async fn fetch_me() -> Result<ApiResponse<UserOnClient>, ServerFnError> {
     Ok(responder.ok(UserOnClient::from(user)))
 }
 
+/// Lets clients restore the logged in user's profile from their session token alone, e.g.
+/// after a page reload.
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "current-user")]
+pub async fn get_current_user() -> Result<ApiResponse<CurrentUserResponse>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<CurrentUserResponse>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let identifiers: Vec<UserIdentifierOnClient> = match db
+        .query("SELECT identifier_type, identifier_value FROM user_identifier WHERE user = $user")
+        .bind(("user", user.id.clone()))
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(identifiers) => identifiers,
+        Err(e) => {
+            error!(?e, "Failed to fetch the user's identifiers");
+            return Ok(responder.internal_server_error("Failed to fetch the user".to_string()));
+        }
+    };
+
+    Ok(responder.ok(CurrentUserResponse {
+        user: UserOnClient::from(user),
+        identifiers,
+    }))
+}
+
+/// Changes the logged in user's profile and returns it. Fields left out stay as they are.
+#[server(input = PatchJson, output = Json, prefix = "/auth", endpoint = "update-profile")]
+pub async fn update_profile(
+    display_name: Option<String>,
+) -> Result<ApiResponse<UserOnClient>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<UserOnClient>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let profile = ProfileUpdate { display_name };
+    if let Err(error) = profile.validate() {
+        let errors = error
+            .iter()
+            .map(|(field, msg)| format!("{}, {}", field, msg))
+            .collect::<Vec<_>>();
+        return Ok(responder.unprocessable_entity(errors.join("\n")));
+    }
+
+    if profile.display_name.is_none() {
+        return Ok(responder.ok(UserOnClient::from(user)));
+    }
+
+    let update = UpdateUser {
+        display_name: profile.display_name,
+        role: None,
+        updated_at: Utc::now().into(),
+    };
+    let updated: Option<User> = match db.update(user.id.clone()).merge(update).await {
+        Ok(updated) => updated,
+        Err(e) => {
+            error!(?e, "Failed to update the profile");
+            return Ok(responder.internal_server_error("Failed to update the profile".to_string()));
+        }
+    };
+
+    match updated {
+        Some(updated) => Ok(responder.ok(UserOnClient::from(updated))),
+        None => Ok(responder.not_found("User not found".to_string())),
+    }
+}
+
 #[server(input=DeleteUrl, output=Json, prefix="/auth", endpoint="logout")]
 pub async fn logout() -> Result<ApiResponse<String>, ServerFnError> {
     let (response_options, db, _user) = match get_authenticated_user::<String>().await {
@@ -180,12 +315,9 @@ pub async fn logout() -> Result<ApiResponse<String>, ServerFnError> {
     };
     let responder = ServerResponse::new(response_options);
 
-    let req = match leptos_actix::extract::<HttpRequest>().await {
+    let req = match current_request().await {
         Ok(req) => req,
-        Err(e) => {
-            error!(?e, "Failed to extract request");
-            return Ok(responder.internal_server_error("Internal server error".to_string()));
-        }
+        Err(e) => return Ok(e),
     };
 
     let session_token = if let Some(cookie) = req.cookie("__Host-session") {
@@ -240,143 +372,345 @@ pub async fn logout() -> Result<ApiResponse<String>, ServerFnError> {
         }
     }
 
-    Ok(responder.ok("Successfully logged out the user".to_string()))
+    Ok(responder.ok(messages::USER_LOGGED_OUT.to_string()))
 }
 
-#[server(input = Json, output = Json, prefix = "/auth", endpoint = "google-url")]
-pub async fn get_google_oauth_url() -> Result<ApiResponse<String>, ServerFnError> {
-    let (response_options, _db) = match get_server_context().await {
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "change-password")]
+pub async fn change_password(
+    current_password: String,
+    new_password: String,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
         Ok(ctx) => ctx,
         Err(e) => return Ok(e),
     };
     let responder = ServerResponse::new(response_options);
 
-    let state = match generate_state() {
-        Ok(s) => s,
-        Err(e) => {
-            error!(?e, "Failed to generate state");
-            return Ok(responder
-                .internal_server_error("Failed to generate authentication state".to_string()));
+    let new_password = NewPassword {
+        password: new_password,
+    };
+    if let Err(error) = new_password.validate() {
+        let errors = error
+            .iter()
+            .map(|(field, msg)| format!("{}, {}", field, msg))
+            .collect::<Vec<_>>();
+        return Ok(responder.unprocessable_entity(errors.join("\n")));
+    }
+
+    let req = match current_request().await {
+        Ok(req) => req,
+        Err(e) => return Ok(e),
+    };
+
+    let session_token = if let Some(cookie) = req.cookie("__Host-session") {
+        cookie.value().to_string()
+    } else if let Some(auth_header) = req.headers().get("Authorization") {
+        let auth_str = auth_header.to_str().unwrap_or("");
+        auth_str.trim_start_matches("Bearer ").to_string()
+    } else {
+        return Ok(responder.unauthorized("You are not logged in".to_string()));
+    };
+
+    if let Err(e) = update_password(
+        &user,
+        &current_password,
+        &new_password.password,
+        &session_token,
+        &db,
+    )
+    .await
+    {
+        if let Some(AuthError::PasswordVerificationError(_)) = e.downcast_ref::<AuthError>() {
+            return Ok(responder.unauthorized("The current password is incorrect".to_string()));
         }
+        error!(?e, "Failed to change the password");
+        return Ok(responder.internal_server_error("Failed to change the password".to_string()));
+    }
+
+    Ok(responder.ok(messages::PASSWORD_CHANGED.to_string()))
+}
+
+/// Deletes the account of the logged in user after they re-enter their password, and clears
+/// the session cookie of web clients.
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "delete-account")]
+pub async fn delete_account(password: String) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
     };
+    let responder = ServerResponse::new(response_options);
 
-    let url = match get_authorization_url(&state) {
-        Ok(u) => u,
-        Err(e) => {
-            error!(?e, "Failed to get authorization URL");
+    let req = match current_request().await {
+        Ok(req) => req,
+        Err(e) => return Ok(e),
+    };
+
+    if let Err(e) = remove_account(&user, &password, &db).await {
+        if let Some(AuthError::PasswordVerificationError(_)) = e.downcast_ref::<AuthError>() {
+            return Ok(responder.unauthorized("The password is incorrect".to_string()));
+        }
+        error!(?e, "Failed to delete the account");
+        return Ok(responder.internal_server_error("Failed to delete the account".to_string()));
+    }
+
+    if req.cookie("__Host-session").is_some() {
+        if let Err(e) = remove_session_cookie() {
+            error!(?e, "Failed to remove session cookie");
             return Ok(
-                responder.internal_server_error("Failed to create authorization URL".to_string())
+                responder.internal_server_error("Failed to remove session cookie".to_string())
             );
         }
+    }
+
+    Ok(responder.ok(messages::ACCOUNT_DELETED.to_string()))
+}
+
+/// Links another email or mobile number to the logged in user's account.
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "add-identifier")]
+pub async fn add_identifier(identifier: Identifier) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
     };
+    let responder = ServerResponse::new(response_options);
 
-    let cookie = format!(
-        "google_oauth_state={}; Path=/; Secure; HttpOnly; SameSite=Lax; Max-Age={}",
-        state,
-        10 * 60
-    );
+    if let Err(error) = identifier.validate() {
+        let errors = error
+            .iter()
+            .map(|(field, msg)| format!("{}, {}", field, msg))
+            .collect::<Vec<_>>();
+        return Ok(responder.unprocessable_entity(errors.join("\n")));
+    }
 
-    use actix_web::http::header::{HeaderValue, SET_COOKIE};
+    if let Err(e) = link_identifier(&user, identifier, &db).await {
+        return match e.downcast_ref::<AuthError>() {
+            Some(error @ (AuthError::NotUniqueError(_) | AuthError::IdentifierTypeTaken(_))) => {
+                Ok(responder.conflict(error.to_string()))
+            }
+            Some(error @ AuthError::OAuthIdentifier) => {
+                Ok(responder.unprocessable_entity(error.to_string()))
+            }
+            _ => {
+                error!(?e, "Failed to add the identifier");
+                Ok(responder.internal_server_error("Failed to add the identifier".to_string()))
+            }
+        };
+    }
 
-    let header_value = match HeaderValue::from_str(&cookie) {
-        Ok(v) => v,
-        Err(e) => {
-            error!(?e, "Failed to create header value");
-            return Ok(responder.internal_server_error("Failed to set cookie".to_string()));
-        }
+    Ok(responder.ok(messages::IDENTIFIER_ADDED.to_string()))
+}
+
+/// Unlinks an identifier from the logged in user's account, unless it is the only one left.
+#[server(input = DeleteUrl, output = Json, prefix = "/auth", endpoint = "remove-identifier")]
+pub async fn remove_identifier(
+    identifier_value: String,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
     };
+    let responder = ServerResponse::new(response_options);
 
-    responder.insert_header(SET_COOKIE, header_value);
+    if let Err(e) = unlink_identifier(&user, &identifier_value, &db).await {
+        return match e.downcast_ref::<AuthError>() {
+            Some(error @ AuthError::IdentifierNotFound) => {
+                Ok(responder.not_found(error.to_string()))
+            }
+            Some(error @ AuthError::LastIdentifier) => Ok(responder.bad_request(error.to_string())),
+            _ => {
+                error!(?e, "Failed to remove the identifier");
+                Ok(responder.internal_server_error("Failed to remove the identifier".to_string()))
+            }
+        };
+    }
 
-    Ok(responder.ok(url))
+    Ok(responder.ok(messages::IDENTIFIER_REMOVED.to_string()))
 }
 
-#[server(input = Json, output = Json, prefix = "/auth", endpoint = "google-callback")]
-pub async fn handle_google_callback(
-    code: String,
-    state: String,
-) -> Result<ApiResponse<String>, ServerFnError> {
-    let (response_options, db) = match get_server_context().await {
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "request-mobile-otp")]
+pub async fn request_mobile_otp() -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
         Ok(ctx) => ctx,
         Err(e) => return Ok(e),
     };
     let responder = ServerResponse::new(response_options);
 
-    let req = match leptos_actix::extract::<HttpRequest>().await {
-        Ok(req) => req,
+    if let Err(retry_after) = check_client_rate_limit(|limiters| &limiters.mobile_otp).await {
+        error!("Mobile verification code rate limit exceeded");
+        return Ok(responder.rate_limited(
+            retry_after,
+            "Too many verification codes requested, please try again later".to_string(),
+        ));
+    }
+
+    let notifier = match leptos_actix::extract::<web::Data<dyn Notifier>>().await {
+        Ok(notifier) => notifier,
         Err(e) => {
-            error!(?e, "Failed to extract request");
+            error!(?e, "Failed to extract the notifier");
             return Ok(responder.internal_server_error("Internal server error".to_string()));
         }
     };
 
-    let stored_state = req
-        .cookie("google_oauth_state")
-        .map(|c| c.value().to_string())
-        .unwrap_or_default();
+    match mobile_otp::request_mobile_otp(user.id, notifier.get_ref(), &db).await {
+        Ok(()) => Ok(responder.ok(messages::MOBILE_OTP_SENT.to_string())),
+        Err(e) => Ok(mobile_otp_error_response(&responder, e)),
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "verify-mobile-otp")]
+pub async fn verify_mobile_otp(code: String) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
 
-    if !validate_state(&state, &stored_state) {
-        error!("State validation failed");
-        return Ok(responder.bad_request("Invalid authentication state".to_string()));
+    match mobile_otp::verify_mobile_otp(user.id, code.trim(), &db).await {
+        Ok(()) => Ok(responder.ok(messages::MOBILE_VERIFIED.to_string())),
+        Err(e) => Ok(mobile_otp_error_response(&responder, e)),
     }
+}
 
-    let token_response = match exchange_code(&code).await {
-        Ok(token) => token,
+#[cfg(feature = "ssr")]
+fn mobile_otp_error_response(responder: &ServerResponse, e: MobileOtpError) -> ApiResponse<String> {
+    match e {
+        MobileOtpError::NoMobileIdentifier => responder.not_found(e.to_string()),
+        MobileOtpError::AlreadyVerified => responder.conflict(e.to_string()),
+        MobileOtpError::NoPendingCode
+        | MobileOtpError::CodeExpired
+        | MobileOtpError::InvalidCode => responder.bad_request(e.to_string()),
+        MobileOtpError::TooManyAttempts => responder.too_many_requests(e.to_string()),
+        MobileOtpError::DatabaseError(_)
+        | MobileOtpError::HashError(_)
+        | MobileOtpError::NotifierError(_) => {
+            error!(?e, "Mobile verification failed");
+            responder.internal_server_error("Failed to verify the mobile number".to_string())
+        }
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "magic-link")]
+pub async fn request_magic_link(
+    identifier: Identifier,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if let Err(retry_after) = check_client_rate_limit(|limiters| &limiters.magic_link).await {
+        error!("Magic link rate limit exceeded");
+        return Ok(responder.rate_limited(
+            retry_after,
+            "Too many login links requested, please try again later".to_string(),
+        ));
+    }
+
+    let Identifier::Email(email) = identifier else {
+        return Ok(responder
+            .unprocessable_entity("Login links can only be sent to an email address".to_string()));
+    };
+
+    let link_url = match std::env::var("MAGIC_LINK_URL") {
+        Ok(url) => url,
         Err(e) => {
-            error!(?e, "Failed to exchange code");
-            return Ok(responder.bad_request("Failed to exchange authorization code".to_string()));
+            error!(?e, "MAGIC_LINK_URL is not set");
+            return Ok(responder.internal_server_error("Internal server error".to_string()));
         }
     };
 
-    let user_info: GoogleUser = match get_user_info(&token_response.access_token).await {
-        Ok(user) => user,
+    let notifier = match leptos_actix::extract::<web::Data<dyn Notifier>>().await {
+        Ok(notifier) => notifier,
         Err(e) => {
-            error!(?e, "Failed to get user info");
-            return Ok(responder.bad_request("Failed to get user information".to_string()));
+            error!(?e, "Failed to extract the notifier");
+            return Ok(responder.internal_server_error("Internal server error".to_string()));
         }
     };
 
-    let user_id = match find_or_create_user(user_info, &db).await {
-        Ok(id) => id,
+    if let Err(e) = send_magic_link(email.trim(), &link_url, notifier.get_ref(), &db).await {
+        error!(?e, "Failed to send the magic link");
+        return Ok(responder.internal_server_error("Failed to send the login link".to_string()));
+    }
+
+    Ok(responder.ok(messages::MAGIC_LINK_SENT.to_string()))
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "magic-link-login")]
+pub async fn login_with_magic_link(
+    token: String,
+    platform: Platform,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if let Err(message) = check_platform_consistency(platform).await {
+        return Ok(responder.bad_request(message));
+    }
+
+    let user_id = match consume_magic_link(&token, &db).await {
+        Ok(user_id) => user_id,
+        Err(e @ (MagicLinkError::InvalidToken | MagicLinkError::LinkExpired)) => {
+            return Ok(responder.unauthorized(e.to_string()));
+        }
         Err(e) => {
-            error!(error = %e, "Failed to find or create user");
-            return Err(ServerFnError::ServerError(format!(
-                "Failed to authenticate user: {:?}",
-                e
-            )));
+            error!(?e, "Failed to redeem the magic link");
+            return Ok(responder.internal_server_error("An internal error occurred.".to_string()));
         }
     };
 
     let session_token = match create_session(user_id, &db).await {
         Ok(token) => token,
-        Err(e) => {
-            error!(?e, "Failed to create session");
-            return Err(ServerFnError::ServerError(
-                "Failed to create session".to_string(),
-            ));
+        Err(error) => {
+            error!(?error);
+            return Ok(
+                responder.internal_server_error("Failed to create user session.".to_string())
+            );
         }
     };
 
-    use actix_web::http::header::{HeaderValue, SET_COOKIE};
-
-    let session_cookie = format!(
-        "__Host-session={}; Path=/; Secure; HttpOnly; SameSite=Lax; Max-Age={}",
-        session_token,
-        24 * 60 * 60
-    );
-
-    let clear_state_cookie =
-        "google_oauth_state=; Path=/; Secure; HttpOnly; SameSite=Lax; Max-Age=0";
+    if let Platform::Web = platform {
+        if let Err(error) = set_session_cookie(&session_token) {
+            error!(?error);
+            return Ok(responder.internal_server_error("Failed to set session cookie.".to_string()));
+        }
 
-    if let Ok(session_header) = HeaderValue::from_str(&session_cookie) {
-        responder.append_header(SET_COOKIE, session_header);
+        Ok(responder.ok(messages::USER_LOGGED_IN.to_string()))
+    } else {
+        Ok(responder.ok(session_token))
     }
+}
 
-    if let Ok(clear_header) = HeaderValue::from_str(clear_state_cookie) {
-        responder.append_header(SET_COOKIE, clear_header);
-    }
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "google-url")]
+pub async fn get_google_oauth_url() -> Result<ApiResponse<String>, ServerFnError> {
+    OAuthCallback::get_url::<GoogleProvider>("__Host-google_oauth_state").await
+}
 
-    Ok(responder.ok("Successfully authenticated with Google".to_string()))
+/// Starts a Google login from the native app. Google redirects to the app's deep link, and
+/// the app finishes the login with `handle_google_callback` and `Platform::Mobile` to get a
+/// session token back.
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "google-url-mobile")]
+pub async fn get_google_oauth_url_mobile() -> Result<ApiResponse<String>, ServerFnError> {
+    OAuthCallback::get_url_with(GoogleProvider::mobile(), "__Host-google_oauth_state").await
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "google-callback")]
+pub async fn handle_google_callback(
+    code: String,
+    state: String,
+    platform: Platform,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    OAuthCallback::handle_with(
+        GoogleProvider::for_platform(platform),
+        code,
+        state,
+        "__Host-google_oauth_state",
+        platform,
+    )
+    .await
 }
 
 #[server(input = Json, output = Json, prefix = "/auth", endpoint = "discord-url")]
@@ -388,8 +722,9 @@ pub async fn get_discord_oauth_url() -> Result<ApiResponse<String>, ServerFnErro
 pub async fn handle_discord_callback(
     code: String,
     state: String,
+    platform: Platform,
 ) -> Result<ApiResponse<String>, ServerFnError> {
-    OAuthCallback::handle::<DiscordProvider>(code, state, "discord_oauth_state").await
+    OAuthCallback::handle::<DiscordProvider>(code, state, "discord_oauth_state", platform).await
 }
 
 #[server(input = Json, output = Json, prefix = "/auth", endpoint = "microsoft-url")]
@@ -401,6 +736,53 @@ pub async fn get_microsoft_oauth_url() -> Result<ApiResponse<String>, ServerFnEr
 pub async fn handle_microsoft_callback(
     code: String,
     state: String,
+    platform: Platform,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    OAuthCallback::handle::<MicrosoftProvider>(code, state, "microsoft_oauth_state", platform).await
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "github-url")]
+pub async fn get_github_oauth_url() -> Result<ApiResponse<String>, ServerFnError> {
+    OAuthCallback::get_url::<GitHubProvider>("github_oauth_state").await
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "github-callback")]
+pub async fn handle_github_callback(
+    code: String,
+    state: String,
+    platform: Platform,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    OAuthCallback::handle::<GitHubProvider>(code, state, "github_oauth_state", platform).await
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "workos-url")]
+pub async fn get_workos_oauth_url() -> Result<ApiResponse<String>, ServerFnError> {
+    OAuthCallback::get_url::<WorkosProvider>("workos_oauth_state").await
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "workos-callback")]
+pub async fn handle_workos_callback(
+    code: String,
+    state: String,
+    platform: Platform,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    OAuthCallback::handle::<WorkosProvider>(code, state, "workos_oauth_state", platform).await
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "apple-url")]
+pub async fn get_apple_oauth_url() -> Result<ApiResponse<String>, ServerFnError> {
+    OAuthCallback::get_url::<AppleProvider>("apple_oauth_state").await
+}
+
+/// Apple posts the callback as a form to the redirect URI, so this takes URL-encoded
+/// fields rather than JSON. `user` is only sent on the user's first authorization. The
+/// browser is always the one posting, so the login is always a web one.
+#[server(input = PostUrl, output = Json, prefix = "/auth", endpoint = "apple-callback")]
+pub async fn handle_apple_callback(
+    code: String,
+    state: String,
+    user: Option<String>,
 ) -> Result<ApiResponse<String>, ServerFnError> {
-    OAuthCallback::handle::<MicrosoftProvider>(code, state, "microsoft_oauth_state").await
+    let provider = AppleProvider::with_user_details(user.as_deref());
+    OAuthCallback::handle_with(provider, code, state, "apple_oauth_state", Platform::Web).await
 }
@@ -1,5 +1,5 @@
 #[cfg(feature = "ssr")]
-use chrono::{DateTime, FixedOffset, Utc};
+use chrono::Utc;
 #[cfg(feature = "ssr")]
 use garde::Validate;
 use leptos::{
@@ -14,8 +14,12 @@ use surrealdb::{Datetime, RecordId};
 #[cfg(feature = "ssr")]
 use tracing::error;
 
+#[cfg(feature = "ssr")]
+use crate::messages;
 use crate::models::api_responses::ApiResponse;
 #[cfg(feature = "ssr")]
+use crate::models::datetime::to_fixed_offset;
+#[cfg(feature = "ssr")]
 use crate::models::education::{
     Course, CourseRecord, Lesson, LessonRecord, Module, ModuleRecord, Track, UpdatedCourseRecord,
     UpdatedLessonRecord, UpdatedModuleRecord,
@@ -44,12 +48,6 @@ use crate::utils::ssr::{ServerResponse, get_authenticated_user, get_server_conte
 #[cfg(feature = "ssr")]
 use crate::utils::token_generator::generate_token;
 
-#[cfg(feature = "ssr")]
-fn datetime_to_fixed(datetime: Datetime) -> DateTime<FixedOffset> {
-    DateTime::parse_from_rfc3339(&datetime.to_string())
-        .unwrap_or_else(|_| Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()))
-}
-
 #[cfg(feature = "ssr")]
 #[derive(Debug, Deserialize)]
 struct CountResult {
@@ -109,6 +107,7 @@ pub async fn fetch_tracks() -> Result<ApiResponse<Vec<TrackOnClient>>, ServerFnE
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
@@ -155,6 +154,7 @@ pub async fn fetch_track_courses(
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
@@ -202,6 +202,7 @@ pub async fn fetch_course_details(
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
@@ -295,6 +296,7 @@ pub async fn fetch_lesson_details(
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
@@ -382,6 +384,7 @@ pub async fn search_courses(
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
@@ -479,7 +482,7 @@ pub async fn enroll_course(course_id: String) -> Result<ApiResponse<String>, Ser
 
     let _ = update_course_enrollment_count(&course_id, &db).await;
 
-    Ok(responder.ok("Enrolled successfully".to_string()))
+    Ok(responder.ok(messages::COURSE_ENROLLED.to_string()))
 }
 
 #[server(input = Json, output = Json, prefix = "/education", endpoint = "unenroll")]
@@ -503,7 +506,7 @@ pub async fn unenroll_course(course_id: String) -> Result<ApiResponse<String>, S
 
     let _ = update_course_enrollment_count(&course_id, &db).await;
 
-    Ok(responder.ok("Unenrolled successfully".to_string()))
+    Ok(responder.ok(messages::COURSE_UNENROLLED.to_string()))
 }
 
 #[server(input = Json, output = Json, prefix = "/education", endpoint = "my-courses")]
@@ -530,11 +533,11 @@ pub async fn fetch_my_courses() -> Result<ApiResponse<Vec<EnrollmentProgress>>,
                 course_id: row.out.id.to_string(),
                 course_title: row.out.title,
                 thumbnail_url: row.out.thumbnail_url,
-                enrolled_at: datetime_to_fixed(row.enrolled_at),
+                enrolled_at: to_fixed_offset(row.enrolled_at),
                 progress_percent: row.progress_percent,
                 completed_lessons: completed,
                 total_lessons: row.out.lesson_count,
-                last_accessed_at: row.last_accessed_at.map(datetime_to_fixed),
+                last_accessed_at: row.last_accessed_at.map(to_fixed_offset),
             }
         })
         .collect::<Vec<_>>();
@@ -669,7 +672,7 @@ pub async fn complete_lesson(lesson_id: String) -> Result<ApiResponse<String>, S
     let _ = bump_streak(&user.id, &db).await;
     let _ = check_and_award_achievements(&user.id, &db).await;
 
-    Ok(responder.ok("Lesson marked as completed".to_string()))
+    Ok(responder.ok(messages::LESSON_COMPLETED.to_string()))
 }
 
 #[server(input = Json, output = Json, prefix = "/education", endpoint = "progress")]
@@ -704,11 +707,11 @@ pub async fn fetch_course_progress(
         course_id: row.out.id.to_string(),
         course_title: row.out.title,
         thumbnail_url: row.out.thumbnail_url,
-        enrolled_at: datetime_to_fixed(row.enrolled_at),
+        enrolled_at: to_fixed_offset(row.enrolled_at),
         progress_percent: row.progress_percent,
         completed_lessons: completed,
         total_lessons: row.out.lesson_count,
-        last_accessed_at: row.last_accessed_at.map(datetime_to_fixed),
+        last_accessed_at: row.last_accessed_at.map(to_fixed_offset),
     };
 
     Ok(responder.ok(payload))
@@ -807,7 +810,7 @@ pub async fn create_course(
         return Ok(responder.internal_server_error("Failed to create course".to_string()));
     }
 
-    Ok(responder.created("Course created".to_string()))
+    Ok(responder.created(messages::COURSE_CREATED.to_string()))
 }
 
 #[server(input = PatchJson, output = Json, prefix = "/education/educator", endpoint = "courses-update")]
@@ -877,7 +880,7 @@ pub async fn update_course(
         return Ok(responder.internal_server_error("Failed to update course".to_string()));
     }
 
-    Ok(responder.ok("Course updated".to_string()))
+    Ok(responder.ok(messages::COURSE_UPDATED.to_string()))
 }
 
 #[server(input = Json, output = Json, prefix = "/education/educator", endpoint = "courses-publish")]
@@ -929,7 +932,7 @@ pub async fn publish_course(course_id: String) -> Result<ApiResponse<String>, Se
         .bind(("course_id", course_id))
         .await?;
 
-    Ok(responder.ok("Course published".to_string()))
+    Ok(responder.ok(messages::COURSE_PUBLISHED.to_string()))
 }
 
 #[server(input = Json, output = Json, prefix = "/education/educator", endpoint = "modules-create")]
@@ -978,7 +981,7 @@ pub async fn create_module(
     let create_query = "CREATE ONLY modules CONTENT $module";
     db.query(create_query).bind(("module", record)).await?;
 
-    Ok(responder.created("Module created".to_string()))
+    Ok(responder.created(messages::MODULE_CREATED.to_string()))
 }
 
 #[server(input = PatchJson, output = Json, prefix = "/education/educator", endpoint = "modules-update")]
@@ -1033,7 +1036,7 @@ pub async fn update_module(
         .bind(("record", record))
         .await?;
 
-    Ok(responder.ok("Module updated".to_string()))
+    Ok(responder.ok(messages::MODULE_UPDATED.to_string()))
 }
 
 #[server(input = DeleteUrl, output = Json, prefix = "/education/educator", endpoint = "modules-delete")]
@@ -1072,7 +1075,7 @@ pub async fn delete_module(module_id: String) -> Result<ApiResponse<String>, Ser
 
     let _ = update_course_lesson_count(&module.course, &db).await;
 
-    Ok(responder.ok("Module deleted".to_string()))
+    Ok(responder.ok(messages::MODULE_DELETED.to_string()))
 }
 
 #[server(input = Json, output = Json, prefix = "/education/educator", endpoint = "lessons-create")]
@@ -1138,7 +1141,7 @@ pub async fn create_lesson(
 
     let _ = update_course_lesson_count(&module.course, &db).await;
 
-    Ok(responder.created("Lesson created".to_string()))
+    Ok(responder.created(messages::LESSON_CREATED.to_string()))
 }
 
 #[server(input = PatchJson, output = Json, prefix = "/education/educator", endpoint = "lessons-update")]
@@ -1210,7 +1213,7 @@ pub async fn update_lesson(
 
     let _ = update_course_lesson_count(&module.course, &db).await;
 
-    Ok(responder.ok("Lesson updated".to_string()))
+    Ok(responder.ok(messages::LESSON_UPDATED.to_string()))
 }
 
 #[server(input = DeleteUrl, output = Json, prefix = "/education/educator", endpoint = "lessons-delete")]
@@ -1249,5 +1252,5 @@ pub async fn delete_lesson(lesson_id: String) -> Result<ApiResponse<String>, Ser
 
     let _ = update_course_lesson_count(&module.course, &db).await;
 
-    Ok(responder.ok("Lesson deleted".to_string()))
+    Ok(responder.ok(messages::LESSON_DELETED.to_string()))
 }
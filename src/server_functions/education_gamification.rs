@@ -1,5 +1,3 @@
-#[cfg(feature = "ssr")]
-use chrono::{DateTime, FixedOffset, Utc};
 use leptos::{prelude::ServerFnError, server_fn::codec::Json, *};
 #[cfg(feature = "ssr")]
 use serde::Deserialize;
@@ -8,6 +6,8 @@ use surrealdb::Datetime;
 
 use crate::models::api_responses::ApiResponse;
 #[cfg(feature = "ssr")]
+use crate::models::datetime::to_fixed_offset;
+#[cfg(feature = "ssr")]
 use crate::models::gamification::{Achievement, Certificate};
 use crate::models::gamification::{
     AchievementOnClient, CertificateOnClient, LeaderboardEntry, UserStreakOnClient,
@@ -17,12 +17,6 @@ use crate::models::user::User;
 #[cfg(feature = "ssr")]
 use crate::utils::ssr::{ServerResponse, get_authenticated_user};
 
-#[cfg(feature = "ssr")]
-fn datetime_to_fixed(datetime: Datetime) -> DateTime<FixedOffset> {
-    DateTime::parse_from_rfc3339(&datetime.to_string())
-        .unwrap_or_else(|_| Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()))
-}
-
 #[cfg(feature = "ssr")]
 #[derive(Debug, Deserialize)]
 struct EarnedWithAchievement {
@@ -86,7 +80,7 @@ pub async fn fetch_achievements() -> Result<ApiResponse<Vec<AchievementOnClient>
             icon: row.out.icon,
             category: row.out.category,
             points: row.out.points,
-            earned_at: Some(datetime_to_fixed(row.earned_at)),
+            earned_at: Some(to_fixed_offset(row.earned_at)),
         })
         .collect::<Vec<_>>();
 
@@ -114,7 +108,7 @@ pub async fn fetch_certificates() -> Result<ApiResponse<Vec<CertificateOnClient>
             id: cert.id.to_string(),
             course_id: cert.course.to_string(),
             certificate_number: cert.certificate_number,
-            issued_at: datetime_to_fixed(cert.issued_at),
+            issued_at: to_fixed_offset(cert.issued_at),
             pdf_url: cert.pdf_url,
         })
         .collect::<Vec<_>>();
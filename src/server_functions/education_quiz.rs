@@ -41,6 +41,7 @@ pub async fn fetch_quiz_for_lesson(
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
@@ -4,6 +4,8 @@ use serde::Deserialize;
 #[cfg(feature = "ssr")]
 use surrealdb::RecordId;
 
+#[cfg(feature = "ssr")]
+use crate::messages;
 use crate::models::api_responses::ApiResponse;
 #[cfg(feature = "ssr")]
 use crate::models::education::Course;
@@ -46,6 +48,7 @@ pub async fn fetch_roadmaps() -> Result<ApiResponse<Vec<RoadmapOnClient>>, Serve
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
@@ -82,6 +85,7 @@ pub async fn fetch_roadmap_detail(
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
@@ -171,7 +175,7 @@ pub async fn start_roadmap(roadmap_id: String) -> Result<ApiResponse<String>, Se
         }
     }
 
-    Ok(responder.ok("Roadmap started".to_string()))
+    Ok(responder.ok(messages::ROADMAP_STARTED.to_string()))
 }
 
 #[server(input = Json, output = Json, prefix = "/education", endpoint = "frameworks")]
@@ -182,6 +186,7 @@ pub async fn fetch_frameworks() -> Result<ApiResponse<Vec<FrameworkOnClient>>, S
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
@@ -216,6 +221,7 @@ pub async fn fetch_framework_detail(
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
@@ -0,0 +1,134 @@
+use actix_web::{
+    HttpRequest, HttpResponse, get,
+    http::header::{CACHE_CONTROL, CONTENT_TYPE},
+    web,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use surrealdb::{Datetime, RecordId, Surreal, engine::remote::ws::Client};
+use tracing::error;
+
+#[cfg(feature = "multi_tenant")]
+use crate::database::tenants::TenantConnections;
+use crate::models::{
+    datetime::to_datetime,
+    events::{EventDetails, PersonalEvent},
+    mosque::MosqueRecord,
+};
+use crate::server_functions::events::EVENTS_IN_WINDOW_QUERY;
+use crate::utils::ssr::PUBLIC_CACHE_CONTROL;
+
+pub const RSS_CONTENT_TYPE: &str = "application/rss+xml; charset=utf-8";
+
+#[derive(Debug, Deserialize)]
+pub struct EventFeedParams {
+    pub mosque_id: String,
+}
+
+/// Serves a mosque's upcoming events as an RSS 2.0 feed, for community sites that read RSS
+/// rather than .ics. This is a plain route because server functions can only answer in their
+/// own encodings.
+#[get("/mosques/events/rss")]
+pub async fn export_mosque_events_rss(
+    req: HttpRequest,
+    params: web::Query<EventFeedParams>,
+    db: web::Data<Surreal<Client>>,
+) -> HttpResponse {
+    let db = match database_for(&req, db.get_ref().clone()) {
+        Ok(db) => db,
+        Err(response) => return response,
+    };
+
+    let mosque_id: RecordId = match params.mosque_id.parse() {
+        Ok(id) => id,
+        Err(_) => return HttpResponse::BadRequest().body("Failed to parse mosque_id"),
+    };
+
+    let mosque: MosqueRecord = match db.select(mosque_id).await {
+        Ok(Some(mosque)) => mosque,
+        Ok(None) => return HttpResponse::NotFound().body("No mosque found with the provided ID"),
+        Err(e) => {
+            error!(?e, "Failed to fetch the mosque");
+            return HttpResponse::InternalServerError().body("Failed to fetch the mosque");
+        }
+    };
+
+    let query_result = db
+        .query(EVENTS_IN_WINDOW_QUERY)
+        .bind(("mosque_id", mosque.id.clone()))
+        .bind(("user_id", None::<RecordId>))
+        .bind(("from", to_datetime(Utc::now().fixed_offset())))
+        .bind(("to", None::<Datetime>))
+        .await
+        .and_then(|mut response| response.take::<Vec<PersonalEvent>>(0));
+
+    let events: Vec<EventDetails> = match query_result {
+        Ok(events) => events.into_iter().map(|personal| personal.event).collect(),
+        Err(e) => {
+            error!(?e, "Failed to fetch the events for the feed");
+            return HttpResponse::InternalServerError().body("Failed to fetch the events");
+        }
+    };
+
+    let connection = req.connection_info();
+    let base_url = format!("{}://{}", connection.scheme(), connection.host());
+
+    HttpResponse::Ok()
+        .insert_header((CONTENT_TYPE, RSS_CONTENT_TYPE))
+        .insert_header((CACHE_CONTROL, PUBLIC_CACHE_CONTROL))
+        .body(render_rss(&mosque, &events, &base_url))
+}
+
+/// The tenant's database when the request names one, like `get_server_context` picks it for
+/// server functions.
+#[cfg_attr(not(feature = "multi_tenant"), allow(unused_variables))]
+fn database_for(
+    req: &HttpRequest,
+    default: Surreal<Client>,
+) -> Result<Surreal<Client>, HttpResponse> {
+    #[cfg(feature = "multi_tenant")]
+    if let Some(tenants) = req.app_data::<web::Data<TenantConnections>>() {
+        return match tenants.for_request(req) {
+            Ok(db) => Ok(db.unwrap_or(default)),
+            Err(e) => Err(HttpResponse::BadRequest().body(e.to_string())),
+        };
+    }
+
+    Ok(default)
+}
+
+/// Renders `events` as an RSS 2.0 channel, each item linking to the event on `base_url`.
+pub fn render_rss(mosque: &MosqueRecord, events: &[EventDetails], base_url: &str) -> String {
+    let mosque_name = mosque.name.as_deref().unwrap_or("Mosque");
+
+    let mut rss = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    rss.push_str("<rss version=\"2.0\">\n<channel>\n");
+    rss.push_str(&format!(
+        "<title>{} events</title>\n<link>{}</link>\n<description>Upcoming events at {}</description>\n",
+        escape_xml(mosque_name),
+        escape_xml(&format!("{base_url}/events?mosque_id={}", mosque.id)),
+        escape_xml(mosque_name),
+    ));
+
+    for event in events {
+        rss.push_str(&format!(
+            "<item>\n<title>{}</title>\n<description>{}</description>\n<link>{}</link>\n<guid isPermaLink=\"false\">{}</guid>\n<pubDate>{}</pubDate>\n</item>\n",
+            escape_xml(&event.title),
+            escape_xml(&event.description),
+            escape_xml(&format!("{base_url}/events?event_id={}", event.id)),
+            escape_xml(&event.id),
+            event.date.to_rfc2822(),
+        ));
+    }
+
+    rss.push_str("</channel>\n</rss>\n");
+    rss
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -1,32 +1,68 @@
 #[cfg(feature = "ssr")]
 use std::collections::HashSet;
 
+#[cfg(feature = "ssr")]
+use actix_web::web;
+#[cfg(feature = "ssr")]
+use chrono::Utc;
+use chrono::{DateTime, FixedOffset};
+#[cfg(feature = "ssr")]
+use chrono_tz::Tz;
 #[cfg(feature = "ssr")]
 use garde::Validate;
 use leptos::{
     prelude::ServerFnError,
-    server_fn::codec::{DeleteUrl, Json, PatchJson},
+    server_fn::codec::{DeleteUrl, GetUrl, Json, PatchJson},
     *,
 };
 #[cfg(feature = "ssr")]
-use surrealdb::{RecordId, sql::Geometry};
+use surrealdb::{Datetime, RecordId, Surreal, engine::remote::ws::Client, sql::Geometry};
 #[cfg(feature = "ssr")]
-use tracing::error;
+use tracing::{error, warn};
 
 #[cfg(feature = "ssr")]
-use crate::models::events::EventSummary;
+use crate::database::query::{TRANSACTION_RESULT, take_checked};
+#[cfg(feature = "ssr")]
+use crate::errors::{app::AppError, events::EventError};
+#[cfg(feature = "ssr")]
+use crate::messages;
+#[cfg(feature = "ssr")]
+use crate::models::datetime::to_datetime;
 #[cfg(feature = "ssr")]
 use crate::models::events::{
-    Event, EventRecord, FavoriteAndNearbyEventsQueryResult, UpdatedEventRecord,
+    Event, EventRecord, FavoriteAndNearbyEventsQueryResult, FeedEntry, UpdatedEventRecord,
 };
+#[cfg(feature = "ssr")]
+use crate::models::events::{EventDetails, EventSummary};
+#[cfg(feature = "ssr")]
+use crate::models::user::User;
 use crate::models::{
     api_responses::ApiResponse,
-    events::{CreateEvent, FetchedEvents, PersonalEvent, UpdatedEvent},
+    events::{
+        CreateEvent, DeleteScope, EventCategoryCount, EventCursor, EventPage, FetchedEvents,
+        MosqueEvents, PersonalEvent, PublicEvent, RsvpEvent, UpdatedEvent,
+    },
 };
 #[cfg(feature = "ssr")]
+use crate::services::event_announcements::{self, MAX_ANNOUNCEMENT_LENGTH};
+#[cfg(feature = "ssr")]
+use crate::services::mosque_cache::find_mosque;
+#[cfg(feature = "ssr")]
+use crate::services::notifier::Notifier;
+#[cfg(feature = "ssr")]
+use crate::services::recurrence::{
+    RotationMode, check_and_rotate_events, next_occurrences_in_zone, on_same_day, rotate_event,
+};
+#[cfg(feature = "ssr")]
+use crate::services::waitlist::{self, RsvpOutcome};
+#[cfg(feature = "ssr")]
 use crate::utils::parsing::parse_record_id;
 #[cfg(feature = "ssr")]
-use crate::utils::ssr::{ServerResponse, get_authenticated_user};
+use crate::utils::rate_limit::check_rate_limit;
+#[cfg(feature = "ssr")]
+use crate::utils::ssr::{
+    ServerResponse, get_authenticated_user, get_optional_authenticated_user, get_server_context,
+};
 #[cfg(feature = "ssr")]
 use crate::utils::user_elevation::is_mosque_admin;
 
@@ -57,36 +93,93 @@ pub async fn add_event(create_event: CreateEvent) -> Result<ApiResponse<String>,
         Err(e) => return Ok(e),
     };
 
+    // The checks on the mosque and the `hosts` relation below all use this one id, so a forged
+    // mosque can't end up hosting the event.
+    let mosque_id = event_record.mosque.clone();
+    if mosque_id.table() != "mosques" {
+        return Ok(responder.not_found("No mosque found with the provided ID".to_string()));
+    }
+    match find_mosque(&mosque_id, &db).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Ok(responder.not_found("No mosque found with the provided ID".to_string()));
+        }
+        Err(e) => {
+            error!(?e, "Failed to fetch the mosque");
+            return Ok(responder.internal_server_error("Failed to fetch the mosque".to_string()));
+        }
+    }
+
     let create_event_transaction = r#"
         BEGIN TRANSACTION;
         LET $event = (CREATE ONLY events CONTENT $event_data);
-        RELATE ($event.mosque) -> hosts -> $event SET created_by = $user_id;
+        RELATE $mosque_id -> hosts -> $event SET created_by = $user_id;
         COMMIT TRANSACTION;
     "#;
 
     let transaction_result = db
         .query(create_event_transaction)
         .bind(("event_data", event_record))
+        .bind(("mosque_id", mosque_id))
         .bind(("user_id", user.id))
         .await;
 
-    match transaction_result {
-        Ok(result) => {
-            if let Err(err) = result.check() {
-                return Ok(responder.internal_server_error(format!(
-                    "Some db error occured during the transaction: {err}"
-                )));
-            }
-        }
+    if let Err(err) = transaction_result.and_then(|result| result.check()) {
+        return Ok(EventError::from(err).respond("Failed to create the event", &responder));
+    }
 
-        Err(err) => {
-            return Ok(responder.internal_server_error(format!(
-                "Some db error occured while executing the transaction: {err}"
-            )));
-        }
+    Ok(responder.created(messages::EVENT_CREATED.to_string()))
+}
+
+/// How many dates `preview_recurrence` lists at most.
+#[cfg(feature = "ssr")]
+const RECURRENCE_PREVIEW_COUNT: usize = 10;
+
+/// Lists the dates a recurring event would take if `create_event` were saved, so admins can
+/// check the series before creating it.
+#[server(input = Json, output = Json, prefix = "/mosques/events", endpoint = "preview-recurrence")]
+pub async fn preview_recurrence(
+    create_event: CreateEvent,
+) -> Result<ApiResponse<Vec<DateTime<FixedOffset>>>, ServerFnError> {
+    let (response_options, _db, _user) =
+        match get_authenticated_user::<Vec<DateTime<FixedOffset>>>().await {
+            Ok(ctx) => ctx,
+            Err(error) => return Ok(error),
+        };
+    let responder = ServerResponse::new(response_options);
+
+    if let Err(err) = create_event.validate() {
+        let errors = err
+            .iter()
+            .map(|(field, msg)| format!("{field}: {msg}"))
+            .collect::<Vec<_>>();
+
+        error!(?errors);
+        return Ok(
+            responder.unprocessable_entity("Error while validating the event's data".to_string())
+        );
     }
 
-    Ok(responder.created("Successfully created the event record Alhadulillah!".to_string()))
+    let event_record = match EventRecord::try_from(create_event) {
+        Ok(record) => record,
+        Err(e) => return Ok(e),
+    };
+
+    let Some(pattern) = event_record.recurrence_pattern else {
+        return Ok(responder.ok(vec![event_record.date]));
+    };
+    let zone = event_record
+        .timezone
+        .as_deref()
+        .and_then(|timezone| timezone.parse::<Tz>().ok());
+
+    Ok(responder.ok(next_occurrences_in_zone(
+        event_record.date,
+        pattern,
+        event_record.recurrence_end_date,
+        RECURRENCE_PREVIEW_COUNT,
+        zone,
+    )))
 }
 
 #[server(input = PatchJson, output = Json, prefix = "/mosques/events", endpoint = "/update-event")]
@@ -94,18 +187,22 @@ pub async fn update_event(
     event_id: String,
     updated_event: UpdatedEvent,
 ) -> Result<ApiResponse<String>, ServerFnError> {
-    let (response_options, db, _user) = match get_authenticated_user::<String>().await {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
         Ok(ctx) => ctx,
         Err(err) => return Ok(err),
     };
 
     let responder = ServerResponse::new(response_options);
 
-    let event_id: RecordId = match parse_record_id(&event_id, "event_id") {
-        Ok(id) => id,
+    let event = match fetch_managed_event(&event_id, &user, &responder, &db).await {
+        Ok(event) => event,
         Err(e) => return Ok(e),
     };
 
+    if updated_event.is_empty() {
+        return Ok(responder.bad_request("No fields to update".to_string()));
+    }
+
     let validation_result = updated_event.validate();
     if let Err(err) = validation_result {
         let errors = err
@@ -125,83 +222,130 @@ pub async fn update_event(
         Err(e) => return Ok(e),
     };
 
+    if let Err(e) = updated_event_record.validate_against(&event) {
+        return Ok(e);
+    }
+
     let update_event_transaction = r#"
         BEGIN TRANSACTION;
-        LET $event = (UPDATE ONLY $event_id MERGE $updated_event);
-        IF $event != NONE {
-            UPDATE hosts SET updated_at = time::now() WHERE out = $event_id;
+        RETURN {
+            LET $event = (UPDATE ONLY $event_id MERGE $updated_event);
+            IF $event != NONE {
+                UPDATE $event_id SET rotation_error = NONE;
+                UPDATE hosts SET updated_at = time::now() WHERE out = $event_id;
+            };
+            $event
         };
         COMMIT TRANSACTION;
-        RETURN $event;
     "#;
 
     let transaction_result = db
         .query(update_event_transaction)
-        .bind(("event_id", event_id))
+        .bind(("event_id", event.id))
         .bind(("updated_event", updated_event_record))
-        .await;
+        .await
+        .map_err(AppError::from)
+        .and_then(|response| take_checked::<Option<Event>>(response, TRANSACTION_RESULT));
 
     match transaction_result {
-        Ok(result) => {
-            let mut result = match result.check() {
-                Ok(r) => r,
-                Err(err) => {
-                    return Ok(responder.internal_server_error(format!(
-                        "Some db error occured during the transaction: {err}"
-                    )));
-                }
-            };
-
-            let event: Option<Event> = match result.take(2) {
-                Ok(event) => event,
-                Err(err) => {
-                    return Ok(responder.internal_server_error(format!(
-                        "Some db error occured while fetching the updated event: {err}"
-                    )));
-                }
-            };
-
-            if event.is_none() {
-                return Ok(responder.not_found("No event found with the provided ID".to_string()));
-            }
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Ok(EventError::EventNotFound.respond("Failed to update the event", &responder));
         }
-
         Err(err) => {
-            return Ok(responder.internal_server_error(format!(
-                "Some db error occured while executing the transaction: {err}"
-            )));
+            return Ok(
+                EventError::from(err).respond("Failed to read the updated event", &responder)
+            );
         }
     }
 
-    Ok(responder.ok("successfully updated the event record".to_string()))
+    Ok(responder.ok(messages::EVENT_UPDATED.to_string()))
 }
 
+/// How many events a page holds when the client doesn't say.
+#[cfg(feature = "ssr")]
+const DEFAULT_EVENT_PAGE_SIZE: u32 = 50;
+#[cfg(feature = "ssr")]
+const MAX_EVENT_PAGE_SIZE: u32 = 100;
+
+/// Cuts a page fetched with one row more than `limit` down to `limit`, returning the cursor to
+/// the next page. Without the extra row the page was the last one.
+#[cfg(feature = "ssr")]
+fn next_cursor<T>(
+    page: &mut Vec<T>,
+    limit: u32,
+    cursor_of: impl Fn(&T) -> EventCursor,
+) -> Option<EventCursor> {
+    if page.len() <= limit as usize {
+        return None;
+    }
+    page.truncate(limit as usize);
+    page.last().map(cursor_of)
+}
+
+/// Binds an optional cursor as `$after_date` and `$after_id`, for queries that page with
+/// `AFTER_CURSOR_CONDITION`.
+#[cfg(feature = "ssr")]
+fn cursor_bindings(after: Option<EventCursor>) -> (Option<Datetime>, Option<String>) {
+    match after {
+        Some(cursor) => (Some(to_datetime(cursor.date)), Some(cursor.id)),
+        None => (None, None),
+    }
+}
+
+/// Keeps the events after `$after_date` and `$after_id`, in the `(date, id)` order pages are
+/// sorted in.
+#[cfg(feature = "ssr")]
+const AFTER_CURSOR_CONDITION: &str = "($after_date = NONE
+    OR <datetime>date > $after_date
+    OR (<datetime>date = $after_date AND type::string(id) > $after_id))";
+
+/// Pages through the events of the user's favorite and nearby mosques in date order, starting
+/// after the `after` cursor.
 #[server(input = Json, output = Json, prefix = "/mosques/events", endpoint = "/fetch-users-favorite-mosques-events")]
 pub async fn fetch_users_favorite_mosques_events(
     lat: f64,
     lon: f64,
-) -> Result<ApiResponse<Vec<PersonalEvent>>, ServerFnError> {
-    let (response_options, db, user) = match get_authenticated_user::<Vec<PersonalEvent>>().await {
-        Ok(ctx) => ctx,
-        Err(err) => return Ok(err),
-    };
+    after: Option<EventCursor>,
+    limit: Option<u32>,
+) -> Result<ApiResponse<EventPage<Vec<PersonalEvent>>>, ServerFnError> {
+    let (response_options, db, user) =
+        match get_authenticated_user::<EventPage<Vec<PersonalEvent>>>().await {
+            Ok(ctx) => ctx,
+            Err(err) => return Ok(err),
+        };
     let responder = ServerResponse::new(response_options);
+    responder.cache_privately();
+
+    let limit = limit
+        .unwrap_or(DEFAULT_EVENT_PAGE_SIZE)
+        .clamp(1, MAX_EVENT_PAGE_SIZE);
 
     let point = Geometry::Point((lon, lat).into());
     let radius_in_meters = 5000;
 
-    let events_and_rsvp_query = r#"
+    // Each list is cut to one row past the page, which is enough to fill it once they're merged
+    let events_and_rsvp_query = format!(
+        r#"
         BEGIN TRANSACTION;
         LET $favorite_events = (
-            SELECT VALUE {
+            SELECT VALUE {{
                 id: type::string(id),
                 title: title,
                 description: description,
                 category: category,
                 date: date,
-                speaker: speaker
-            }
-            FROM $user_id->favorited->mosques->hosts->events
+                speaker: speaker,
+                timezone: timezone ?? mosque.timezone,
+                rsvp_deadline: rsvp_deadline
+            }}
+            FROM (
+                SELECT *, <datetime>date AS starts_at, type::string(id) AS event_key
+                FROM $user_id->favorited->mosques->hosts->events
+                WHERE deleted_at = NONE AND {AFTER_CURSOR_CONDITION}
+                ORDER BY starts_at ASC, event_key ASC
+                LIMIT $fetch_limit
+            )
         );
 
         LET $attending_events = (
@@ -217,46 +361,58 @@ pub async fn fetch_users_favorite_mosques_events(
         );
 
         LET $nearby_events = (
-            SELECT VALUE {
+            SELECT VALUE {{
                 id: type::string(id),
                 title: title,
                 description: description,
                 category: category,
                 date: date,
-                speaker: speaker
-            }
-            FROM events
-            WHERE mosque IN $nearby_mosques
+                speaker: speaker,
+                timezone: timezone ?? mosque.timezone,
+                rsvp_deadline: rsvp_deadline
+            }}
+            FROM (
+                SELECT *, <datetime>date AS starts_at, type::string(id) AS event_key
+                FROM events
+                WHERE mosque IN $nearby_mosques
+                    AND deleted_at = NONE
+                    AND {AFTER_CURSOR_CONDITION}
+                ORDER BY starts_at ASC, event_key ASC
+                LIMIT $fetch_limit
+            )
         );
         COMMIT TRANSACTION;
 
-        RETURN {
+        RETURN {{
             favorite_events: $favorite_events,
             attending_events: $attending_events,
             nearby_events: $nearby_events
-        };
-    "#;
+        }};
+    "#
+    );
+    let (after_date, after_id) = cursor_bindings(after);
 
     let events_and_rsvp_query_result = db
         .query(events_and_rsvp_query)
         .bind(("user_id", user.id.clone()))
         .bind(("point", point))
         .bind(("radius", radius_in_meters))
+        .bind(("after_date", after_date))
+        .bind(("after_id", after_id))
+        .bind(("fetch_limit", limit + 1))
         .await;
 
     let mut db_response = match events_and_rsvp_query_result {
         Ok(response) => response,
         Err(err) => {
-            return Ok(responder.internal_server_error(format!("Some db error occured: {err}")));
+            return Ok(EventError::from(err).respond("Failed to fetch the feed", &responder));
         }
     };
 
     db_response = match db_response.check() {
         Ok(response) => response,
         Err(err) => {
-            return Ok(responder.internal_server_error(format!(
-                "Some db error occured during the transaction: {err}"
-            )));
+            return Ok(EventError::from(err).respond("Failed to fetch the feed", &responder));
         }
     };
 
@@ -264,22 +420,44 @@ pub async fn fetch_users_favorite_mosques_events(
         match db_response.take::<Option<FavoriteAndNearbyEventsQueryResult>>(4) {
             Ok(Some(events_and_attendance)) => events_and_attendance,
             Ok(None) => {
-                return Ok(responder.internal_server_error(
-                    "No event data was returned from the transaction".to_string(),
-                ));
+                return Ok(EventError::MissingResult.respond("Failed to read the feed", &responder));
             }
             Err(err) => {
-                return Ok(responder.internal_server_error(format!("Some db error occured: {err}")));
+                return Ok(EventError::from(err).respond("Failed to read the feed", &responder));
             }
         };
 
-    let rsvp_set: HashSet<String> = events_and_attendance.attending_events.into_iter().collect();
+    let rsvp_set: HashSet<String> = events_and_attendance
+        .attending_events
+        .into_iter()
+        .flatten()
+        .collect();
     let mut seen_event_ids = HashSet::new();
 
-    let personal_events: Vec<PersonalEvent> = events_and_attendance
+    // A favorite whose mosque or events were deleted mid-traversal must not fail the whole feed
+    let entries: Vec<FeedEntry> = events_and_attendance
         .favorite_events
         .into_iter()
         .chain(events_and_attendance.nearby_events)
+        .collect();
+    let entry_count = entries.len();
+    let events: Vec<EventDetails> = entries
+        .into_iter()
+        .filter_map(|entry| match entry {
+            FeedEntry::Event(event) => Some(event),
+            FeedEntry::Broken(_) => None,
+        })
+        .collect();
+    if events.len() < entry_count {
+        warn!(
+            user = %user.id,
+            skipped = entry_count - events.len(),
+            "Skipped broken entries in the favorite and nearby events feed"
+        );
+    }
+
+    let mut personal_events: Vec<PersonalEvent> = events
+        .into_iter()
         .filter_map(|event| {
             let event_id = event.id.clone();
             if !seen_event_ids.insert(event_id.clone()) {
@@ -291,151 +469,947 @@ pub async fn fetch_users_favorite_mosques_events(
         })
         .collect();
 
-    Ok(responder.ok(personal_events))
+    // Favorites and nearby events come from separate queries, so the page is cut here
+    personal_events.sort_by_key(|personal| personal.event.cursor());
+    let next_cursor = next_cursor(&mut personal_events, limit, |personal| {
+        personal.event.cursor()
+    });
+    Ok(responder.ok(EventPage {
+        events: personal_events,
+        next_cursor,
+    }))
 }
 
 #[server(input = Json, output = Json, prefix = "/mosques/events", endpoint = "/fetch-mosque-events")]
 pub async fn fetch_mosque_events(
     mosque_id: String,
-) -> Result<ApiResponse<FetchedEvents>, ServerFnError> {
-    let (response_options, db, user) = match get_authenticated_user::<FetchedEvents>().await {
-        Ok(ctx) => ctx,
-        Err(e) => return Ok(e),
-    };
+    include_past: Option<bool>,
+    as_timezone: Option<String>,
+    after: Option<EventCursor>,
+    limit: Option<u32>,
+) -> Result<ApiResponse<EventPage<FetchedEvents>>, ServerFnError> {
+    let (response_options, db, user) =
+        match get_authenticated_user::<EventPage<FetchedEvents>>().await {
+            Ok(ctx) => ctx,
+            Err(e) => return Ok(e),
+        };
 
     let responder = ServerResponse::new(response_options);
+    responder.cache_privately();
 
     let mosque_id: RecordId = match parse_record_id(&mosque_id, "mosque_id") {
         Ok(id) => id,
         Err(e) => return Ok(e),
     };
 
+    match find_mosque(&mosque_id, &db).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Ok(responder.not_found("No mosque found with the provided ID".to_string()));
+        }
+        Err(e) => {
+            error!(?e, "Failed to fetch the mosque");
+            return Ok(responder.internal_server_error("Failed to fetch the mosque".to_string()));
+        }
+    }
+
+    // Dates keep the mosque's offset unless the client asks for them in another zone
+    let as_timezone = match as_timezone.as_deref().map(str::parse::<Tz>).transpose() {
+        Ok(zone) => zone,
+        Err(_) => {
+            return Ok(responder.unprocessable_entity(
+                "The timezone must be an IANA name such as America/Detroit".to_string(),
+            ));
+        }
+    };
+    let in_requested_zone = |event: EventDetails| match as_timezone {
+        Some(zone) => event.with_date_in(zone),
+        None => event,
+    };
+
     let is_admin = is_mosque_admin(&user.id, &mosque_id, &db).await.is_ok();
 
+    // Recurring events move to their next date on rotation, so they show up as upcoming again
+    // once rotated.
+    let upcoming_only = if include_past.unwrap_or(false) {
+        ""
+    } else {
+        "AND <datetime>date >= time::now()"
+    };
+    let limit = limit
+        .unwrap_or(DEFAULT_EVENT_PAGE_SIZE)
+        .clamp(1, MAX_EVENT_PAGE_SIZE);
+    let (after_date, after_id) = cursor_bindings(after);
+
     if is_admin {
-        let query = r#"
+        let query = format!(
+            r#"
             SELECT 
-                {
+                {{
                     id: type::string(id),
                     title: title,
                     description: description,
                     category: category,
                     date: date,
-                    speaker: speaker
-                } AS event,
+                    speaker: speaker,
+                    timezone: timezone ?? mosque.timezone,
+                    rsvp_deadline: rsvp_deadline
+                }} AS event,
 
                 array::len(<-attending)
-                AS rsvp_count
+                AS rsvp_count,
+
+                <datetime>date AS starts_at,
+                type::string(id) AS event_key
 
             FROM $mosque_id->hosts->events
-        "#;
+            WHERE deleted_at = NONE
+                AND {AFTER_CURSOR_CONDITION}
+                {upcoming_only}
+            ORDER BY starts_at ASC, event_key ASC
+            LIMIT $fetch_limit
+        "#
+        );
 
-        let query_result = db.query(query).bind(("mosque_id", mosque_id)).await;
+        let query_result = db
+            .query(query)
+            .bind(("mosque_id", mosque_id))
+            .bind(("after_date", after_date))
+            .bind(("after_id", after_id))
+            .bind(("fetch_limit", limit + 1))
+            .await;
 
         let events: Vec<EventSummary> = match query_result {
             Ok(mut response) => response.take(0).unwrap_or_default(),
             Err(err) => {
-                return Ok(responder.internal_server_error(format!("Some db error occured: {err}")));
+                return Ok(EventError::from(err)
+                    .respond("Failed to fetch the mosque's events", &responder));
             }
         };
-
-        Ok(responder.ok(FetchedEvents::Summary(events)))
+        let mut events: Vec<EventSummary> = events
+            .into_iter()
+            .map(|summary| EventSummary::new(in_requested_zone(summary.event), summary.rsvp_count))
+            .collect();
+
+        let next_cursor = next_cursor(&mut events, limit, |summary| summary.event.cursor());
+        Ok(responder.ok(EventPage {
+            events: FetchedEvents::Summary(events),
+            next_cursor,
+        }))
     } else {
-        let query = r#"
+        let query = format!(
+            r#"
             SELECT 
-                {
+                {{
                     id: type::string(id),
                     title: title,
                     description: description,
                     category: category,
                     date: date,
-                    speaker: speaker
-                } AS event,
+                    speaker: speaker,
+                    timezone: timezone ?? mosque.timezone,
+                    rsvp_deadline: rsvp_deadline
+                }} AS event,
 
                 (array::len(<-attending WHERE in = $user_id) == 1)
-                AS rsvp
+                AS rsvp,
+
+                <datetime>date AS starts_at,
+                type::string(id) AS event_key
 
             FROM $mosque_id->hosts->events
-        "#;
+            WHERE deleted_at = NONE
+                AND {AFTER_CURSOR_CONDITION}
+                {upcoming_only}
+            ORDER BY starts_at ASC, event_key ASC
+            LIMIT $fetch_limit
+        "#
+        );
 
         let query_result = db
             .query(query)
             .bind(("mosque_id", mosque_id))
             .bind(("user_id", user.id))
+            .bind(("after_date", after_date))
+            .bind(("after_id", after_id))
+            .bind(("fetch_limit", limit + 1))
             .await;
 
         let events: Vec<PersonalEvent> = match query_result {
             Ok(mut response) => response.take(0).unwrap_or_default(),
             Err(err) => {
-                return Ok(responder.internal_server_error(format!("Some db error occured: {err}")));
+                return Ok(EventError::from(err)
+                    .respond("Failed to fetch the mosque's events", &responder));
             }
         };
+        let mut events: Vec<PersonalEvent> = events
+            .into_iter()
+            .map(|personal| PersonalEvent::new(in_requested_zone(personal.event), personal.rsvp))
+            .collect();
+
+        let next_cursor = next_cursor(&mut events, limit, |personal| personal.event.cursor());
+        Ok(responder.ok(EventPage {
+            events: FetchedEvents::Personal(events),
+            next_cursor,
+        }))
+    }
+}
+
+/// How many mosques `fetch_events_for_mosques` accepts in one call.
+#[cfg(feature = "ssr")]
+const MAX_MOSQUES_PER_EVENTS_FETCH: usize = 20;
+
+/// Turns an optional `from`/`to` window into query bounds, with `from` defaulting to now and
+/// `to` to no end.
+#[cfg(feature = "ssr")]
+fn event_window(
+    from: Option<DateTime<FixedOffset>>,
+    to: Option<DateTime<FixedOffset>>,
+) -> Result<(Datetime, Option<Datetime>), String> {
+    let from = from.unwrap_or_else(|| Utc::now().fixed_offset());
+    if to.is_some_and(|to| to < from) {
+        return Err("from must not be after to".to_string());
+    }
+
+    Ok((to_datetime(from), to.map(to_datetime)))
+}
+
+/// The events a mosque hosts between `$from` and `$to`, with whether `$user_id` RSVP'd.
+#[cfg(feature = "ssr")]
+pub(crate) const EVENTS_IN_WINDOW_QUERY: &str = r#"
+    SELECT
+        {
+            id: type::string(id),
+            title: title,
+            description: description,
+            category: category,
+            date: date,
+            speaker: speaker,
+            timezone: timezone ?? mosque.timezone,
+            rsvp_deadline: rsvp_deadline
+        } AS event,
+
+        (array::len(<-attending WHERE in = $user_id) == 1)
+        AS rsvp,
+
+        <datetime>date AS starts_at
+
+    FROM $mosque_id->hosts->events
+    WHERE deleted_at = NONE
+        AND <datetime>date >= $from
+        AND ($to = NONE OR <datetime>date <= $to)
+    ORDER BY starts_at ASC
+"#;
+
+/// Lists the events of each of `mosque_ids` between `from` and `to`, grouped by mosque in the
+/// order the ids were given. `from` defaults to now and `to` to no end.
+#[server(input = Json, output = Json, prefix = "/mosques/events", endpoint = "/fetch-events-for-mosques")]
+pub async fn fetch_events_for_mosques(
+    mosque_ids: Vec<String>,
+    from: Option<DateTime<FixedOffset>>,
+    to: Option<DateTime<FixedOffset>>,
+) -> Result<ApiResponse<Vec<MosqueEvents>>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<Vec<MosqueEvents>>().await {
+        Ok(ctx) => ctx,
+        Err(err) => return Ok(err),
+    };
+    let responder = ServerResponse::new(response_options);
+    responder.cache_privately();
+
+    if mosque_ids.len() > MAX_MOSQUES_PER_EVENTS_FETCH {
+        return Ok(responder.bad_request(format!(
+            "Events can be fetched for at most {MAX_MOSQUES_PER_EVENTS_FETCH} mosques at once"
+        )));
+    }
+
+    let (from, to) = match event_window(from, to) {
+        Ok(window) => window,
+        Err(msg) => return Ok(responder.bad_request(msg)),
+    };
+
+    let mut seen_mosque_ids = HashSet::new();
+    let mut parsed_mosque_ids = Vec::new();
+    for mosque_id in mosque_ids {
+        let mosque_id: RecordId = match parse_record_id(&mosque_id, "mosque_ids") {
+            Ok(id) => id,
+            Err(e) => return Ok(e),
+        };
+        if seen_mosque_ids.insert(mosque_id.to_string()) {
+            parsed_mosque_ids.push(mosque_id);
+        }
+    }
+
+    let mut mosque_events = Vec::with_capacity(parsed_mosque_ids.len());
+    for mosque_id in parsed_mosque_ids {
+        match find_mosque(&mosque_id, &db).await {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                return Ok(responder.not_found(format!("No mosque found with the ID {mosque_id}")));
+            }
+            Err(e) => {
+                error!(?e, "Failed to fetch the mosque");
+                return Ok(
+                    responder.internal_server_error("Failed to fetch the mosque".to_string())
+                );
+            }
+        }
+
+        let query_result = db
+            .query(EVENTS_IN_WINDOW_QUERY)
+            .bind(("mosque_id", mosque_id.clone()))
+            .bind(("user_id", user.id.clone()))
+            .bind(("from", from.clone()))
+            .bind(("to", to.clone()))
+            .await;
+
+        let events: Vec<PersonalEvent> =
+            match query_result.and_then(|mut response| response.take(0)) {
+                Ok(events) => events,
+                Err(err) => {
+                    return Ok(EventError::from(err)
+                        .respond("Failed to fetch the mosques' events", &responder));
+                }
+            };
+
+        mosque_events.push(MosqueEvents {
+            mosque_id: mosque_id.to_string(),
+            events,
+        });
+    }
+
+    Ok(responder.ok(mosque_events))
+}
+
+/// Lists a mosque's events between `from` and `to` for its public page, so no login is
+/// needed. Logged in users also get whether they RSVP'd. `from` defaults to now and `to` to
+/// no end.
+#[server(input = GetUrl, output = Json, prefix = "/mosques/events", endpoint = "/fetch-public-mosque-events")]
+pub async fn fetch_public_mosque_events(
+    mosque_id: String,
+    from: Option<DateTime<FixedOffset>>,
+    to: Option<DateTime<FixedOffset>>,
+) -> Result<ApiResponse<Vec<PublicEvent>>, ServerFnError> {
+    let (response_options, db, user) =
+        match get_optional_authenticated_user::<Vec<PublicEvent>>().await {
+            Ok(ctx) => ctx,
+            Err(err) => return Ok(err),
+        };
+    let responder = ServerResponse::new(response_options);
+
+    let mosque_id: RecordId = match parse_record_id(&mosque_id, "mosque_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    let (from, to) = match event_window(from, to) {
+        Ok(window) => window,
+        Err(msg) => return Ok(responder.bad_request(msg)),
+    };
 
-        Ok(responder.ok(FetchedEvents::Personal(events)))
+    match find_mosque(&mosque_id, &db).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Ok(responder.not_found("No mosque found with the provided ID".to_string()));
+        }
+        Err(e) => {
+            error!(?e, "Failed to fetch the mosque");
+            return Ok(responder.internal_server_error("Failed to fetch the mosque".to_string()));
+        }
+    }
+
+    let query_result = db
+        .query(EVENTS_IN_WINDOW_QUERY)
+        .bind(("mosque_id", mosque_id))
+        .bind(("user_id", user.as_ref().map(|user| user.id.clone())))
+        .bind(("from", from))
+        .bind(("to", to))
+        .await;
+
+    let events: Vec<PersonalEvent> = match query_result.and_then(|mut response| response.take(0)) {
+        Ok(events) => events,
+        Err(err) => {
+            return Ok(
+                EventError::from(err).respond("Failed to fetch the public events", &responder)
+            );
+        }
+    };
+    let events = events
+        .into_iter()
+        .map(|personal| PublicEvent::new(personal.event, user.as_ref().map(|_| personal.rsvp)))
+        .collect();
+
+    // Logged in callers see their own RSVPs, which no shared cache may hand to anyone else
+    if user.is_some() {
+        responder.cache_privately();
+    } else {
+        responder.cache_publicly();
+    }
+    Ok(responder.ok(events))
+}
+
+/// Counts a mosque's upcoming events per category for the filters on its page, busiest
+/// category first. Categories without upcoming events are left out.
+#[server(input = GetUrl, output = Json, prefix = "/mosques/events", endpoint = "/event-category-counts")]
+pub async fn get_mosque_event_category_counts(
+    mosque_id: String,
+) -> Result<ApiResponse<Vec<EventCategoryCount>>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<Vec<EventCategoryCount>>().await {
+        Ok(ctx) => ctx,
+        Err(err) => return Ok(err),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let mosque_id: RecordId = match parse_record_id(&mosque_id, "mosque_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    match find_mosque(&mosque_id, &db).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Ok(responder.not_found("No mosque found with the provided ID".to_string()));
+        }
+        Err(e) => {
+            error!(?e, "Failed to fetch the mosque");
+            return Ok(responder.internal_server_error("Failed to fetch the mosque".to_string()));
+        }
+    }
+
+    let query = r#"
+        SELECT category, count() AS count
+        FROM $mosque_id->hosts->events
+        WHERE deleted_at = NONE AND <datetime>date >= time::now()
+        GROUP BY category
+        ORDER BY count DESC, category ASC
+    "#;
+
+    match db
+        .query(query)
+        .bind(("mosque_id", mosque_id))
+        .await
+        .and_then(|mut response| response.take::<Vec<EventCategoryCount>>(0))
+    {
+        Ok(counts) => {
+            responder.cache_publicly();
+            Ok(responder.ok(counts))
+        }
+        Err(e) => {
+            error!(?e, "Failed to count the mosque's events");
+            Ok(responder.internal_server_error("Failed to count the events".to_string()))
+        }
     }
 }
 
 #[server(input = DeleteUrl, output = Json, prefix = "/mosques/events", endpoint = "/delete/")]
-pub async fn delete_event(event_id: String) -> Result<ApiResponse<String>, ServerFnError> {
-    tracing::info!(?event_id, "delete_event called with event_id");
+pub async fn delete_event(
+    event_id: String,
+    delete_scope: Option<DeleteScope>,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    tracing::info!(
+        ?event_id,
+        ?delete_scope,
+        "delete_event called with event_id"
+    );
+
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(err) => return Ok(err),
+    };
+
+    let responder = ServerResponse::new(response_options);
+
+    let event = match fetch_managed_event(&event_id, &user, &responder, &db).await {
+        Ok(event) => event,
+        Err(e) => return Ok(e),
+    };
+    let event_id = event.id.clone();
+
+    if delete_scope.unwrap_or_default() == DeleteScope::This {
+        // Dropping one occurrence of a series means moving it on to the next one, which also
+        // deletes the row when that was the last occurrence.
+        if event.recurrence_pattern.is_some() {
+            return match rotate_event(event, RotationMode::ClearAttendance, &db).await {
+                Ok(_) => Ok(responder.ok(messages::EVENT_OCCURRENCE_DELETED.to_string())),
+                Err(err) => Ok(
+                    EventError::from(err).respond("Failed to delete the occurrence", &responder)
+                ),
+            };
+        }
+    }
 
-    let (response_options, db, _user) = match get_authenticated_user::<String>().await {
+    // The row and its hosts, RSVP and waitlist edges are kept so the event can be restored
+    let delete_result = db
+        .query("UPDATE ONLY $event_id SET deleted_at = time::now()")
+        .bind(("event_id", event_id))
+        .await
+        .and_then(|mut response| response.take::<Option<Event>>(0));
+
+    match delete_result {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Ok(EventError::EventNotFound.respond("Failed to delete the event", &responder));
+        }
+        Err(err) => {
+            return Ok(EventError::from(err).respond("Failed to delete the event", &responder));
+        }
+    }
+
+    Ok(responder.ok(messages::EVENT_DELETED.to_string()))
+}
+
+/// Brings back an event removed with `delete_event`, along with its RSVPs. Only app admins and
+/// admins of the hosting mosque may.
+#[server(input = Json, output = Json, prefix = "/mosques/events", endpoint = "/restore")]
+pub async fn restore_event(event_id: String) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
         Ok(ctx) => ctx,
         Err(err) => return Ok(err),
     };
+    let responder = ServerResponse::new(response_options);
+
+    let event_id: RecordId = match parse_record_id(&event_id, "event_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    let event: Option<Event> = match db.select(event_id).await {
+        Ok(event) => event,
+        Err(err) => {
+            return Ok(EventError::from(err).respond("Failed to fetch the event", &responder));
+        }
+    };
+    let Some(event) = event.filter(|event| event.deleted_at.is_some()) else {
+        return Ok(responder.not_found("No deleted event found with the provided ID".to_string()));
+    };
+
+    if !user.is_app_admin() && is_mosque_admin(&user.id, &event.mosque, &db).await.is_err() {
+        return Ok(
+            responder.forbidden("Only admins of the event's mosque can restore it".to_string())
+        );
+    }
+
+    let restore_result = db
+        .query("UPDATE $event_id SET deleted_at = NONE")
+        .bind(("event_id", event.id))
+        .await
+        .and_then(|response| response.check());
+
+    match restore_result {
+        Ok(_) => Ok(responder.ok(messages::EVENT_RESTORED.to_string())),
+        Err(err) => Ok(EventError::from(err).respond("Failed to restore the event", &responder)),
+    }
+}
+
+/// Whether `user` may edit or delete `event`: app admins, admins of the hosting mosque and the
+/// user who created the event can.
+#[cfg(feature = "ssr")]
+async fn can_manage_event(
+    user: &User,
+    event: &Event,
+    db: &Surreal<Client>,
+) -> Result<bool, surrealdb::Error> {
+    if user.is_app_admin() || is_mosque_admin(&user.id, &event.mosque, db).await.is_ok() {
+        return Ok(true);
+    }
+
+    let creators: Vec<RecordId> = db
+        .query("SELECT VALUE created_by FROM hosts WHERE out = $event")
+        .bind(("event", event.id.clone()))
+        .await?
+        .take(0)?;
+
+    Ok(creators.contains(&user.id))
+}
+
+/// Fetches an event for a user allowed to manage it, see `can_manage_event`.
+#[cfg(feature = "ssr")]
+async fn fetch_managed_event(
+    event_id: &str,
+    user: &User,
+    responder: &ServerResponse,
+    db: &Surreal<Client>,
+) -> Result<Event, ApiResponse<String>> {
+    let event_id: RecordId = parse_record_id(event_id, "event_id")?;
+
+    let event: Option<Event> = match db.select(event_id).await {
+        Ok(event) => event,
+        Err(e) => {
+            error!(?e, "Failed to fetch the event");
+            return Err(responder.internal_server_error("Failed to fetch the event".to_string()));
+        }
+    };
+    let Some(event) = event.filter(|event| event.deleted_at.is_none()) else {
+        return Err(responder.not_found("No event found with the provided ID".to_string()));
+    };
+
+    match can_manage_event(user, &event, db).await {
+        Ok(true) => Ok(event),
+        Ok(false) => Err(responder.forbidden(
+            "Only the event's creator or admins of the hosting mosque can manage it".to_string(),
+        )),
+        Err(e) => {
+            error!(?e, "Failed to check who can manage the event");
+            Err(responder.internal_server_error("Failed to fetch the event".to_string()))
+        }
+    }
+}
 
+#[cfg(feature = "ssr")]
+async fn save_exception_dates(
+    event: Event,
+    responder: &ServerResponse,
+    db: &Surreal<Client>,
+    message: &str,
+) -> ApiResponse<String> {
+    let result = db
+        .query("UPDATE $event SET exception_dates = $exception_dates")
+        .bind(("event", event.id))
+        .bind(("exception_dates", event.exception_dates))
+        .await
+        .and_then(|response| response.check());
+
+    match result {
+        Ok(_) => responder.ok(message.to_string()),
+        Err(e) => {
+            error!(?e, "Failed to save the cancelled occurrences");
+            responder.internal_server_error("Failed to save the cancelled occurrences".to_string())
+        }
+    }
+}
+
+/// Cancels the occurrence of a recurring event on the day of `date`. Rotation skips over it
+/// to the following occurrence.
+#[server(input = Json, output = Json, prefix = "/mosques/events", endpoint = "/add-exception")]
+pub async fn add_event_exception(
+    event_id: String,
+    date: DateTime<FixedOffset>,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(err) => return Ok(err),
+    };
     let responder = ServerResponse::new(response_options);
 
+    let mut event = match fetch_managed_event(&event_id, &user, &responder, &db).await {
+        Ok(event) => event,
+        Err(e) => return Ok(e),
+    };
+
+    if event.recurrence_pattern.is_none() {
+        return Ok(responder.bad_request(
+            "Only recurring events have occurrences that can be cancelled".to_string(),
+        ));
+    }
+
+    if event
+        .exception_dates
+        .iter()
+        .any(|exception| on_same_day(*exception, date))
+    {
+        return Ok(responder.ok(messages::EVENT_EXCEPTION_ADDED.to_string()));
+    }
+
+    event.exception_dates.push(date);
+    Ok(save_exception_dates(event, &responder, &db, messages::EVENT_EXCEPTION_ADDED).await)
+}
+
+/// Restores an occurrence cancelled with `add_event_exception`.
+#[server(input = DeleteUrl, output = Json, prefix = "/mosques/events", endpoint = "/remove-exception")]
+pub async fn remove_event_exception(
+    event_id: String,
+    date: DateTime<FixedOffset>,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(err) => return Ok(err),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let mut event = match fetch_managed_event(&event_id, &user, &responder, &db).await {
+        Ok(event) => event,
+        Err(e) => return Ok(e),
+    };
+
+    let cancelled = event.exception_dates.len();
+    event
+        .exception_dates
+        .retain(|exception| !on_same_day(*exception, date));
+    if event.exception_dates.len() == cancelled {
+        return Ok(responder.not_found("No occurrence was cancelled on that date".to_string()));
+    }
+
+    Ok(save_exception_dates(event, &responder, &db, messages::EVENT_EXCEPTION_REMOVED).await)
+}
+
+/// Runs the event rotation job right away and returns how many events were rotated, so
+/// operators can catch up without waiting for the next scheduled run.
+#[server(input = Json, output = Json, prefix = "/mosques/events", endpoint = "/trigger-rotation")]
+pub async fn trigger_event_rotation() -> Result<ApiResponse<usize>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<usize>().await {
+        Ok(ctx) => ctx,
+        Err(err) => return Ok(err),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if !user.is_app_admin() {
+        error!(
+            "Unauthorized attempt to trigger the event rotation by user {}",
+            user.id
+        );
+        return Ok(
+            responder.forbidden("Only app admins can trigger the event rotation".to_string())
+        );
+    }
+
+    match check_and_rotate_events(&db).await {
+        Ok(rotated_count) => Ok(responder.ok(rotated_count)),
+        Err(e) => {
+            error!(?e, "Failed to rotate the events");
+            Ok(responder.internal_server_error("Failed to rotate the events".to_string()))
+        }
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques/events", endpoint = "/rsvp")]
+pub async fn rsvp_to_event(event_id: String) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(err) => return Ok(err),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if !user.is_verified() {
+        return Ok(responder.forbidden("Verify your account before RSVPing to events".to_string()));
+    }
+
     let event_id: RecordId = match parse_record_id(&event_id, "event_id") {
         Ok(id) => id,
         Err(e) => return Ok(e),
     };
 
-    let delete_event_transaction = r#"
-        BEGIN TRANSACTION;
-        DELETE hosts WHERE out = $event_id;
-        DELETE attending WHERE out = $event_id;
-        LET $deleted = (DELETE ONLY $event_id RETURN BEFORE);
-        COMMIT TRANSACTION;
-        RETURN $deleted;
-    "#;
+    let event: Option<Event> = match db.select(event_id.clone()).await {
+        Ok(event) => event,
+        Err(err) => {
+            return Ok(EventError::from(err).respond("Failed to fetch the event", &responder));
+        }
+    };
 
-    let transaction_result = db
-        .query(delete_event_transaction)
-        .bind(("event_id", event_id))
-        .await;
+    let Some(event) = event.filter(|event| event.deleted_at.is_none()) else {
+        return Ok(responder.not_found("No event found with the provided ID".to_string()));
+    };
 
-    match transaction_result {
-        Ok(result) => {
-            let mut result = match result.check() {
-                Ok(r) => r,
-                Err(err) => {
-                    return Ok(responder.internal_server_error(format!(
-                        "Some db error occured during the transaction: {err}"
-                    )));
-                }
-            };
+    if event
+        .rsvp_deadline
+        .is_some_and(|deadline| deadline <= Utc::now())
+    {
+        return Ok(responder.bad_request("RSVPs closed".to_string()));
+    }
 
-            let event: Option<Event> = match result.take(3) {
-                Ok(event) => event,
-                Err(err) => {
-                    return Ok(responder.internal_server_error(format!(
-                        "Some db error occured while fetching the deleted event: {err}"
-                    )));
-                }
-            };
+    let existing_rsvp_query =
+        "SELECT VALUE id FROM attending, waitlisted WHERE in = $user_id AND out = $event_id";
+    let existing_rsvp: Vec<RecordId> = match db
+        .query(existing_rsvp_query)
+        .bind(("user_id", user.id.clone()))
+        .bind(("event_id", event_id.clone()))
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(rsvps) => rsvps,
+        Err(err) => {
+            return Ok(
+                EventError::from(err).respond("Failed to check for an existing RSVP", &responder)
+            );
+        }
+    };
 
-            if event.is_none() {
-                return Ok(responder.not_found("No event found with the provided ID".to_string()));
-            }
+    if !existing_rsvp.is_empty() {
+        return Ok(responder.conflict("You have already RSVP'd to this event".to_string()));
+    }
+
+    match waitlist::rsvp(user.id, event_id, &db).await {
+        Ok(RsvpOutcome::Attending) => Ok(responder.ok(messages::EVENT_RSVPED.to_string())),
+        Ok(RsvpOutcome::Waitlisted) => Ok(responder.ok(messages::EVENT_WAITLISTED.to_string())),
+        Err(err) => Ok(err.respond("Failed to RSVP to the event", &responder)),
+    }
+}
+
+/// Sends `message` to everyone who RSVP'd to the event. Only the admins of the event's mosque
+/// may, and only a few times an hour so attendees aren't spammed.
+#[server(input = Json, output = Json, prefix = "/mosques/events", endpoint = "/notify-attendees")]
+pub async fn notify_attendees(
+    event_id: String,
+    message: String,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(err) => return Ok(err),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let message = message.trim();
+    if message.is_empty() || message.chars().count() > MAX_ANNOUNCEMENT_LENGTH {
+        return Ok(responder.unprocessable_entity(format!(
+            "The message must be between 1 and {MAX_ANNOUNCEMENT_LENGTH} characters"
+        )));
+    }
+
+    let event_id: RecordId = match parse_record_id(&event_id, "event_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+    let event: Event = match db.select(event_id).await {
+        Ok(Some(event)) if event.deleted_at.is_none() => event,
+        Ok(_) => {
+            return Ok(
+                EventError::EventNotFound.respond("Failed to notify the attendees", &responder)
+            );
+        }
+        Err(err) => {
+            return Ok(EventError::from(err).respond("Failed to fetch the event", &responder));
+        }
+    };
+
+    if is_mosque_admin(&user.id, &event.mosque, &db).await.is_err() {
+        return Ok(responder.forbidden(
+            "Only the admins of the event's mosque can notify its attendees".to_string(),
+        ));
+    }
+
+    let key = format!("{}:{}", user.id, event.id);
+    if let Err(retry_after) =
+        check_rate_limit(|limiters| &limiters.attendee_notification, &key).await
+    {
+        warn!(user = %user.id, event = %event.id, "Attendees notified too often");
+        return Ok(responder.rate_limited(
+            retry_after,
+            "The attendees were notified too often, please try again later".to_string(),
+        ));
+    }
+
+    let notifier = match leptos_actix::extract::<web::Data<dyn Notifier>>().await {
+        Ok(notifier) => notifier,
+        Err(e) => {
+            error!(?e, "Failed to extract the notifier");
+            return Ok(responder.internal_server_error("Internal server error".to_string()));
         }
+    };
+
+    match event_announcements::notify_attendees(&event, message, notifier.get_ref(), &db).await {
+        Ok(_) => Ok(responder.ok(messages::ATTENDEES_NOTIFIED.to_string())),
+        Err(err) => Ok(err.respond("Failed to notify the attendees", &responder)),
+    }
+}
+
+/// Lists the events the user has RSVP'd to between `from` and `to`, across all mosques.
+#[server(input = Json, output = Json, prefix = "/mosques/events", endpoint = "/fetch-my-rsvp-events")]
+pub async fn fetch_my_rsvp_events(
+    from: Option<DateTime<FixedOffset>>,
+    to: Option<DateTime<FixedOffset>>,
+) -> Result<ApiResponse<Vec<RsvpEvent>>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<Vec<RsvpEvent>>().await {
+        Ok(ctx) => ctx,
+        Err(err) => return Ok(err),
+    };
+    let responder = ServerResponse::new(response_options);
+    responder.cache_privately();
 
+    let (from, to) = match event_window(from, to) {
+        Ok(window) => window,
+        Err(msg) => return Ok(responder.bad_request(msg)),
+    };
+
+    let query = r#"
+        SELECT
+            {
+                id: type::string(id),
+                title: title,
+                description: description,
+                category: category,
+                date: date,
+                speaker: speaker,
+                timezone: timezone ?? mosque.timezone,
+                rsvp_deadline: rsvp_deadline
+            } AS event,
+
+            mosque.name AS mosque_name,
+
+            <datetime>date AS starts_at
+
+        FROM $user_id->attending->events
+        WHERE deleted_at = NONE
+            AND <datetime>date >= $from
+            AND ($to = NONE OR <datetime>date <= $to)
+        ORDER BY starts_at ASC
+    "#;
+
+    let query_result = db
+        .query(query)
+        .bind(("user_id", user.id))
+        .bind(("from", from))
+        .bind(("to", to))
+        .await;
+
+    match query_result.and_then(|mut response| response.take::<Vec<RsvpEvent>>(0)) {
+        Ok(events) => Ok(responder.ok(events)),
         Err(err) => {
-            return Ok(responder.internal_server_error(format!(
-                "Some db error occured while executing the transaction: {err}"
-            )));
+            Ok(EventError::from(err).respond("Failed to fetch the RSVP'd events", &responder))
         }
     }
+}
 
-    Ok(responder.ok("Successfully deleted the event record".to_string()))
+#[server(input = DeleteUrl, output = Json, prefix = "/mosques/events", endpoint = "/cancel-rsvp")]
+pub async fn cancel_rsvp(event_id: String) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(err) => return Ok(err),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let event_id: RecordId = match parse_record_id(&event_id, "event_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    let cancelled = match waitlist::cancel_rsvp(user.id, event_id.clone(), &db).await {
+        Ok(cancelled) => cancelled,
+        Err(err) => return Ok(err.respond("Failed to cancel the RSVP", &responder)),
+    };
+
+    if !cancelled.promoted.is_empty() {
+        notify_promoted(event_id, cancelled.promoted, &db).await;
+    }
+
+    if !cancelled.cancelled {
+        return Ok(responder.not_found("No RSVP found for this event".to_string()));
+    }
+
+    Ok(responder.ok(messages::EVENT_RSVP_CANCELLED.to_string()))
+}
+
+/// The promotion has already happened by now, so failing to tell the promoted users is only
+/// logged rather than failing the cancellation.
+#[cfg(feature = "ssr")]
+async fn notify_promoted(event_id: RecordId, promoted: Vec<RecordId>, db: &Surreal<Client>) {
+    let event: Event = match db.select(event_id).await {
+        Ok(Some(event)) => event,
+        Ok(None) => return,
+        Err(e) => {
+            error!(?e, "Failed to fetch the event to notify promoted users");
+            return;
+        }
+    };
+
+    let notifier = match leptos_actix::extract::<web::Data<dyn Notifier>>().await {
+        Ok(notifier) => notifier,
+        Err(e) => {
+            error!(?e, "Failed to extract the notifier");
+            return;
+        }
+    };
+
+    if let Err(e) =
+        event_announcements::notify_promoted(&event, promoted, notifier.get_ref(), db).await
+    {
+        error!(?e, event = %event.id, "Failed to notify the users promoted off the waitlist");
+    }
 }
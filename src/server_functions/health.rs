@@ -0,0 +1,36 @@
+use leptos::{
+    prelude::ServerFnError,
+    server_fn::codec::{GetUrl, Json},
+    *,
+};
+#[cfg(feature = "ssr")]
+use tracing::error;
+
+use crate::models::{api_responses::ApiResponse, health::HealthStatus};
+#[cfg(feature = "ssr")]
+use crate::utils::ssr::{ServerResponse, get_server_context};
+
+/// Liveness and readiness probe for container orchestration. Answers 503 when SurrealDB can't be
+/// reached, so the instance is taken out of rotation until it can.
+#[server(input = GetUrl, output = Json, prefix = "/system", endpoint = "health")]
+pub async fn health_check() -> Result<ApiResponse<HealthStatus>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<HealthStatus>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let ping = db
+        .query("RETURN 1")
+        .await
+        .and_then(|response| response.check());
+    if let Err(e) = ping {
+        error!(?e, "The database is unreachable");
+        return Ok(responder.service_unavailable("The database is unreachable".to_string()));
+    }
+
+    Ok(responder.ok(HealthStatus {
+        database_connected: true,
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    }))
+}
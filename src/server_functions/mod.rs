@@ -3,5 +3,10 @@ pub mod education;
 pub mod education_gamification;
 pub mod education_quiz;
 pub mod education_roadmap;
+#[cfg(feature = "ssr")]
+pub mod event_feed;
 pub mod events;
+pub mod health;
 pub mod mosque;
+pub mod password_reset;
+pub mod verification;
@@ -1,45 +1,85 @@
 #[cfg(feature = "ssr")]
 use crate::{
-    errors::user_elevation::UserElevationError,
+    errors::{adoption::AdoptionError, user_elevation::UserElevationError},
+    messages,
+    services::{
+        mosque_adoption::{AdoptionDecision, create_adoption_request, review_adoption_request},
+        mosque_bounds::Bounds,
+        mosque_cache::{find_mosque, invalidate_mosque},
+        mosque_import::insert_new_mosques,
+    },
     utils::{
+        mosque_name::{name_match_score, normalize_mosque_name},
         parsing::parse_record_id,
+        rate_limit::check_rate_limit,
         ssr::{ServerResponse, get_authenticated_user, get_server_context},
         user_elevation::elevate_user,
-        user_elevation::is_mosque_admin,
+        user_elevation::{demote_to_regular, is_mosque_admin},
     },
 };
 use leptos::{
     prelude::ServerFnError,
-    server_fn::codec::{DeleteUrl, Json, PatchJson},
+    server_fn::codec::{DeleteUrl, GetUrl, Json, PatchJson},
     *,
 };
 
 use crate::models::{
-    api_responses::{ApiResponse, MosqueResponse},
-    mosque::PrayerTimesUpdate,
+    api_responses::{
+        AddMosquesResult, ApiResponse, MosqueListPage, MosqueResponse, PrayerTimesResponse,
+    },
+    mosque::{
+        AdoptionRequestOnClient, GeoJsonFeatureCollection, MosqueAdminOnClient, MosqueListFilter,
+        MosquePage, PrayerTimesUpdate,
+    },
+    user::UserOnClient,
 };
 
 #[cfg(feature = "ssr")]
 use crate::models::mosque::{
-    MosqueFromOverpass, MosqueRecord, MosqueSearchResult, OverpassResponse,
+    AdoptionRequestDetails, ManualMosqueRecord, MosqueAdminDetails, MosqueCreator,
+    MosqueDetailsUpdate, MosqueExportRecord, MosqueFromOverpass, MosqueNormalizedName,
+    MosqueRecord, MosqueSearchResult, OverpassResponse,
 };
 #[cfg(feature = "ssr")]
-use crate::models::user::{UserIdentifier, UserIdentifierOnClient};
+use crate::models::user::{Role, UserIdentifier, UserIdentifierOnClient};
+#[cfg(feature = "ssr")]
+use chrono::{Duration, Utc};
 #[cfg(feature = "ssr")]
 use std::collections::{HashMap, HashSet};
 #[cfg(feature = "ssr")]
-use surrealdb::{RecordId, sql::Geometry};
+use surrealdb::{Datetime, RecordId, Surreal, engine::remote::ws::Client, sql::Geometry};
 #[cfg(feature = "ssr")]
 use tracing::error;
 
+#[cfg(feature = "ssr")]
+const DEFAULT_SEARCH_RADIUS_IN_METERS: u32 = 5000;
+#[cfg(feature = "ssr")]
+const MIN_SEARCH_RADIUS_IN_METERS: u32 = 500;
+#[cfg(feature = "ssr")]
+const MAX_SEARCH_RADIUS_IN_METERS: u32 = 50_000;
+#[cfg(feature = "ssr")]
+const DEFAULT_NAME_SEARCH_LIMIT: usize = 20;
+#[cfg(feature = "ssr")]
+const MAX_NAME_SEARCH_LIMIT: usize = 50;
+/// How many of the most similar names are fuzzy matched for each result asked for.
+#[cfg(feature = "ssr")]
+const NAME_SEARCH_CANDIDATES_PER_RESULT: usize = 5;
+#[cfg(feature = "ssr")]
+const MIN_KEYWORD_SEARCH_LENGTH: usize = 2;
+#[cfg(feature = "ssr")]
+const MAX_MOSQUE_LIST_PAGE_SIZE: u32 = 100;
+/// Largest viewport `fetch_mosques_in_bounds` answers for, roughly 100 km by 100 km.
+#[cfg(feature = "ssr")]
+const MAX_BOUNDS_AREA_IN_SQUARE_DEGREES: f64 = 1.0;
+
 #[server(input=Json, output=Json, prefix = "/mosques", endpoint = "add-mosque-of-region")]
 pub async fn add_mosques_of_region(
     south: f64,
     west: f64,
     north: f64,
     east: f64,
-) -> Result<ApiResponse<String>, ServerFnError> {
-    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+) -> Result<ApiResponse<AddMosquesResult>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<AddMosquesResult>().await {
         Ok(ctx) => ctx,
         Err(e) => return Ok(e),
     };
@@ -153,48 +193,269 @@ pub async fn add_mosques_of_region(
                 .map(|tags| (tags.name, tags.street, tags.city))
                 .unwrap_or((None, None, None));
 
-            Some(MosqueFromOverpass {
-                id: RecordId::from(("mosques", elem.id)),
+            Some(MosqueFromOverpass::new(
+                RecordId::from(("mosques", elem.id)),
                 name,
                 location,
                 street,
                 city,
-            })
+            ))
         })
         .collect();
 
-    let num_mosques = mosques.len();
+    match insert_new_mosques(mosques, &db).await {
+        Ok(counts) => Ok(responder.ok(AddMosquesResult {
+            added: counts.inserted,
+            skipped: counts.skipped,
+            region: [south, west, north, east],
+        })),
+        Err(e) => {
+            error!(?e, "Failed to add mosques of region");
+            Ok(responder
+                .internal_server_error("Failed to add mosques due to database error".to_string()))
+        }
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "create-mosque")]
+pub async fn create_mosque(
+    name: String,
+    lat: f64,
+    lon: f64,
+    street: Option<String>,
+    city: Option<String>,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if !user.is_app_admin() && !user.is_mosque_supervisor() {
+        error!(
+            "Unauthorized attempt to create a mosque by user {}",
+            user.id
+        );
+        return Ok(responder
+            .forbidden("Only app admins and mosque supervisors can create mosques".to_string()));
+    }
+
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Ok(responder.bad_request("Mosque name must not be empty".to_string()));
+    }
 
-    let insert_query = "INSERT INTO mosques $mosques";
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+        return Ok(responder.bad_request("Mosque coordinates are out of range".to_string()));
+    }
 
-    db.query(insert_query).bind(("mosques", mosques)).await?;
+    let mosque = ManualMosqueRecord {
+        normalized_name: normalize_mosque_name(&name),
+        name,
+        location: Geometry::Point((lon, lat).into()),
+        street,
+        city,
+        created_by: user.id,
+    };
 
-    Ok(ApiResponse {
-        data: Some(format!(
-            "Added {} mosques for the region {} {} {} {} successfully",
-            num_mosques, south, west, north, east
-        )),
-        error: None,
-    })
+    let created: Option<MosqueRecord> = match db.create("mosques").content(mosque).await {
+        Ok(created) => created,
+        Err(e) => {
+            error!(?e, "Failed to create mosque");
+            return Ok(responder.internal_server_error(
+                "Failed to create mosque due to database error".to_string(),
+            ));
+        }
+    };
+
+    match created {
+        Some(mosque) => Ok(responder.created(mosque.id.to_string())),
+        None => Ok(responder
+            .internal_server_error("Failed to create mosque due to database error".to_string())),
+    }
 }
 
-#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "fetch-mosques-for-location")]
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "fetch-mosque-creator")]
+pub async fn fetch_mosque_creator(
+    mosque_id: String,
+) -> Result<ApiResponse<Option<UserOnClient>>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<Option<UserOnClient>>().await
+    {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if !user.is_app_admin() {
+        return Ok(responder.forbidden("Only app admins can see who created a mosque".to_string()));
+    }
+
+    let mosque_id: RecordId = match parse_record_id(&mosque_id, "mosque_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    let creator: Option<MosqueCreator> = match db
+        .query("SELECT created_by FROM ONLY $mosque_id FETCH created_by")
+        .bind(("mosque_id", mosque_id))
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(creator) => creator,
+        Err(e) => {
+            error!(?e, "Failed to fetch mosque creator");
+            return Ok(responder.internal_server_error(
+                "Failed to fetch mosque creator due to database error".to_string(),
+            ));
+        }
+    };
+
+    match creator {
+        Some(creator) => Ok(responder.ok(creator.created_by.map(UserOnClient::from))),
+        None => Ok(responder.not_found("Mosque not found".to_string())),
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "import-mosques-from-geojson")]
+pub async fn import_mosques_from_geojson(
+    body: String,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if !user.is_app_admin() {
+        error!(
+            "Unauthorized attempt to import mosques from GeoJSON by user {}",
+            user.id
+        );
+        return Ok(responder.forbidden("Only app admins can import mosques".to_string()));
+    }
+
+    let collection: GeoJsonFeatureCollection = match serde_json::from_str(&body) {
+        Ok(collection) => collection,
+        Err(e) => {
+            error!(?e, "Failed to parse GeoJSON body");
+            return Ok(responder.bad_request(format!("Invalid GeoJSON: {}", e)));
+        }
+    };
+
+    if collection.collection_type != "FeatureCollection" {
+        return Ok(responder.bad_request(format!(
+            "Invalid GeoJSON: expected type 'FeatureCollection', found '{}'",
+            collection.collection_type
+        )));
+    }
+
+    let mut mosques = Vec::with_capacity(collection.features.len());
+    for (index, feature) in collection.features.iter().enumerate() {
+        match feature.to_mosque() {
+            Ok(mosque) => mosques.push(mosque),
+            Err(reason) => {
+                return Ok(responder.bad_request(format!(
+                    "Invalid GeoJSON feature at index {}: {}",
+                    index, reason
+                )));
+            }
+        }
+    }
+
+    match insert_new_mosques(mosques, &db).await {
+        Ok(counts) => Ok(responder.ok(format!(
+            "Imported {} new mosques, skipped {} existing mosques",
+            counts.inserted, counts.skipped
+        ))),
+        Err(e) => {
+            error!(?e, "Failed to import mosques from GeoJSON");
+            Ok(responder.internal_server_error(
+                "Failed to import mosques due to database error".to_string(),
+            ))
+        }
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "export-mosques-geojson")]
+pub async fn export_mosques_geojson(
+    south: f64,
+    west: f64,
+    north: f64,
+    east: f64,
+) -> Result<ApiResponse<GeoJsonFeatureCollection>, ServerFnError> {
+    let (response_options, db, user) =
+        match get_authenticated_user::<GeoJsonFeatureCollection>().await {
+            Ok(ctx) => ctx,
+            Err(e) => return Ok(e),
+        };
+    let responder = ServerResponse::new(response_options);
+
+    if !user.is_app_admin() && !user.is_mosque_supervisor() {
+        error!("Unauthorized attempt to export mosques by user {}", user.id);
+        return Ok(responder
+            .forbidden("Only app admins and mosque supervisors can export mosques".to_string()));
+    }
+
+    let bounds = Bounds::new(south, west, north, east);
+    if let Err(reason) = bounds.validate() {
+        return Ok(responder.bad_request(reason));
+    }
+
+    let export_query = r#"
+        SELECT
+            record::id(id) AS key,
+            location,
+            name,
+            street,
+            city,
+            imam != NONE AS has_imam,
+            muazzin != NONE AS has_muazzin
+        FROM mosques
+        WHERE location INSIDE $bounds
+    "#;
+
+    let records: Vec<MosqueExportRecord> = match db
+        .query(export_query)
+        .bind(("bounds", bounds.to_geometry()))
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(records) => records,
+        Err(e) => {
+            error!(?e, "Failed to fetch mosques for export");
+            return Ok(responder.internal_server_error(
+                "Failed to export mosques due to database error".to_string(),
+            ));
+        }
+    };
+
+    Ok(responder.ok(GeoJsonFeatureCollection {
+        collection_type: "FeatureCollection".to_string(),
+        features: records.into_iter().map(Into::into).collect(),
+    }))
+}
+
+#[server(input = GetUrl, output = Json, prefix = "/mosques", endpoint = "fetch-mosques-for-location")]
 pub async fn fetch_mosques_for_location(
     lat: f64,
     lon: f64,
+    radius_meters: Option<u32>,
 ) -> Result<ApiResponse<Vec<MosqueResponse>>, ServerFnError> {
-    let (_, db) = match get_server_context::<Vec<MosqueResponse>>().await {
+    let (response_options, db) = match get_server_context::<Vec<MosqueResponse>>().await {
         Ok(ctx) => ctx,
         Err(e) => {
             return Ok(ApiResponse {
                 data: None,
                 error: e.error,
+                code: e.code,
             });
         }
     };
     let point = Geometry::Point((lon, lat).into());
 
-    let radius_in_meters = 5000;
+    let radius_in_meters = radius_meters
+        .unwrap_or(DEFAULT_SEARCH_RADIUS_IN_METERS)
+        .clamp(MIN_SEARCH_RADIUS_IN_METERS, MAX_SEARCH_RADIUS_IN_METERS);
     let query = r#"
         SELECT *, geo::distance(location, $point) AS distance FROM mosques
         WHERE geo::distance(location, $point) < $radius
@@ -208,7 +469,334 @@ pub async fn fetch_mosques_for_location(
         .await?;
 
     let mosques: Vec<MosqueSearchResult> = response.take(0)?;
+    let mosque_responses = into_mosque_responses(mosques, &db).await?;
 
+    ServerResponse::new(response_options).cache_publicly();
+    Ok(ApiResponse {
+        data: Some(mosque_responses),
+        error: None,
+        code: None,
+    })
+}
+
+/// Lists the mosques inside a map viewport, for map UIs that pan and zoom instead of searching
+/// around a point.
+#[server(input = GetUrl, output = Json, prefix = "/mosques", endpoint = "fetch-mosques-in-bounds")]
+pub async fn fetch_mosques_in_bounds(
+    south: f64,
+    west: f64,
+    north: f64,
+    east: f64,
+) -> Result<ApiResponse<Vec<MosqueResponse>>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<Vec<MosqueResponse>>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let bounds = Bounds::new(south, west, north, east);
+    if let Err(reason) = bounds.validate() {
+        return Ok(responder.bad_request(reason));
+    }
+    if bounds.area_in_square_degrees() > MAX_BOUNDS_AREA_IN_SQUARE_DEGREES {
+        return Ok(
+            responder.bad_request("The requested area is too large, please zoom in".to_string())
+        );
+    }
+
+    let mosques: Vec<MosqueSearchResult> = match db
+        .query("SELECT * FROM mosques WHERE location INSIDE $bounds FETCH imam, muazzin")
+        .bind(("bounds", bounds.to_geometry()))
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(mosques) => mosques,
+        Err(e) => {
+            error!(
+                ?e,
+                "Failed to fetch the mosques inside the requested bounds"
+            );
+            return Ok(responder.internal_server_error("Failed to fetch mosques".to_string()));
+        }
+    };
+
+    match into_mosque_responses(mosques, &db).await {
+        Ok(mosques) => {
+            responder.cache_publicly();
+            Ok(responder.ok(mosques))
+        }
+        Err(e) => {
+            error!(?e, "Failed to fetch the contacts of the mosques");
+            Ok(responder.internal_server_error("Failed to fetch mosques".to_string()))
+        }
+    }
+}
+
+#[server(input = GetUrl, output = Json, prefix = "/mosques", endpoint = "search-mosques-by-name")]
+pub async fn search_mosques_by_name(
+    query: String,
+    limit: Option<usize>,
+) -> Result<ApiResponse<Vec<MosqueResponse>>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<Vec<MosqueResponse>>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let normalized_query = normalize_mosque_name(&query);
+    if normalized_query.is_empty() {
+        return Ok(responder.bad_request("Search query must not be empty".to_string()));
+    }
+
+    let limit = limit
+        .unwrap_or(DEFAULT_NAME_SEARCH_LIMIT)
+        .clamp(1, MAX_NAME_SEARCH_LIMIT);
+
+    // Only the names most similar to the query are scored, so a search doesn't have to pull
+    // every mosque name out of the database
+    let candidates_query = r#"
+        SELECT
+            id,
+            normalized_name,
+            string::similarity::smithwaterman(normalized_name, $query) AS similarity
+        FROM mosques
+        WHERE normalized_name != NONE
+        ORDER BY similarity DESC
+        LIMIT $candidate_limit
+    "#;
+
+    let candidates: Vec<MosqueNormalizedName> = match db
+        .query(candidates_query)
+        .bind(("query", normalized_query.clone()))
+        .bind(("candidate_limit", limit * NAME_SEARCH_CANDIDATES_PER_RESULT))
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            error!(?e, "Failed to fetch mosque names");
+            return Ok(responder.internal_server_error(
+                "Failed to search mosques due to database error".to_string(),
+            ));
+        }
+    };
+
+    let mut matches: Vec<(usize, RecordId)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            name_match_score(&normalized_query, &candidate.normalized_name)
+                .map(|score| (score, candidate.id))
+        })
+        .collect();
+    matches.sort_by_key(|(score, _)| *score);
+    matches.truncate(limit);
+
+    let ranked_ids: Vec<RecordId> = matches.into_iter().map(|(_, id)| id).collect();
+    if ranked_ids.is_empty() {
+        return Ok(responder.ok(vec![]));
+    }
+
+    let mosques: Vec<MosqueSearchResult> = match db
+        .query("SELECT * FROM $mosque_ids FETCH imam, muazzin")
+        .bind(("mosque_ids", ranked_ids.clone()))
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(mosques) => mosques,
+        Err(e) => {
+            error!(?e, "Failed to fetch matching mosques");
+            return Ok(responder.internal_server_error(
+                "Failed to search mosques due to database error".to_string(),
+            ));
+        }
+    };
+
+    let mut mosque_responses = match into_mosque_responses(mosques, &db).await {
+        Ok(responses) => responses,
+        Err(e) => {
+            error!(?e, "Failed to fetch mosque contacts");
+            return Ok(responder.internal_server_error(
+                "Failed to search mosques due to database error".to_string(),
+            ));
+        }
+    };
+
+    let rank: HashMap<String, usize> = ranked_ids
+        .iter()
+        .enumerate()
+        .map(|(position, id)| (id.to_string(), position))
+        .collect();
+    mosque_responses.sort_by_key(|mosque| rank.get(&mosque.id).copied().unwrap_or(usize::MAX));
+
+    responder.cache_publicly();
+    Ok(responder.ok(mosque_responses))
+}
+
+/// Finds mosques whose name or city contains `query`, ignoring case. Unlike
+/// `search_mosques_by_name` this is a plain substring match, so it also finds mosques by the
+/// city they are in.
+#[server(input = GetUrl, output = Json, prefix = "/mosques", endpoint = "search-mosques")]
+pub async fn search_mosques(
+    query: String,
+    limit: Option<usize>,
+) -> Result<ApiResponse<Vec<MosqueResponse>>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<Vec<MosqueResponse>>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let query = query.trim().to_lowercase();
+    if query.chars().count() < MIN_KEYWORD_SEARCH_LENGTH {
+        return Ok(responder.bad_request(format!(
+            "Search query must be at least {} characters long",
+            MIN_KEYWORD_SEARCH_LENGTH
+        )));
+    }
+
+    let limit = limit
+        .unwrap_or(DEFAULT_NAME_SEARCH_LIMIT)
+        .clamp(1, MAX_NAME_SEARCH_LIMIT);
+
+    // OSM imports can leave `name` or `city` unset, which `?? ''` turns into a non-match.
+    let mosques: Vec<MosqueSearchResult> = match db
+        .query(
+            "SELECT * FROM mosques
+                WHERE string::lowercase(name ?? '') CONTAINS $query
+                    OR string::lowercase(city ?? '') CONTAINS $query
+                ORDER BY name
+                LIMIT $limit
+                FETCH imam, muazzin",
+        )
+        .bind(("query", query))
+        .bind(("limit", limit))
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(mosques) => mosques,
+        Err(e) => {
+            error!(?e, "Failed to search mosques");
+            return Ok(responder.internal_server_error(
+                "Failed to search mosques due to database error".to_string(),
+            ));
+        }
+    };
+
+    match into_mosque_responses(mosques, &db).await {
+        Ok(mosque_responses) => {
+            responder.cache_publicly();
+            Ok(responder.ok(mosque_responses))
+        }
+        Err(e) => {
+            error!(?e, "Failed to fetch mosque contacts");
+            Ok(responder.internal_server_error(
+                "Failed to search mosques due to database error".to_string(),
+            ))
+        }
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "list-all-mosques")]
+pub async fn list_all_mosques(
+    filter: MosqueListFilter,
+    page: MosquePage,
+) -> Result<ApiResponse<MosqueListPage>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<MosqueListPage>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if !user.is_app_admin() {
+        error!(
+            "Unauthorized attempt to list all mosques by user {}",
+            user.id
+        );
+        return Ok(responder.forbidden("Only app admins can list all mosques".to_string()));
+    }
+
+    let page_number = page.page.max(1);
+    let per_page = page.per_page.clamp(1, MAX_MOSQUE_LIST_PAGE_SIZE);
+
+    let mut conditions = Vec::new();
+    match filter.has_admin {
+        Some(true) => conditions.push("count(<-handles) > 0"),
+        Some(false) => conditions.push("count(<-handles) = 0"),
+        None => (),
+    }
+    match filter.has_prayer_times {
+        Some(true) => conditions.push("(adhan_times != NONE OR jamat_times != NONE)"),
+        Some(false) => conditions.push("(adhan_times = NONE AND jamat_times = NONE)"),
+        None => (),
+    }
+    if filter.city.is_some() {
+        conditions.push("(city != NONE AND string::lowercase(city) = $city)");
+    }
+    if filter.stale_for_days.is_some() {
+        conditions.push("updated_at < $stale_before");
+    }
+
+    let where_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", conditions.join(" AND "))
+    };
+    let query = format!(
+        r#"
+        SELECT * FROM mosques {where_clause}
+            ORDER BY name ASC, id ASC
+            LIMIT $limit START $start
+            FETCH imam, muazzin;
+        SELECT count() FROM mosques {where_clause} GROUP ALL;
+        "#
+    );
+
+    let stale_before = filter
+        .stale_for_days
+        .map(|days| Datetime::from(Utc::now() - Duration::days(days.into())));
+
+    let result = db
+        .query(query)
+        .bind(("city", filter.city.map(|city| city.to_lowercase())))
+        .bind(("stale_before", stale_before))
+        .bind(("limit", per_page))
+        .bind(("start", (page_number - 1) * per_page))
+        .await
+        .and_then(|mut response| {
+            let mosques: Vec<MosqueSearchResult> = response.take(0)?;
+            let total: Option<usize> = response.take((1, "count"))?;
+            Ok((mosques, total.unwrap_or(0)))
+        });
+
+    let (mosques, total) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            error!(?e, "Failed to list mosques");
+            return Ok(responder.internal_server_error("Failed to list mosques".to_string()));
+        }
+    };
+
+    match into_mosque_responses(mosques, &db).await {
+        Ok(mosques) => Ok(responder.ok(MosqueListPage {
+            mosques,
+            total,
+            page: page_number,
+            per_page,
+        })),
+        Err(e) => {
+            error!(?e, "Failed to fetch contacts of listed mosques");
+            Ok(responder.internal_server_error("Failed to list mosques".to_string()))
+        }
+    }
+}
+
+/// Converts fetched mosques into responses, attaching the imam's and muazzin's contacts
+/// with a single bulk identifier lookup.
+#[cfg(feature = "ssr")]
+async fn into_mosque_responses(
+    mosques: Vec<MosqueSearchResult>,
+    db: &Surreal<Client>,
+) -> Result<Vec<MosqueResponse>, surrealdb::Error> {
     // 1. Collect unique user IDs for bulk identifier fetch
     let mut user_ids = HashSet::new();
     for mosque in &mosques {
@@ -267,10 +855,7 @@ pub async fn fetch_mosques_for_location(
         })
         .collect();
 
-    Ok(ApiResponse {
-        data: Some(mosque_responses),
-        error: None,
-    })
+    Ok(mosque_responses)
 }
 
 #[server(input = PatchJson, output = Json, prefix = "/mosques", endpoint = "update-adhan-jamat-times")]
@@ -303,11 +888,60 @@ pub async fn update_adhan_jamat_times(
         }
     }
 
-    db.update::<Option<MosqueRecord>>(mosque_id)
+    if let Err(msg) = prayer_times.check_order() {
+        return Ok(responder.bad_request(msg));
+    }
+
+    match find_mosque(&mosque_id, &db).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Ok(responder.not_found("No mosque found with the provided ID".to_string()));
+        }
+        Err(e) => {
+            error!(?e, "Failed to fetch the mosque");
+            return Ok(responder.internal_server_error("Failed to fetch the mosque".to_string()));
+        }
+    }
+
+    let result = db
+        .update::<Option<MosqueRecord>>(mosque_id.clone())
         .merge(prayer_times)
-        .await?;
+        .await;
+    invalidate_mosque(&mosque_id).await;
+    result?;
+
+    Ok(responder.ok(messages::PRAYER_TIMES_UPDATED.to_string()))
+}
+
+#[server(input = GetUrl, output = Json, prefix = "/mosques", endpoint = "fetch-prayer-times")]
+pub async fn fetch_prayer_times(
+    mosque_id: String,
+) -> Result<ApiResponse<PrayerTimesResponse>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<PrayerTimesResponse>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let mosque_id: RecordId = match parse_record_id(&mosque_id, "mosque_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
 
-    Ok(responder.ok("Successfully updated jamat and adhan times".to_string()))
+    match find_mosque(&mosque_id, &db).await {
+        Ok(Some(mosque)) => {
+            responder.cache_publicly();
+            Ok(responder.ok(PrayerTimesResponse {
+                adhan_times: mosque.adhan_times,
+                jamat_times: mosque.jamat_times,
+            }))
+        }
+        Ok(None) => Ok(responder.not_found("No mosque found with the provided ID".to_string())),
+        Err(e) => {
+            error!(?e, "Failed to fetch the mosque's prayer times");
+            Ok(responder.internal_server_error("Failed to fetch the prayer times".to_string()))
+        }
+    }
 }
 
 #[server(input = Json, output = Json, prefix = "/mosques", endpoint = "add-admin")]
@@ -363,7 +997,250 @@ pub async fn add_admin(
         }
     }
 
-    Ok(responder.ok("Elevated the user to a requested_user".to_string()))
+    Ok(responder.ok(messages::USER_ELEVATED_TO_REQUESTED_USER.to_string()))
+}
+
+#[server(input = DeleteUrl, output = Json, prefix = "/mosques", endpoint = "remove-admin")]
+pub async fn remove_admin(
+    requested_user: String,
+    mosque_id: String,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, mosque_supervisor) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let requested_user: RecordId = match parse_record_id(&requested_user, "requested_user") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    let mosque_id: RecordId = match parse_record_id(&mosque_id, "mosque_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    if !mosque_supervisor.is_mosque_supervisor() && !mosque_supervisor.is_app_admin() {
+        error!(
+            "The user {} trying to revoke a mosque_admin is not a mosque_supervisor or app_admin",
+            mosque_supervisor.id
+        );
+        return Ok(responder.forbidden(
+            "The user trying to revoke a mosque_admin is not a mosque_supervisor or app_admin"
+                .to_string(),
+        ));
+    }
+
+    let removed: Result<Vec<RecordId>, surrealdb::Error> = db
+        .query("DELETE handles WHERE in = $requested_user AND out = $mosque RETURN BEFORE")
+        .bind(("requested_user", requested_user))
+        .bind(("mosque", mosque_id))
+        .await
+        .and_then(|mut response| response.take("id"));
+
+    match removed {
+        Ok(removed) if removed.is_empty() => {
+            Ok(responder.not_found("The user is not an admin of the provided mosque".to_string()))
+        }
+        Ok(_) => Ok(responder.ok(messages::MOSQUE_ADMIN_REMOVED.to_string())),
+        Err(e) => {
+            error!(?e, "Failed to remove the mosque admin");
+            Ok(responder.internal_server_error(
+                "Failed to remove the mosque admin due to db error".to_string(),
+            ))
+        }
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "list-mosque-admins")]
+pub async fn list_mosque_admins(
+    mosque_id: String,
+) -> Result<ApiResponse<Vec<MosqueAdminOnClient>>, ServerFnError> {
+    let (response_options, db, user) =
+        match get_authenticated_user::<Vec<MosqueAdminOnClient>>().await {
+            Ok(ctx) => ctx,
+            Err(e) => return Ok(e),
+        };
+    let responder = ServerResponse::new(response_options);
+
+    if !user.is_mosque_supervisor() && !user.is_app_admin() {
+        error!(
+            "Unauthorized attempt to list mosque admins by user {}",
+            user.id
+        );
+        return Ok(
+            responder.forbidden("Only mosque supervisors can list mosque admins".to_string())
+        );
+    }
+
+    let mosque_id: RecordId = match parse_record_id(&mosque_id, "mosque_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    let query = r#"
+        SELECT in.* AS user, granted_by.* AS granted_by, granted_at
+            FROM $mosque<-handles
+            ORDER BY granted_at ASC
+    "#;
+    let admins: Vec<MosqueAdminDetails> = match db
+        .query(query)
+        .bind(("mosque", mosque_id))
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(admins) => admins,
+        Err(e) => {
+            error!(?e, "Failed to fetch mosque admins");
+            return Ok(responder.internal_server_error("Failed to fetch mosque admins".to_string()));
+        }
+    };
+
+    Ok(responder.ok(admins.into_iter().map(Into::into).collect()))
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "request-mosque-adoption")]
+pub async fn request_mosque_adoption(
+    mosque_id: String,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let mosque_id = match parse_record_id(&mosque_id, "mosque_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    match create_adoption_request(user.id, mosque_id, &db).await {
+        Ok(request_id) => Ok(responder.created(request_id.to_string())),
+        Err(AdoptionError::MosqueNotFound) => {
+            Ok(responder.not_found("No mosque found with the provided ID".to_string()))
+        }
+        Err(AdoptionError::AlreadyAdmin) => {
+            Ok(responder.conflict("You are already an admin of this mosque".to_string()))
+        }
+        Err(AdoptionError::AlreadyRequested) => Ok(responder
+            .conflict("You already have a pending adoption request for this mosque".to_string())),
+        Err(e) => {
+            error!(?e, "Failed to create the adoption request");
+            Ok(
+                responder
+                    .internal_server_error("Failed to create the adoption request".to_string()),
+            )
+        }
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "list-adoption-requests")]
+pub async fn list_adoption_requests()
+-> Result<ApiResponse<Vec<AdoptionRequestOnClient>>, ServerFnError> {
+    let (response_options, db, user) =
+        match get_authenticated_user::<Vec<AdoptionRequestOnClient>>().await {
+            Ok(ctx) => ctx,
+            Err(e) => return Ok(e),
+        };
+    let responder = ServerResponse::new(response_options);
+
+    if !user.is_mosque_supervisor() && !user.is_app_admin() {
+        error!(
+            "Unauthorized attempt to list adoption requests by user {}",
+            user.id
+        );
+        return Ok(
+            responder.forbidden("Only mosque supervisors can list adoption requests".to_string())
+        );
+    }
+
+    let query = r#"
+        SELECT *, mosque.name AS mosque_name FROM adoption_requests
+            WHERE status = "pending"
+            ORDER BY created_at ASC
+            FETCH user
+    "#;
+    let requests: Vec<AdoptionRequestDetails> = match db
+        .query(query)
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(requests) => requests,
+        Err(e) => {
+            error!(?e, "Failed to fetch adoption requests");
+            return Ok(
+                responder.internal_server_error("Failed to fetch adoption requests".to_string())
+            );
+        }
+    };
+
+    Ok(responder.ok(requests.into_iter().map(Into::into).collect()))
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "approve-adoption-request")]
+pub async fn approve_adoption_request(
+    request_id: String,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    review_adoption(request_id, AdoptionDecision::Approve).await
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "reject-adoption-request")]
+pub async fn reject_adoption_request(
+    request_id: String,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    review_adoption(request_id, AdoptionDecision::Reject).await
+}
+
+#[cfg(feature = "ssr")]
+async fn review_adoption(
+    request_id: String,
+    decision: AdoptionDecision,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if !user.is_mosque_supervisor() && !user.is_app_admin() {
+        error!(
+            "Unauthorized attempt to review an adoption request by user {}",
+            user.id
+        );
+        return Ok(
+            responder.forbidden("Only mosque supervisors can review adoption requests".to_string())
+        );
+    }
+
+    let request_id = match parse_record_id(&request_id, "request_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    match review_adoption_request(request_id, user.id, decision, &db).await {
+        Ok(()) => match decision {
+            AdoptionDecision::Approve => {
+                Ok(responder.ok(messages::ADOPTION_REQUEST_APPROVED.to_string()))
+            }
+            AdoptionDecision::Reject => {
+                Ok(responder.ok(messages::ADOPTION_REQUEST_REJECTED.to_string()))
+            }
+        },
+        Err(AdoptionError::RequestNotFound) => {
+            Ok(responder.not_found("No adoption request found with the provided ID".to_string()))
+        }
+        Err(AdoptionError::AlreadyReviewed) => {
+            Ok(responder.conflict("The adoption request has already been reviewed".to_string()))
+        }
+        Err(e) => {
+            error!(?e, "Failed to review the adoption request");
+            Ok(
+                responder
+                    .internal_server_error("Failed to review the adoption request".to_string()),
+            )
+        }
+    }
 }
 
 #[server(input = Json, output = Json, prefix = "/mosques", endpoint = "elevate-user-to-mosque-supervisor")]
@@ -381,7 +1258,7 @@ pub async fn elevate_user_to_mosque_supervisor(
         Err(e) => return Ok(e),
     };
 
-    let result = elevate_user(app_admin.id, user_id, "mosque_supervisor".to_string(), &db).await;
+    let result = elevate_user(app_admin.id, user_id, Role::MosqueSupervisor, &db).await;
 
     match result {
         Ok(success_msg) => return Ok(responder.ok(success_msg)),
@@ -402,6 +1279,10 @@ pub async fn elevate_user_to_mosque_supervisor(
             UserElevationError::SelfElevationNotAllowed => {
                 return Ok(responder.bad_request("You cannot elevate yourself".to_string()));
             }
+            e @ (UserElevationError::InvalidTargetRole(_)
+            | UserElevationError::UnmanagedRole(_)) => {
+                return Ok(responder.bad_request(e.to_string()));
+            }
             UserElevationError::DatabaseError(db_err) => {
                 error!(?db_err, "Database error during user elevation");
                 return Err(ServerFnError::ServerError(
@@ -412,6 +1293,73 @@ pub async fn elevate_user_to_mosque_supervisor(
     }
 }
 
+/// Sets a mosque supervisor or mosque admin back to `regular`. With `remove_admin_rights` the
+/// user also stops administering every mosque they handled.
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "demote-user")]
+pub async fn demote_user(
+    user_id: String,
+    remove_admin_rights: Option<bool>,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, app_admin) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let user_id: RecordId = match parse_record_id(&user_id, "user_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    let result = demote_to_regular(
+        app_admin.id,
+        user_id,
+        remove_admin_rights.unwrap_or(false),
+        &db,
+    )
+    .await;
+
+    match result {
+        Ok(success_msg) => Ok(responder.ok(success_msg)),
+        Err(UserElevationError::Unauthorized) => {
+            Ok(responder.forbidden("You are not authorized to perform this action".to_string()))
+        }
+        Err(UserElevationError::AdminNotFound) => {
+            Ok(responder.unauthorized("Admin user not found".to_string()))
+        }
+        Err(UserElevationError::TargetUserNotFound) => {
+            Ok(responder.not_found("User to demote not found".to_string()))
+        }
+        Err(e) => {
+            error!(?e, "Database error during user demotion");
+            Ok(
+                responder
+                    .internal_server_error("Internal server error during demotion".to_string()),
+            )
+        }
+    }
+}
+
+/// Keeps a user from toggling the same favorite over and over, which could spam notifications
+/// or skew the mosque's popularity. Returns the 429 response to send when they have to wait.
+#[cfg(feature = "ssr")]
+async fn check_favorite_cooldown(
+    user: &RecordId,
+    mosque_id: &RecordId,
+    responder: &ServerResponse,
+) -> Result<(), ApiResponse<String>> {
+    let key = format!("{user}:{mosque_id}");
+    if let Err(retry_after) = check_rate_limit(|limiters| &limiters.favorite_toggle, &key).await {
+        error!(%user, %mosque_id, "Favorite toggled too often");
+        return Err(responder.rate_limited(
+            retry_after,
+            "You are changing this favorite too often, please try again later".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 #[server(input = Json, output = Json, prefix = "/mosques", endpoint = "add-favorite")]
 pub async fn add_favorite(mosque_id: String) -> Result<ApiResponse<String>, ServerFnError> {
     let (response_options, db, user) = match get_authenticated_user::<String>().await {
@@ -420,11 +1368,19 @@ pub async fn add_favorite(mosque_id: String) -> Result<ApiResponse<String>, Serv
     };
     let responder = ServerResponse::new(response_options);
 
+    if !user.is_verified() {
+        return Ok(responder.forbidden("Verify your account before favoriting mosques".to_string()));
+    }
+
     let mosque_id = match parse_record_id(&mosque_id, "mosque_id") {
         Ok(id) => id,
         Err(e) => return Ok(e),
     };
 
+    if let Err(e) = check_favorite_cooldown(&user.id, &mosque_id, &responder).await {
+        return Ok(e);
+    }
+
     let favorite_query = r#"
         RELATE $user_id -> favorited -> $mosque_id;
         "#;
@@ -443,7 +1399,7 @@ pub async fn add_favorite(mosque_id: String) -> Result<ApiResponse<String>, Serv
         }
     }
 
-    Ok(responder.ok("Successfully added the mosque to user's favorite list".to_string()))
+    Ok(responder.ok(messages::FAVORITE_MOSQUE_ADDED.to_string()))
 }
 
 #[server(input = DeleteUrl, output = Json, prefix = "/mosques", endpoint = "/remove-favorite")]
@@ -459,6 +1415,10 @@ pub async fn remove_favorite(mosque_id: String) -> Result<ApiResponse<String>, S
         Err(e) => return Ok(e),
     };
 
+    if let Err(e) = check_favorite_cooldown(&user.id, &mosque_id, &responder).await {
+        return Ok(e);
+    }
+
     let remove_favorite_query = "DELETE favorited WHERE in = $user_id AND out = $mosque_id";
 
     let result = db
@@ -477,7 +1437,40 @@ pub async fn remove_favorite(mosque_id: String) -> Result<ApiResponse<String>, S
         }
     }
 
-    Ok(responder.ok("Successfully removed the mosque from favorite list of the user".to_string()))
+    Ok(responder.ok(messages::FAVORITE_MOSQUE_REMOVED.to_string()))
+}
+
+#[server(input = Json, output = Json, prefix = "/mosques", endpoint = "fetch-favorite-mosques")]
+pub async fn fetch_favorite_mosques() -> Result<ApiResponse<Vec<MosqueResponse>>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<Vec<MosqueResponse>>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+    responder.cache_privately();
+
+    let mosques: Vec<MosqueSearchResult> = match db
+        .query("SELECT * FROM $user_id->favorited->mosques FETCH imam, muazzin")
+        .bind(("user_id", user.id))
+        .await
+        .and_then(|mut response| response.take(0))
+    {
+        Ok(mosques) => mosques,
+        Err(e) => {
+            error!(?e, "Failed to fetch favorite mosques for the user");
+            return Ok(
+                responder.internal_server_error("Failed to fetch favorite mosques".to_string())
+            );
+        }
+    };
+
+    match into_mosque_responses(mosques, &db).await {
+        Ok(mosque_responses) => Ok(responder.ok(mosque_responses)),
+        Err(e) => {
+            error!(?e, "Failed to fetch contacts of favorite mosques");
+            Ok(responder.internal_server_error("Failed to fetch favorite mosques".to_string()))
+        }
+    }
 }
 
 #[server(input = PatchJson, output = Json, prefix = "/mosques", endpoint = "update-personnel")]
@@ -522,6 +1515,17 @@ pub async fn update_mosque_personnel(
         }
     }
 
+    match find_mosque(&mosque_id, &db).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Ok(responder.not_found("No mosque found with the provided ID".to_string()));
+        }
+        Err(e) => {
+            error!(?e, "Failed to fetch the mosque");
+            return Ok(responder.internal_server_error("Failed to fetch the mosque".to_string()));
+        }
+    }
+
     let update_query = format!(
         "UPDATE mosques SET {} = $person_id WHERE id = $mosque_id",
         person_type
@@ -529,8 +1533,9 @@ pub async fn update_mosque_personnel(
     let result = db
         .query(update_query)
         .bind(("person_id", person_id))
-        .bind(("mosque_id", mosque_id))
+        .bind(("mosque_id", mosque_id.clone()))
         .await;
+    invalidate_mosque(&mosque_id).await;
 
     match result {
         Ok(_) => Ok(responder.ok(format!(
@@ -545,3 +1550,71 @@ pub async fn update_mosque_personnel(
         }
     }
 }
+
+#[server(input = PatchJson, output = Json, prefix = "/mosques", endpoint = "update-details")]
+pub async fn update_mosque_details(
+    mosque_id: String,
+    name: Option<String>,
+    street: Option<String>,
+    city: Option<String>,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let mosque_id: RecordId = match parse_record_id(&mosque_id, "mosque_id") {
+        Ok(id) => id,
+        Err(e) => return Ok(e),
+    };
+
+    if !user.is_app_admin() {
+        if let Err(e) = is_mosque_admin(&user.id, &mosque_id, &db).await {
+            return match e {
+                UserElevationError::Unauthorized => {
+                    error!(
+                        "Unauthorized attempt to update mosque {} by user {}",
+                        mosque_id, user.id
+                    );
+                    Ok(responder
+                        .forbidden("Only admins of this mosque can update its details".to_string()))
+                }
+                _ => {
+                    error!(?e, "Failed to verify admin permissions");
+                    Ok(responder
+                        .internal_server_error("Failed to verify admin permissions".to_string()))
+                }
+            };
+        }
+    }
+
+    let name = name.map(|name| name.trim().to_string());
+    if name.as_deref() == Some("") {
+        return Ok(responder.bad_request("Mosque name must not be empty".to_string()));
+    }
+
+    let update = MosqueDetailsUpdate {
+        normalized_name: name.as_deref().map(normalize_mosque_name),
+        name,
+        street,
+        city,
+    };
+
+    let result = db.update(mosque_id.clone()).merge(update).await;
+    invalidate_mosque(&mosque_id).await;
+    let updated: Option<MosqueRecord> = match result {
+        Ok(updated) => updated,
+        Err(e) => {
+            error!(?e, "Failed to update mosque details");
+            return Ok(responder.internal_server_error(
+                "Failed to update mosque details due to database error".to_string(),
+            ));
+        }
+    };
+
+    match updated {
+        Some(_) => Ok(responder.ok(messages::MOSQUE_DETAILS_UPDATED.to_string())),
+        None => Ok(responder.not_found("No mosque found with the provided ID".to_string())),
+    }
+}
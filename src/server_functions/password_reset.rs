@@ -0,0 +1,104 @@
+#[cfg(feature = "ssr")]
+use actix_web::web;
+#[cfg(feature = "ssr")]
+use garde::Validate;
+use leptos::{prelude::ServerFnError, server_fn::codec::Json, *};
+#[cfg(feature = "ssr")]
+use tracing::error;
+
+#[cfg(feature = "ssr")]
+use crate::auth::password_reset::{create_password_reset, redeem_password_reset};
+#[cfg(feature = "ssr")]
+use crate::errors::password_reset::PasswordResetError;
+#[cfg(feature = "ssr")]
+use crate::messages;
+#[cfg(feature = "ssr")]
+use crate::models::auth::NewPassword;
+use crate::models::{api_responses::ApiResponse, user::Identifier};
+#[cfg(feature = "ssr")]
+use crate::services::notifier::Notifier;
+#[cfg(feature = "ssr")]
+use crate::utils::rate_limit::check_client_rate_limit;
+#[cfg(feature = "ssr")]
+use crate::utils::ssr::{ServerResponse, get_server_context};
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "request-password-reset")]
+pub async fn request_password_reset(
+    identifier: Identifier,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if let Err(retry_after) = check_client_rate_limit(|limiters| &limiters.password_reset).await {
+        error!("Password reset rate limit exceeded");
+        return Ok(responder.rate_limited(
+            retry_after,
+            "Too many password resets requested, please try again later".to_string(),
+        ));
+    }
+
+    let reset_url = match std::env::var("PASSWORD_RESET_URL") {
+        Ok(url) => url,
+        Err(e) => {
+            error!(?e, "PASSWORD_RESET_URL is not set");
+            return Ok(responder.internal_server_error("Internal server error".to_string()));
+        }
+    };
+
+    let notifier = match leptos_actix::extract::<web::Data<dyn Notifier>>().await {
+        Ok(notifier) => notifier,
+        Err(e) => {
+            error!(?e, "Failed to extract the notifier");
+            return Ok(responder.internal_server_error("Internal server error".to_string()));
+        }
+    };
+
+    match create_password_reset(&identifier, &reset_url, notifier.get_ref(), &db).await {
+        Ok(()) => Ok(responder.ok(messages::PASSWORD_RESET_SENT.to_string())),
+        Err(e @ PasswordResetError::UnsupportedIdentifier) => {
+            Ok(responder.unprocessable_entity(e.to_string()))
+        }
+        Err(e) => {
+            error!(?e, "Failed to create a password reset");
+            Ok(responder
+                .internal_server_error("Failed to send the password reset link".to_string()))
+        }
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "reset-password")]
+pub async fn reset_password(
+    token: String,
+    new_password: String,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    let new_password = NewPassword {
+        password: new_password,
+    };
+    if let Err(error) = new_password.validate() {
+        let errors = error
+            .iter()
+            .map(|(field, msg)| format!("{}, {}", field, msg))
+            .collect::<Vec<_>>();
+        return Ok(responder.unprocessable_entity(errors.join("\n")));
+    }
+
+    match redeem_password_reset(&token, &new_password.password, &db).await {
+        Ok(_) => Ok(responder.ok(messages::PASSWORD_RESET.to_string())),
+        Err(e @ (PasswordResetError::InvalidToken | PasswordResetError::TokenExpired)) => {
+            Ok(responder.bad_request(e.to_string()))
+        }
+        Err(e) => {
+            error!(?e, "Failed to reset the password");
+            Ok(responder.internal_server_error("Failed to reset the password".to_string()))
+        }
+    }
+}
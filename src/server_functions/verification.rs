@@ -0,0 +1,89 @@
+#[cfg(feature = "ssr")]
+use actix_web::web;
+use leptos::{prelude::ServerFnError, server_fn::codec::Json, *};
+#[cfg(feature = "ssr")]
+use tracing::error;
+
+#[cfg(feature = "ssr")]
+use crate::auth::verification::{
+    confirm_verification as confirm_verification_token, send_verification as send_verification_link,
+};
+#[cfg(feature = "ssr")]
+use crate::errors::verification::VerificationError;
+#[cfg(feature = "ssr")]
+use crate::messages;
+use crate::models::{api_responses::ApiResponse, user::Identifier};
+#[cfg(feature = "ssr")]
+use crate::services::notifier::Notifier;
+#[cfg(feature = "ssr")]
+use crate::utils::rate_limit::check_client_rate_limit;
+#[cfg(feature = "ssr")]
+use crate::utils::ssr::{ServerResponse, get_authenticated_user, get_server_context};
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "send-verification")]
+pub async fn send_verification(
+    identifier: Identifier,
+) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db, user) = match get_authenticated_user::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    if let Err(retry_after) = check_client_rate_limit(|limiters| &limiters.registration).await {
+        error!("Verification rate limit exceeded");
+        return Ok(responder.rate_limited(
+            retry_after,
+            "Too many verification links requested, please try again later".to_string(),
+        ));
+    }
+
+    let link_url = match std::env::var("VERIFICATION_URL") {
+        Ok(url) => url,
+        Err(e) => {
+            error!(?e, "VERIFICATION_URL is not set");
+            return Ok(responder.internal_server_error("Internal server error".to_string()));
+        }
+    };
+
+    let notifier = match leptos_actix::extract::<web::Data<dyn Notifier>>().await {
+        Ok(notifier) => notifier,
+        Err(e) => {
+            error!(?e, "Failed to extract the notifier");
+            return Ok(responder.internal_server_error("Internal server error".to_string()));
+        }
+    };
+
+    match send_verification_link(&user.id, &identifier, &link_url, notifier.get_ref(), &db).await {
+        Ok(()) => Ok(responder.ok(messages::VERIFICATION_SENT.to_string())),
+        Err(e @ VerificationError::UnsupportedIdentifier) => {
+            Ok(responder.unprocessable_entity(e.to_string()))
+        }
+        Err(e @ VerificationError::IdentifierNotFound) => Ok(responder.not_found(e.to_string())),
+        Err(e @ VerificationError::AlreadyVerified) => Ok(responder.conflict(e.to_string())),
+        Err(e) => {
+            error!(?e, "Failed to send a verification link");
+            Ok(responder.internal_server_error("Failed to send the verification link".to_string()))
+        }
+    }
+}
+
+#[server(input = Json, output = Json, prefix = "/auth", endpoint = "confirm-verification")]
+pub async fn confirm_verification(token: String) -> Result<ApiResponse<String>, ServerFnError> {
+    let (response_options, db) = match get_server_context::<String>().await {
+        Ok(ctx) => ctx,
+        Err(e) => return Ok(e),
+    };
+    let responder = ServerResponse::new(response_options);
+
+    match confirm_verification_token(&token, &db).await {
+        Ok(_) => Ok(responder.ok(messages::ACCOUNT_VERIFIED.to_string())),
+        Err(e @ (VerificationError::InvalidToken | VerificationError::TokenExpired)) => {
+            Ok(responder.bad_request(e.to_string()))
+        }
+        Err(e) => {
+            error!(?e, "Failed to confirm a verification");
+            Ok(responder.internal_server_error("Failed to verify the account".to_string()))
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
+use tracing::warn;
+
+use crate::{
+    errors::events::EventError,
+    models::{events::Event, user::UserIdentifier},
+    services::notifier::Notifier,
+};
+
+/// Longest message `notify_attendees` sends, so it still fits a few SMS segments.
+pub const MAX_ANNOUNCEMENT_LENGTH: usize = 480;
+
+/// Sends `message` to everyone who RSVP'd to `event`, by email when they have one and by SMS
+/// otherwise. A failed delivery is logged and skipped so one bad address can't stop the rest.
+/// Returns how many attendees were reached.
+pub async fn notify_attendees(
+    event: &Event,
+    message: &str,
+    notifier: &dyn Notifier,
+    db: &Surreal<Client>,
+) -> Result<usize, EventError> {
+    let attendees: Vec<RecordId> = db
+        .query("SELECT VALUE in FROM attending WHERE out = $event")
+        .bind(("event", event.id.clone()))
+        .await?
+        .take(0)?;
+
+    notify_users(event, attendees, message, notifier, db).await
+}
+
+/// Lets the users promoted off the waitlist of `event` know they now have a place.
+pub async fn notify_promoted(
+    event: &Event,
+    promoted: Vec<RecordId>,
+    notifier: &dyn Notifier,
+    db: &Surreal<Client>,
+) -> Result<usize, EventError> {
+    let message = format!(
+        "A place opened up and you are now attending, see you on {}.",
+        event.date.format("%A, %d %B at %H:%M")
+    );
+    notify_users(event, promoted, &message, notifier, db).await
+}
+
+async fn notify_users(
+    event: &Event,
+    users: Vec<RecordId>,
+    message: &str,
+    notifier: &dyn Notifier,
+    db: &Surreal<Client>,
+) -> Result<usize, EventError> {
+    let identifiers: Vec<UserIdentifier> = db
+        .query(
+            "SELECT * FROM user_identifier
+                WHERE user IN $users
+                AND identifier_type IN ['email', 'mobile']",
+        )
+        .bind(("users", users))
+        .await?
+        .take(0)?;
+
+    // An attendee with both an email and a mobile number only gets the email
+    let mut contacts: HashMap<RecordId, UserIdentifier> = HashMap::new();
+    for identifier in identifiers {
+        let prefer_new = contacts
+            .get(&identifier.user)
+            .is_none_or(|current| current.identifier_type != "email");
+        if prefer_new {
+            contacts.insert(identifier.user.clone(), identifier);
+        }
+    }
+
+    let subject = format!("Update for {}", event.title);
+    let mut reached = 0;
+    for contact in contacts.values() {
+        let delivery = if contact.identifier_type == "email" {
+            notifier
+                .send_email(&contact.identifier_value, &subject, message)
+                .await
+        } else {
+            notifier
+                .send_sms(
+                    &contact.identifier_value,
+                    &format!("{}: {}", event.title, message),
+                )
+                .await
+        };
+
+        match delivery {
+            Ok(()) => reached += 1,
+            Err(e) => warn!(?e, user = %contact.user, event = %event.id, "Failed to notify a user"),
+        }
+    }
+
+    Ok(reached)
+}
@@ -0,0 +1,173 @@
+use argon2::{
+    Argon2,
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+};
+use chrono::{Duration, Utc};
+use rand::{Rng, rngs::OsRng};
+use serde::{Deserialize, Serialize};
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client, sql::Datetime};
+
+use crate::{
+    database::expiry::Expirable, errors::mobile_otp::MobileOtpError, services::notifier::Notifier,
+};
+
+pub const MOBILE_OTP_TTL_IN_MINUTES: i64 = 10;
+/// Wrong guesses allowed before the code is locked and a new one has to be requested.
+pub const MOBILE_OTP_MAX_ATTEMPTS: i64 = 5;
+
+#[derive(Debug, Deserialize)]
+struct MobileIdentifier {
+    id: RecordId,
+    identifier_value: String,
+    verified: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct NewMobileOtp {
+    identifier: RecordId,
+    code_hash: String,
+    expires_at: Datetime,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MobileOtp {
+    id: RecordId,
+    code_hash: String,
+    attempts: i64,
+    expires_at: Datetime,
+}
+
+impl Expirable for MobileOtp {
+    fn expires_at(&self) -> &Datetime {
+        &self.expires_at
+    }
+}
+
+pub fn generate_otp() -> String {
+    format!("{:06}", OsRng.gen_range(0..1_000_000))
+}
+
+async fn unverified_mobile_identifier(
+    user: &RecordId,
+    db: &Surreal<Client>,
+) -> Result<MobileIdentifier, MobileOtpError> {
+    let identifier: Option<MobileIdentifier> = db
+        .query(
+            "SELECT id, identifier_value, verified FROM user_identifier
+                WHERE user = $user AND identifier_type = 'mobile'",
+        )
+        .bind(("user", user.clone()))
+        .await?
+        .take(0)?;
+
+    match identifier {
+        None => Err(MobileOtpError::NoMobileIdentifier),
+        Some(identifier) if identifier.verified => Err(MobileOtpError::AlreadyVerified),
+        Some(identifier) => Ok(identifier),
+    }
+}
+
+/// Texts a fresh code to the user's mobile number, replacing any code sent earlier.
+pub async fn request_mobile_otp(
+    user: RecordId,
+    notifier: &dyn Notifier,
+    db: &Surreal<Client>,
+) -> Result<(), MobileOtpError> {
+    let identifier = unverified_mobile_identifier(&user, db).await?;
+
+    let code = generate_otp();
+    let salt = SaltString::generate(&mut OsRng);
+    let code_hash = Argon2::default()
+        .hash_password(code.as_bytes(), &salt)
+        .map_err(MobileOtpError::HashError)?
+        .to_string();
+
+    let otp = NewMobileOtp {
+        identifier: identifier.id.clone(),
+        code_hash,
+        expires_at: Datetime::from(Utc::now() + Duration::minutes(MOBILE_OTP_TTL_IN_MINUTES)),
+    };
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            DELETE mobile_otps WHERE identifier = $identifier;
+            CREATE mobile_otps CONTENT $otp;
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("identifier", identifier.id))
+    .bind(("otp", otp))
+    .await?
+    .check()?;
+
+    let message = format!(
+        "{} is your Merzah verification code. It expires in {} minutes.",
+        code, MOBILE_OTP_TTL_IN_MINUTES
+    );
+    notifier
+        .send_sms(&identifier.identifier_value, &message)
+        .await?;
+
+    Ok(())
+}
+
+/// Checks `code` against the pending one and marks the user and their mobile number verified
+/// on a match. Expired codes are discarded, and a code stops accepting guesses once
+/// `MOBILE_OTP_MAX_ATTEMPTS` wrong ones have been made.
+pub async fn verify_mobile_otp(
+    user: RecordId,
+    code: &str,
+    db: &Surreal<Client>,
+) -> Result<(), MobileOtpError> {
+    let identifier = unverified_mobile_identifier(&user, db).await?;
+
+    let otp: Option<MobileOtp> = db
+        .query("SELECT * FROM mobile_otps WHERE identifier = $identifier")
+        .bind(("identifier", identifier.id.clone()))
+        .await?
+        .take(0)?;
+    let otp = otp.ok_or(MobileOtpError::NoPendingCode)?;
+
+    if otp.is_expired() {
+        let _: Option<MobileOtp> = db.delete(otp.id).await?;
+        return Err(MobileOtpError::CodeExpired);
+    }
+
+    if otp.attempts >= MOBILE_OTP_MAX_ATTEMPTS {
+        return Err(MobileOtpError::TooManyAttempts);
+    }
+
+    let code_hash = PasswordHash::new(&otp.code_hash).map_err(MobileOtpError::HashError)?;
+    if Argon2::default()
+        .verify_password(code.as_bytes(), &code_hash)
+        .is_err()
+    {
+        db.query("UPDATE $otp SET attempts += 1")
+            .bind(("otp", otp.id))
+            .await?
+            .check()?;
+
+        if otp.attempts + 1 >= MOBILE_OTP_MAX_ATTEMPTS {
+            return Err(MobileOtpError::TooManyAttempts);
+        }
+        return Err(MobileOtpError::InvalidCode);
+    }
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            UPDATE $identifier SET verified = true, updated_at = time::now();
+            UPDATE $user SET verified = true, updated_at = time::now();
+            DELETE $otp;
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("identifier", identifier.id))
+    .bind(("user", user))
+    .bind(("otp", otp.id))
+    .await?
+    .check()?;
+
+    Ok(())
+}
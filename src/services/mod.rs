@@ -1,4 +1,14 @@
 pub mod achievement;
 pub mod course_stats;
+pub mod event_announcements;
+pub mod mobile_otp;
+pub mod mosque_adoption;
+pub mod mosque_bounds;
+pub mod mosque_cache;
+pub mod mosque_import;
+pub mod mosque_names;
+pub mod notifier;
 pub mod recurrence;
 pub mod streak;
+pub mod user_verification;
+pub mod waitlist;
@@ -0,0 +1,96 @@
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
+
+use crate::{
+    errors::adoption::AdoptionError,
+    models::mosque::{AdoptionRequest, AdoptionRequestStatus, MosqueRecord, NewAdoptionRequest},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdoptionDecision {
+    Approve,
+    Reject,
+}
+
+/// Files a pending request from `user` to become an admin of `mosque`.
+pub async fn create_adoption_request(
+    user: RecordId,
+    mosque: RecordId,
+    db: &Surreal<Client>,
+) -> Result<RecordId, AdoptionError> {
+    let mosque_record: Option<MosqueRecord> = db.select(mosque.clone()).await?;
+    if mosque_record.is_none() {
+        return Err(AdoptionError::MosqueNotFound);
+    }
+
+    let check_query = r#"
+        SELECT VALUE id FROM handles WHERE in = $user AND out = $mosque;
+        SELECT VALUE id FROM adoption_requests
+            WHERE user = $user AND mosque = $mosque AND status = "pending";
+    "#;
+    let mut check_result = db
+        .query(check_query)
+        .bind(("user", user.clone()))
+        .bind(("mosque", mosque.clone()))
+        .await?;
+
+    let handles: Vec<RecordId> = check_result.take(0)?;
+    if !handles.is_empty() {
+        return Err(AdoptionError::AlreadyAdmin);
+    }
+
+    let pending_requests: Vec<RecordId> = check_result.take(1)?;
+    if !pending_requests.is_empty() {
+        return Err(AdoptionError::AlreadyRequested);
+    }
+
+    let request: Option<AdoptionRequest> = db
+        .create("adoption_requests")
+        .content(NewAdoptionRequest { user, mosque })
+        .await?;
+
+    request
+        .map(|request| request.id)
+        .ok_or(AdoptionError::RequestNotFound)
+}
+
+/// Approves or rejects a pending adoption request. Approving grants the requesting user
+/// the `handles` relation to the mosque in the same transaction.
+pub async fn review_adoption_request(
+    request_id: RecordId,
+    reviewer: RecordId,
+    decision: AdoptionDecision,
+    db: &Surreal<Client>,
+) -> Result<(), AdoptionError> {
+    let request: Option<AdoptionRequest> = db.select(request_id.clone()).await?;
+    let request = request.ok_or(AdoptionError::RequestNotFound)?;
+
+    if request.status != AdoptionRequestStatus::Pending {
+        return Err(AdoptionError::AlreadyReviewed);
+    }
+
+    let query = match decision {
+        AdoptionDecision::Approve => {
+            r#"
+            BEGIN TRANSACTION;
+            UPDATE $request_id SET status = "approved", reviewed_by = $reviewer, reviewed_at = time::now();
+            RELATE $user -> handles -> $mosque SET granted_by = $reviewer;
+            COMMIT TRANSACTION;
+            "#
+        }
+        AdoptionDecision::Reject => {
+            r#"
+            UPDATE $request_id SET status = "rejected", reviewed_by = $reviewer, reviewed_at = time::now();
+            "#
+        }
+    };
+
+    db.query(query)
+        .bind(("request_id", request_id))
+        .bind(("reviewer", reviewer))
+        .bind(("user", request.user))
+        .bind(("mosque", request.mosque))
+        .await?
+        .check()?;
+
+    Ok(())
+}
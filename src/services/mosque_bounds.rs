@@ -0,0 +1,61 @@
+use geo_types::{LineString, Polygon};
+use surrealdb::sql::Geometry;
+
+/// A latitude/longitude bounding box, in the same `south, west, north, east` order
+/// used by the Overpass import.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub south: f64,
+    pub west: f64,
+    pub north: f64,
+    pub east: f64,
+}
+
+impl Bounds {
+    pub fn new(south: f64, west: f64, north: f64, east: f64) -> Self {
+        Bounds {
+            south,
+            west,
+            north,
+            east,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !(-90.0..=90.0).contains(&self.south) || !(-90.0..=90.0).contains(&self.north) {
+            return Err("Latitudes must be between -90 and 90".to_string());
+        }
+
+        if !(-180.0..=180.0).contains(&self.west) || !(-180.0..=180.0).contains(&self.east) {
+            return Err("Longitudes must be between -180 and 180".to_string());
+        }
+
+        if self.south >= self.north {
+            return Err("south must be less than north".to_string());
+        }
+
+        if self.west >= self.east {
+            return Err("west must be less than east".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Size of the box in square degrees, good enough to refuse boxes that span a continent.
+    pub fn area_in_square_degrees(&self) -> f64 {
+        (self.north - self.south) * (self.east - self.west)
+    }
+
+    /// The box as a polygon, for `location INSIDE $bounds` queries.
+    pub fn to_geometry(&self) -> Geometry {
+        let corners = vec![
+            (self.west, self.south),
+            (self.east, self.south),
+            (self.east, self.north),
+            (self.west, self.north),
+            (self.west, self.south),
+        ];
+
+        Geometry::Polygon(Polygon::new(LineString::from(corners), vec![]))
+    }
+}
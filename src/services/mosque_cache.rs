@@ -0,0 +1,112 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use actix_web::web;
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
+use tracing::error;
+
+use crate::models::mosque::MosqueRecord;
+
+/// Mosque records by id, kept for `ttl` so hot mosques are not read on every request.
+/// Every write to a mosque record has to `invalidate` it.
+#[derive(Debug)]
+pub struct MosqueCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, MosqueRecord)>>,
+}
+
+impl MosqueCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reads `MOSQUE_CACHE_TTL_SECS`, falling back to 30 seconds when it is unset or invalid.
+    pub fn from_env() -> Self {
+        let ttl_secs = std::env::var("MOSQUE_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(30);
+
+        Self::new(Duration::from_secs(ttl_secs))
+    }
+
+    pub fn get_at(&self, id: &RecordId, now: Instant) -> Option<MosqueRecord> {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        entries.retain(|_, (cached_at, _)| now.duration_since(*cached_at) < self.ttl);
+        entries
+            .get(&id.to_string())
+            .map(|(_, mosque)| mosque.clone())
+    }
+
+    pub fn insert_at(&self, mosque: MosqueRecord, now: Instant) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(mosque.id.to_string(), (now, mosque));
+    }
+
+    pub fn invalidate(&self, id: &RecordId) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(&id.to_string());
+    }
+
+    /// Returns the cached record for `id`, reading and caching it on a miss. Missing mosques
+    /// are not cached, so a mosque created right after a miss is found straight away.
+    pub async fn get(
+        &self,
+        id: &RecordId,
+        db: &Surreal<Client>,
+    ) -> Result<Option<MosqueRecord>, surrealdb::Error> {
+        if let Some(mosque) = self.get_at(id, Instant::now()) {
+            return Ok(Some(mosque));
+        }
+
+        let mosque: Option<MosqueRecord> = db.select(id.clone()).await?;
+        if let Some(mosque) = &mosque {
+            self.insert_at(mosque.clone(), Instant::now());
+        }
+
+        Ok(mosque)
+    }
+}
+
+async fn extract_mosque_cache() -> Option<web::Data<MosqueCache>> {
+    match leptos_actix::extract::<web::Data<MosqueCache>>().await {
+        Ok(cache) => Some(cache),
+        Err(e) => {
+            error!(?e, "Failed to extract the mosque cache, skipping it");
+            None
+        }
+    }
+}
+
+/// Looks the mosque up through the app's `MosqueCache`, reading the database directly when
+/// the cache is unavailable.
+pub async fn find_mosque(
+    id: &RecordId,
+    db: &Surreal<Client>,
+) -> Result<Option<MosqueRecord>, surrealdb::Error> {
+    match extract_mosque_cache().await {
+        Some(cache) => cache.get(id, db).await,
+        None => db.select(id.clone()).await,
+    }
+}
+
+/// Drops the mosque from the app's `MosqueCache` after it has been written to.
+pub async fn invalidate_mosque(id: &RecordId) {
+    if let Some(cache) = extract_mosque_cache().await {
+        cache.invalidate(id);
+    }
+}
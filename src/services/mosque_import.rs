@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
+
+use crate::models::mosque::MosqueFromOverpass;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ImportCounts {
+    pub inserted: usize,
+    pub skipped: usize,
+}
+
+/// Inserts the mosques whose ids are not in the database yet and skips the rest.
+///
+/// Existing mosques are left untouched so that re-imports never clobber personnel,
+/// prayer times or details edited by admins.
+pub async fn insert_new_mosques(
+    mosques: Vec<MosqueFromOverpass>,
+    db: &Surreal<Client>,
+) -> Result<ImportCounts, surrealdb::Error> {
+    let ids: Vec<RecordId> = mosques.iter().map(|mosque| mosque.id.clone()).collect();
+
+    let existing_ids: Vec<RecordId> = db
+        .query("SELECT VALUE id FROM mosques WHERE id IN $ids")
+        .bind(("ids", ids))
+        .await?
+        .take(0)?;
+
+    let mut seen: HashSet<RecordId> = existing_ids.into_iter().collect();
+    let mut counts = ImportCounts::default();
+    let mut new_mosques = Vec::new();
+
+    for mosque in mosques {
+        if seen.insert(mosque.id.clone()) {
+            new_mosques.push(mosque);
+        } else {
+            counts.skipped += 1;
+        }
+    }
+
+    counts.inserted = new_mosques.len();
+
+    if !new_mosques.is_empty() {
+        db.query("INSERT INTO mosques $mosques")
+            .bind(("mosques", new_mosques))
+            .await?
+            .check()?;
+    }
+
+    Ok(counts)
+}
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
+
+use crate::{models::mosque::MosqueNormalizedName, utils::mosque_name::normalize_mosque_name};
+
+#[derive(Debug, Deserialize)]
+struct NamedMosque {
+    id: RecordId,
+    name: String,
+}
+
+/// Fills in `normalized_name` for mosques saved before the column existed, which name search
+/// could otherwise never find. Mosques that already have one are left alone, so this is safe
+/// to run on every start. Returns how many mosques were updated.
+pub async fn backfill_normalized_names(db: &Surreal<Client>) -> Result<usize, surrealdb::Error> {
+    let mosques: Vec<NamedMosque> = db
+        .query("SELECT id, name FROM mosques WHERE normalized_name = NONE AND name != NONE")
+        .await?
+        .take(0)?;
+
+    let updates: Vec<MosqueNormalizedName> = mosques
+        .into_iter()
+        .map(|mosque| MosqueNormalizedName {
+            id: mosque.id,
+            normalized_name: normalize_mosque_name(&mosque.name),
+        })
+        .collect();
+    let updated = updates.len();
+
+    if updated > 0 {
+        db.query(
+            "FOR $update IN $updates { UPDATE $update.id SET normalized_name = $update.normalized_name; }",
+        )
+        .bind(("updates", updates))
+        .await?
+        .check()?;
+    }
+
+    Ok(updated)
+}
@@ -1,13 +1,14 @@
 use chrono::{
-    DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, TimeZone,
+    DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc,
 };
+use chrono_tz::Tz;
 use std::cmp::min;
 
 #[cfg(feature = "ssr")]
-use crate::models::events::Event;
-use crate::models::events::EventRecurrence;
+use crate::models::events::{Event, RotationLogEntry, RotationOutcome};
+use crate::models::events::{EventRecurrence, RecurrenceUnit};
 #[cfg(feature = "ssr")]
-use surrealdb::{Surreal, engine::remote::ws::Client};
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
 
 pub fn calculate_next_date(
     curr_date: DateTime<FixedOffset>,
@@ -38,65 +39,194 @@ pub fn calculate_next_date(
             Some(curr_date + Duration::days(days_to_add as i64))
         }
 
-        EventRecurrence::Monthly => {
-            let date = curr_date.date_naive();
-            let next_month = if date.month() == 12 {
-                1
-            } else {
-                date.month() + 1
-            };
-            let year = if next_month == 1 {
-                date.year() + 1
-            } else {
-                date.year()
-            };
-            let day = min(date.day(), days_in_month(year, next_month));
+        EventRecurrence::Monthly => add_months(curr_date, 1),
 
-            let next_date: NaiveDate = NaiveDate::from_ymd_opt(year, next_month, day)
-                .or_else(|| NaiveDate::from_ymd_opt(year, next_month, 1))?;
+        EventRecurrence::Quaterly => add_months(curr_date, 3),
 
-            let naive_datetime: NaiveDateTime = next_date.and_time(curr_date.time());
+        EventRecurrence::Yearly => add_months(curr_date, 12),
 
-            match curr_date.timezone().from_local_datetime(&naive_datetime) {
-                LocalResult::Single(dt) => Some(dt),
-                _ => None,
+        EventRecurrence::Custom { unit, every } => {
+            if every == 0 {
+                return None;
             }
-        }
 
-        EventRecurrence::Quaterly => {
-            let date = curr_date.date_naive();
-            let months_to_add = 3;
-            let total_months = (date.year() * 12) + date.month() as i32;
-            let next_total_months = total_months + months_to_add;
-            let next_year = next_total_months / 12;
-            let next_month = (next_total_months % 12) as u32;
-            let next_month = if next_month == 0 { 12 } else { next_month };
-            let day = min(date.day(), days_in_month(next_year, next_month));
-
-            let next_date = NaiveDate::from_ymd_opt(next_year, next_month, day)
-                .or_else(|| NaiveDate::from_ymd_opt(next_year, next_month, 1))?;
-            let naive_datetime = next_date.and_time(curr_date.time());
-
-            match curr_date.timezone().from_local_datetime(&naive_datetime) {
-                LocalResult::Single(dt) => Some(dt),
-                _ => None,
+            match unit {
+                RecurrenceUnit::Day => curr_date.checked_add_signed(Duration::days(every.into())),
+                RecurrenceUnit::Week => curr_date.checked_add_signed(Duration::weeks(every.into())),
+                RecurrenceUnit::Month => add_months(curr_date, every),
+                RecurrenceUnit::Year => add_months(curr_date, every.checked_mul(12)?),
             }
         }
+    }
+}
 
-        EventRecurrence::Yearly => {
-            let date = curr_date.date_naive();
-            let next_year = date.year() + 1;
-            let day = min(date.day(), days_in_month(next_year, date.month()));
+/// Like `calculate_next_date`, but keeps the wall-clock time in `zone` instead of the UTC
+/// offset, so a Friday 6 PM event stays at 6 PM local after a DST change.
+///
+/// A time repeated when the clocks go back resolves to its first instance, and a time skipped
+/// when they go forward moves past the gap.
+pub fn calculate_next_date_in_zone(
+    curr_date: DateTime<FixedOffset>,
+    pattern: EventRecurrence,
+    zone: Tz,
+) -> Option<DateTime<FixedOffset>> {
+    // The recurrence rules only look at the calendar, so run them on the wall-clock time
+    // as if it were UTC and place the result back in the zone afterwards.
+    let wall_clock = curr_date
+        .with_timezone(&zone)
+        .naive_local()
+        .and_utc()
+        .fixed_offset();
+    let next_wall_clock = calculate_next_date(wall_clock, pattern)?.naive_utc();
+
+    let next_date = match zone.from_local_datetime(&next_wall_clock) {
+        LocalResult::Single(date) => date,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => zone
+            .from_local_datetime(&(next_wall_clock + Duration::hours(1)))
+            .earliest()?,
+    };
 
-            let next_date = NaiveDate::from_ymd_opt(next_year, date.month(), day)
-                .or_else(|| NaiveDate::from_ymd_opt(next_year, date.month(), 1))?;
-            let naive_datetime = next_date.and_time(curr_date.time());
+    Some(next_date.fixed_offset())
+}
 
-            match curr_date.timezone().from_local_datetime(&naive_datetime) {
-                LocalResult::Single(dt) => Some(dt),
-                _ => None,
-            }
-        }
+/// Uses `zone` to calculate the next date when the event has one, and the offset stored with
+/// the date otherwise.
+pub fn next_event_date(
+    curr_date: DateTime<FixedOffset>,
+    pattern: EventRecurrence,
+    zone: Option<Tz>,
+) -> Option<DateTime<FixedOffset>> {
+    match zone {
+        Some(zone) => calculate_next_date_in_zone(curr_date, pattern, zone),
+        None => calculate_next_date(curr_date, pattern),
+    }
+}
+
+/// Whether `exception` cancels the occurrence at `date`, comparing calendar days in the
+/// occurrence's offset.
+pub fn on_same_day(exception: DateTime<FixedOffset>, date: DateTime<FixedOffset>) -> bool {
+    exception.with_timezone(date.offset()).date_naive() == date.date_naive()
+}
+
+/// Returns the next occurrence after `curr_date` that doesn't fall on the day of one of
+/// `exception_dates`, along with how many occurrences were stepped through to reach it, the
+/// skipped ones included.
+pub fn next_date_skipping_exceptions(
+    curr_date: DateTime<FixedOffset>,
+    pattern: EventRecurrence,
+    zone: Option<Tz>,
+    exception_dates: &[DateTime<FixedOffset>],
+) -> Option<(DateTime<FixedOffset>, u32)> {
+    let is_exception = |date: DateTime<FixedOffset>| {
+        exception_dates
+            .iter()
+            .any(|exception| on_same_day(*exception, date))
+    };
+
+    let mut next_date = next_event_date(curr_date, pattern.clone(), zone)?;
+    let mut steps = 1;
+    while is_exception(next_date) {
+        next_date = next_event_date(next_date, pattern.clone(), zone)?;
+        steps += 1;
+    }
+
+    Some((next_date, steps))
+}
+
+/// Like `next_date_skipping_exceptions`, but keeps stepping until the occurrence is after
+/// `now`, so an event left overdue for several periods catches up in one go. The returned
+/// steps include every occurrence stepped through on the way.
+pub fn next_date_after(
+    curr_date: DateTime<FixedOffset>,
+    now: DateTime<Utc>,
+    pattern: EventRecurrence,
+    zone: Option<Tz>,
+    exception_dates: &[DateTime<FixedOffset>],
+) -> Option<(DateTime<FixedOffset>, u32)> {
+    let (mut next_date, mut steps) =
+        next_date_skipping_exceptions(curr_date, pattern.clone(), zone, exception_dates)?;
+    while next_date <= now {
+        let (date, more_steps) =
+            next_date_skipping_exceptions(next_date, pattern.clone(), zone, exception_dates)?;
+        next_date = date;
+        steps += more_steps;
+    }
+
+    Some((next_date, steps))
+}
+
+/// Lists the first `count` dates of a series starting at `start`, `start` included, stopping
+/// early at the first date past `end`.
+pub fn next_occurrences(
+    start: DateTime<FixedOffset>,
+    pattern: EventRecurrence,
+    end: Option<DateTime<FixedOffset>>,
+    count: usize,
+) -> Vec<DateTime<FixedOffset>> {
+    next_occurrences_in_zone(start, pattern, end, count, None)
+}
+
+/// `next_occurrences` for a series whose occurrences follow the wall clock of `zone`.
+pub fn next_occurrences_in_zone(
+    start: DateTime<FixedOffset>,
+    pattern: EventRecurrence,
+    end: Option<DateTime<FixedOffset>>,
+    count: usize,
+    zone: Option<Tz>,
+) -> Vec<DateTime<FixedOffset>> {
+    std::iter::successors(Some(start), |date| {
+        next_event_date(*date, pattern.clone(), zone)
+    })
+    .take_while(|date| end.is_none_or(|end| *date <= end))
+    .take(count)
+    .collect()
+}
+
+/// Returns the date of the last occurrence of a series made of `occurrences` events,
+/// the first of which happens at `start`.
+pub fn end_date_after_occurrences(
+    start: DateTime<FixedOffset>,
+    pattern: EventRecurrence,
+    occurrences: u32,
+) -> Option<DateTime<FixedOffset>> {
+    end_date_after_occurrences_in_zone(start, pattern, occurrences, None)
+}
+
+/// `end_date_after_occurrences` for a series whose occurrences follow the wall clock of `zone`.
+pub fn end_date_after_occurrences_in_zone(
+    start: DateTime<FixedOffset>,
+    pattern: EventRecurrence,
+    occurrences: u32,
+    zone: Option<Tz>,
+) -> Option<DateTime<FixedOffset>> {
+    if occurrences == 0 {
+        return None;
+    }
+
+    let mut date = start;
+    for _ in 1..occurrences {
+        date = next_event_date(date, pattern.clone(), zone)?;
+    }
+
+    Some(date)
+}
+
+/// Moves `curr_date` forward by `months` calendar months, keeping the time of day and
+/// clamping the day to the length of the target month, so Jan 31 + 1 month is Feb 28 or 29.
+fn add_months(curr_date: DateTime<FixedOffset>, months: u32) -> Option<DateTime<FixedOffset>> {
+    let date = curr_date.date_naive();
+    let total_months = i64::from(date.year()) * 12 + i64::from(date.month0()) + i64::from(months);
+    let year = i32::try_from(total_months.div_euclid(12)).ok()?;
+    let month = total_months.rem_euclid(12) as u32 + 1;
+    let day = min(date.day(), days_in_month(year, month));
+
+    let next_date = NaiveDate::from_ymd_opt(year, month, day)?;
+    let naive_datetime: NaiveDateTime = next_date.and_time(curr_date.time());
+
+    match curr_date.timezone().from_local_datetime(&naive_datetime) {
+        LocalResult::Single(dt) => Some(dt),
+        _ => None,
     }
 }
 
@@ -118,35 +248,160 @@ fn days_in_month(year: i32, month: u32) -> u32 {
     }
 }
 
+/// What happens to an occurrence's RSVPs once the event moves on to its next date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RotationMode {
+    /// RSVPs belong to a single occurrence and are dropped on rotation.
+    #[default]
+    ClearAttendance,
+    /// RSVPs carry over to the next occurrence, e.g. for standing registrations.
+    KeepAttendance,
+}
+
+/// Records how an event's rotation went in `rotation_log`. A failure to write the log is only
+/// traced, so it never stops the rotation itself.
+#[cfg(feature = "ssr")]
+async fn log_rotation(
+    event: &RecordId,
+    outcome: RotationOutcome,
+    reason: Option<String>,
+    db: &Surreal<Client>,
+) {
+    let entry = RotationLogEntry {
+        event: event.clone(),
+        outcome,
+        reason,
+    };
+
+    let result = db
+        .query("CREATE rotation_log CONTENT $entry")
+        .bind(("entry", entry))
+        .await
+        .and_then(|response| response.check());
+
+    if let Err(e) = result {
+        tracing::error!(?e, %event, "Failed to record the rotation outcome");
+    }
+}
+
+/// Deletes an event whose recurrence series has run out.
+#[cfg(feature = "ssr")]
+async fn end_series(event: &RecordId, db: &Surreal<Client>) -> Result<(), surrealdb::Error> {
+    db.query("DELETE $event")
+        .bind(("event", event.clone()))
+        .await?;
+    tracing::info!("Deleted event {} - recurrence series ended", event);
+    log_rotation(event, RotationOutcome::Deleted, None, db).await;
+    Ok(())
+}
+
+/// Moves `event` to its next upcoming occurrence, stepping over any it has missed. An event
+/// whose next date can't be calculated is flagged with `rotation_error`, which keeps
+/// `check_and_rotate_events` from retrying it until the event is edited.
 #[cfg(feature = "ssr")]
-pub async fn rotate_event(event: Event, db: &Surreal<Client>) -> Result<bool, surrealdb::Error> {
+pub async fn rotate_event(
+    event: Event,
+    mode: RotationMode,
+    db: &Surreal<Client>,
+) -> Result<bool, surrealdb::Error> {
     use tracing::{error, info};
 
     let Some(pattern) = event.recurrence_pattern.clone() else {
         return Ok(false);
     };
 
-    let Some(next_date) = calculate_next_date(event.date, pattern) else {
+    // The occurrence that just passed was the last one of a count-limited series.
+    if event
+        .recurrence_count
+        .is_some_and(|count| event.occurrences_rotated + 1 >= count)
+    {
+        end_series(&event.id, db).await?;
+        return Ok(false);
+    }
+
+    let zone = event
+        .timezone
+        .as_deref()
+        .and_then(|timezone| timezone.parse::<Tz>().ok());
+
+    let Some((next_date, steps)) = next_date_after(
+        event.date,
+        Utc::now(),
+        pattern.clone(),
+        zone,
+        &event.exception_dates,
+    ) else {
         error!("Failed to calculate next date for event {}", event.id);
+        let reason = format!(
+            "No {:?} occurrence could be calculated after {}",
+            pattern, event.date
+        );
+        db.query("UPDATE $event SET rotation_error = $reason")
+            .bind(("event", event.id.clone()))
+            .bind(("reason", reason.clone()))
+            .await?
+            .check()?;
+        log_rotation(&event.id, RotationOutcome::Failed, Some(reason), db).await;
         return Ok(false);
     };
 
-    if let Some(end_date) = event.recurrence_end_date {
-        if next_date > end_date {
-            db.query("DELETE $event")
-                .bind(("event", event.id.clone()))
-                .await?;
-            info!("Deleted event {} - recurrence series ended", event.id);
-            return Ok(false);
-        }
+    // Catching up on missed occurrences or skipping cancelled ones can also run a series out.
+    let count_exhausted = event
+        .recurrence_count
+        .is_some_and(|count| event.occurrences_rotated + 1 + steps > count);
+    let end_date_passed = event
+        .recurrence_end_date
+        .is_some_and(|end_date| next_date > end_date);
+
+    if count_exhausted || end_date_passed {
+        end_series(&event.id, db).await?;
+        return Ok(false);
     }
 
-    db.query("UPDATE $event SET date = $next_date")
+    // The deadline keeps the same lead time on the next occurrence.
+    let next_rsvp_deadline = event
+        .rsvp_deadline
+        .map(|deadline| (deadline + (next_date - event.date)).to_rfc3339());
+
+    let rotate_query = match mode {
+        RotationMode::ClearAttendance => {
+            r#"
+            BEGIN TRANSACTION;
+            UPDATE $event SET
+                date = $next_date,
+                rsvp_deadline = $rsvp_deadline,
+                occurrences_rotated += $steps;
+            DELETE attending WHERE out = $event;
+            DELETE waitlisted WHERE out = $event;
+            COMMIT TRANSACTION;
+            "#
+        }
+        RotationMode::KeepAttendance => {
+            r#"
+            UPDATE $event SET
+                date = $next_date,
+                rsvp_deadline = $rsvp_deadline,
+                occurrences_rotated += $steps
+            "#
+        }
+    };
+
+    let result = db
+        .query(rotate_query)
         .bind(("event", event.id.clone()))
         .bind(("next_date", next_date.to_rfc3339()))
-        .await?;
+        .bind(("rsvp_deadline", next_rsvp_deadline))
+        .bind(("steps", steps))
+        .await
+        .and_then(|response| response.check());
+
+    if let Err(e) = result {
+        log_rotation(&event.id, RotationOutcome::Failed, Some(e.to_string()), db).await;
+        return Err(e);
+    }
 
     info!("Rotated event {} to {}", event.id, next_date);
+    log_rotation(&event.id, RotationOutcome::Rotated, None, db).await;
     Ok(true)
 }
 
@@ -158,6 +413,8 @@ pub async fn check_and_rotate_events(db: &Surreal<Client>) -> Result<usize, surr
         SELECT * FROM events
         WHERE <datetime>date < time::now()
         AND recurrence_pattern != NONE
+        AND rotation_error = NONE
+        AND deleted_at = NONE
     "#;
 
     let events: Vec<Event> = db.query(search_query).await?.take(0)?;
@@ -165,7 +422,7 @@ pub async fn check_and_rotate_events(db: &Surreal<Client>) -> Result<usize, surr
     let mut rotated_count = 0;
 
     for event in events {
-        match rotate_event(event, db).await {
+        match rotate_event(event, RotationMode::default(), db).await {
             Ok(true) => rotated_count += 1,
             Ok(false) => {}
             Err(e) => error!("Failed to rotate event: {}", e),
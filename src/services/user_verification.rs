@@ -0,0 +1,28 @@
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+/// Marks as verified the users saved before verification existed, who have no `verified` at
+/// all and would otherwise be locked out of favorites and RSVPs, and users with an OAuth
+/// identifier, whose provider already vouches for them. Users who registered since and haven't
+/// verified are left alone, so this is safe to run on every start. Returns how many users were
+/// updated.
+pub async fn backfill_verified_users(db: &Surreal<Client>) -> Result<usize, surrealdb::Error> {
+    let updated: Option<usize> = db
+        .query(
+            r#"
+            LET $oauth_users = (
+                SELECT VALUE user FROM user_identifier
+                WHERE identifier_type NOT IN ['email', 'mobile']
+            );
+            LET $updated = (
+                UPDATE users SET verified = true
+                WHERE verified = NONE OR (verified = false AND id IN $oauth_users)
+                RETURN id
+            );
+            RETURN array::len($updated);
+        "#,
+        )
+        .await?
+        .take(2)?;
+
+    Ok(updated.unwrap_or_default())
+}
@@ -0,0 +1,128 @@
+use serde::Deserialize;
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
+use tracing::warn;
+
+use crate::{
+    database::query::{TRANSACTION_RESULT, take_checked},
+    errors::{app::AppError, events::EventError},
+};
+
+/// Simultaneous cancellations of the same event conflict when they promote from the waitlist,
+/// so the one that loses is retried against the other's result. Other failures aren't retried,
+/// they would only fail the same way again.
+const CANCEL_RSVP_ATTEMPTS: usize = 3;
+
+#[derive(Debug, PartialEq)]
+pub enum RsvpOutcome {
+    Attending,
+    Waitlisted,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CancelledRsvp {
+    /// Whether the user was attending or waitlisted at all.
+    pub cancelled: bool,
+    /// Waitlisted users who took the places that opened up, oldest first.
+    pub promoted: Vec<RecordId>,
+}
+
+/// RSVPs `user` to the event, or adds them to its waitlist when it is at capacity.
+pub async fn rsvp(
+    user: RecordId,
+    event_id: RecordId,
+    db: &Surreal<Client>,
+) -> Result<RsvpOutcome, EventError> {
+    let rsvp_transaction = r#"
+        BEGIN TRANSACTION;
+        RETURN {
+            LET $capacity = $event_id.capacity;
+            LET $full = $capacity != NONE
+                AND array::len(SELECT VALUE id FROM attending WHERE out = $event_id) >= $capacity;
+            IF $full {
+                RELATE $user_id -> waitlisted -> $event_id;
+            } ELSE {
+                RELATE $user_id -> attending -> $event_id;
+            };
+            $full
+        };
+        COMMIT TRANSACTION;
+    "#;
+
+    let response = db
+        .query(rsvp_transaction)
+        .bind(("user_id", user))
+        .bind(("event_id", event_id))
+        .await?;
+    let waitlisted: Option<bool> = take_checked(response, TRANSACTION_RESULT)?;
+
+    if waitlisted.ok_or(EventError::MissingResult)? {
+        Ok(RsvpOutcome::Waitlisted)
+    } else {
+        Ok(RsvpOutcome::Attending)
+    }
+}
+
+/// Drops `user` from the event's attendees or waitlist and fills any places that opened up
+/// from the waitlist, in one transaction. The places are worked out from the committed
+/// attendance rather than from this cancellation alone, so a retry after a conflicting
+/// cancellation never promotes past the event's capacity.
+pub async fn cancel_rsvp(
+    user: RecordId,
+    event_id: RecordId,
+    db: &Surreal<Client>,
+) -> Result<CancelledRsvp, EventError> {
+    let cancel_transaction = r#"
+        BEGIN TRANSACTION;
+        RETURN {
+            LET $attending = (DELETE attending WHERE in = $user_id AND out = $event_id RETURN BEFORE);
+            LET $waiting = (DELETE waitlisted WHERE in = $user_id AND out = $event_id RETURN BEFORE);
+
+            LET $capacity = $event_id.capacity;
+            LET $open_places = IF $capacity = NONE {
+                0
+            } ELSE {
+                $capacity - array::len(SELECT VALUE id FROM attending WHERE out = $event_id)
+            };
+            LET $promoted = IF $open_places > 0 {
+                (SELECT in, created_at FROM waitlisted
+                    WHERE out = $event_id
+                    ORDER BY created_at ASC
+                    LIMIT $open_places).in
+            } ELSE {
+                []
+            };
+            FOR $promoted_user IN $promoted {
+                DELETE waitlisted WHERE in = $promoted_user AND out = $event_id;
+                RELATE $promoted_user -> attending -> $event_id;
+            };
+
+            {
+                cancelled: array::len($attending) + array::len($waiting) > 0,
+                promoted: $promoted
+            }
+        };
+        COMMIT TRANSACTION;
+    "#;
+
+    let mut attempt = 1;
+    loop {
+        let result = db
+            .query(cancel_transaction)
+            .bind(("user_id", user.clone()))
+            .bind(("event_id", event_id.clone()))
+            .await
+            .map_err(AppError::from)
+            .and_then(|response| {
+                take_checked::<Option<CancelledRsvp>>(response, TRANSACTION_RESULT)
+            });
+
+        match result {
+            Ok(cancelled) => return cancelled.ok_or(EventError::MissingResult),
+            Err(e) if e.is_transaction_conflict() && attempt < CANCEL_RSVP_ATTEMPTS => {
+                warn!(?e, attempt, event = %event_id, "Retrying an RSVP cancellation");
+                attempt += 1;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
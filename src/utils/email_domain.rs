@@ -0,0 +1,129 @@
+/// Well-known throwaway inbox providers. Extra domains can be blocked through the
+/// `DISPOSABLE_EMAIL_DOMAINS` environment variable.
+const DISPOSABLE_EMAIL_DOMAINS: [&str; 30] = [
+    "10minutemail.com",
+    "33mail.com",
+    "dispostable.com",
+    "emailondeck.com",
+    "fakeinbox.com",
+    "getairmail.com",
+    "getnada.com",
+    "guerrillamail.com",
+    "guerrillamail.net",
+    "guerrillamailblock.com",
+    "harakirimail.com",
+    "maildrop.cc",
+    "mailcatch.com",
+    "mailinator.com",
+    "mailnesia.com",
+    "mintemail.com",
+    "mohmal.com",
+    "moakt.com",
+    "mytemp.email",
+    "sharklasers.com",
+    "spamgourmet.com",
+    "temp-mail.org",
+    "tempail.com",
+    "tempmail.com",
+    "tempmailo.com",
+    "throwawaymail.com",
+    "trashmail.com",
+    "yopmail.com",
+    "yopmail.net",
+    "zetmail.com",
+];
+
+/// Which email domains registration accepts.
+#[derive(Debug, Clone)]
+pub struct EmailDomainPolicy {
+    pub block_disposable: bool,
+    pub extra_blocked_domains: Vec<String>,
+}
+
+impl Default for EmailDomainPolicy {
+    fn default() -> Self {
+        Self {
+            block_disposable: true,
+            extra_blocked_domains: Vec::new(),
+        }
+    }
+}
+
+impl EmailDomainPolicy {
+    /// Blocking is on unless `BLOCK_DISPOSABLE_EMAILS` is `false`. `DISPOSABLE_EMAIL_DOMAINS`
+    /// takes a comma separated list of domains to block on top of the built-in ones.
+    pub fn from_env() -> Self {
+        let block_disposable = std::env::var("BLOCK_DISPOSABLE_EMAILS")
+            .map(|value| !value.trim().eq_ignore_ascii_case("false"))
+            .unwrap_or(true);
+        let extra_blocked_domains = std::env::var("DISPOSABLE_EMAIL_DOMAINS")
+            .map(|domains| {
+                domains
+                    .split(',')
+                    .map(|domain| domain.trim().to_lowercase())
+                    .filter(|domain| !domain.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            block_disposable,
+            extra_blocked_domains,
+        }
+    }
+
+    /// Whether `domain`, or a domain it is a subdomain of, is blocked.
+    pub fn is_blocked(&self, domain: &str) -> bool {
+        if !self.block_disposable {
+            return false;
+        }
+
+        let domain = domain.to_lowercase();
+        let mut candidate = domain.as_str();
+        loop {
+            if DISPOSABLE_EMAIL_DOMAINS.contains(&candidate)
+                || self
+                    .extra_blocked_domains
+                    .iter()
+                    .any(|blocked| blocked == candidate)
+            {
+                return true;
+            }
+
+            match candidate.split_once('.') {
+                Some((_, parent)) if parent.contains('.') => candidate = parent,
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// The part of an email address after the last `@`.
+pub fn email_domain(email: &str) -> Option<&str> {
+    email
+        .rsplit_once('@')
+        .map(|(_, domain)| domain)
+        .filter(|domain| !domain.is_empty())
+}
+
+/// Whether the domain can receive mail from the internet: at least two dot separated labels
+/// made of letters, digits and hyphens, ending in an alphabetic top-level domain.
+pub fn is_valid_email_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.len() < 2 {
+        return false;
+    }
+
+    let labels_are_valid = labels.iter().all(|label| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    });
+    let top_level_domain = labels[labels.len() - 1];
+
+    labels_are_valid
+        && top_level_domain.len() >= 2
+        && top_level_domain.chars().all(|c| c.is_ascii_alphabetic())
+}
@@ -1,7 +1,11 @@
 #[cfg(feature = "ssr")]
 pub mod education_auth;
+pub mod email_domain;
+pub mod mosque_name;
 pub mod parsing;
 #[cfg(feature = "ssr")]
+pub mod rate_limit;
+#[cfg(feature = "ssr")]
 pub mod ssr;
 pub mod token_generator;
 #[cfg(feature = "ssr")]
@@ -0,0 +1,86 @@
+/// Words that carry no meaning when searching for a mosque by name ("Masjid Al-Noor"
+/// and "Noor Mosque" should both be found by searching "noor").
+const IGNORED_NAME_WORDS: [&str; 14] = [
+    "masjid", "masjed", "masjide", "musjid", "mosque", "musalla", "jamia", "jama", "jame", "jamaa",
+    "al", "el", "e", "the",
+];
+
+/// Lowercases a mosque name, turns punctuation into spaces and drops generic words like
+/// "Masjid" or "Mosque". Falls back to the plain lowercased name when nothing is left.
+pub fn normalize_mosque_name(name: &str) -> String {
+    let lowercased: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    let words: Vec<&str> = lowercased.split_whitespace().collect();
+    let meaningful: Vec<&str> = words
+        .iter()
+        .copied()
+        .filter(|word| !IGNORED_NAME_WORDS.contains(word))
+        .collect();
+
+    if meaningful.is_empty() {
+        words.join(" ")
+    } else {
+        meaningful.join(" ")
+    }
+}
+
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution_cost = if a_char == *b_char { 0 } else { 1 };
+            current[j + 1] = (previous[j] + substitution_cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b_chars.len()]
+}
+
+fn allowed_typos(word: &str) -> usize {
+    match word.chars().count() {
+        0..=3 => 0,
+        4..=6 => 1,
+        _ => 2,
+    }
+}
+
+/// Scores how well a normalized query matches a normalized mosque name.
+///
+/// Every query word has to match a name word either as a prefix or within a small edit
+/// distance. Returns `None` when the name doesn't match, otherwise the total edit distance
+/// (lower is better).
+pub fn name_match_score(normalized_query: &str, normalized_name: &str) -> Option<usize> {
+    let name_words: Vec<&str> = normalized_name.split_whitespace().collect();
+    let mut score = 0;
+
+    for query_word in normalized_query.split_whitespace() {
+        let best = name_words
+            .iter()
+            .map(|name_word| {
+                if name_word.starts_with(query_word) {
+                    0
+                } else {
+                    levenshtein(query_word, name_word)
+                }
+            })
+            .min()?;
+
+        if best > allowed_typos(query_word) {
+            return None;
+        }
+        score += best;
+    }
+
+    Some(score)
+}
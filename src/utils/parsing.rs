@@ -1,4 +1,6 @@
 use crate::models::api_responses::ApiResponse;
+#[cfg(feature = "ssr")]
+use crate::models::api_responses::ErrorCode;
 
 #[cfg(feature = "ssr")]
 use actix_web::http::StatusCode;
@@ -17,6 +19,9 @@ pub fn parse_record_id<T>(id: &str, field_name: &str) -> Result<RecordId, ApiRes
         let response_options = expect_context::<ResponseOptions>();
         response_options.set_status(StatusCode::BAD_REQUEST);
 
-        ApiResponse::error(format!("Failed to parse {}", field_name))
+        ApiResponse::error_with_code(
+            ErrorCode::BadRequest,
+            format!("Failed to parse {}", field_name),
+        )
     })
 }
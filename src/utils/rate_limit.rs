@@ -0,0 +1,177 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use actix_web::web;
+use tracing::{error, warn};
+
+use crate::{models::user::Identifier, utils::ssr::current_request};
+
+/// The key shared by clients whose IP can't be read.
+const UNKNOWN_CLIENT: &str = "unknown";
+
+/// Sliding-window limiter allowing at most `max_requests` per key within `window`.
+#[derive(Debug)]
+pub struct RateLimiter {
+    max_requests: usize,
+    window: Duration,
+    hits: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests: usize, window: Duration) -> Self {
+        Self {
+            max_requests,
+            window,
+            hits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reads `{prefix}_RATE_LIMIT_MAX_REQUESTS` and `{prefix}_RATE_LIMIT_WINDOW_SECS`,
+    /// falling back to the given defaults when they are unset or invalid.
+    pub fn from_env(prefix: &str, default_max_requests: usize, default_window_secs: u64) -> Self {
+        let max_requests = std::env::var(format!("{prefix}_RATE_LIMIT_MAX_REQUESTS"))
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default_max_requests);
+        let window_secs = std::env::var(format!("{prefix}_RATE_LIMIT_WINDOW_SECS"))
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default_window_secs);
+
+        Self::new(max_requests, Duration::from_secs(window_secs))
+    }
+
+    /// Records a request for `key`. When the limit has already been reached the request is
+    /// not recorded and the time until the oldest one leaves the window is returned.
+    pub fn check(&self, key: &str) -> Result<(), Duration> {
+        self.check_at(key, Instant::now())
+    }
+
+    pub fn check_at(&self, key: &str, now: Instant) -> Result<(), Duration> {
+        let mut hits = self
+            .hits
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        hits.retain(|_, times| {
+            while times
+                .front()
+                .is_some_and(|time| now.duration_since(*time) >= self.window)
+            {
+                times.pop_front();
+            }
+            !times.is_empty()
+        });
+
+        let times = hits.entry(key.to_string()).or_default();
+        if times.len() >= self.max_requests {
+            let retry_after = times
+                .front()
+                .map(|oldest| self.window.saturating_sub(now.duration_since(*oldest)))
+                .unwrap_or(self.window);
+            return Err(retry_after);
+        }
+
+        times.push_back(now);
+        Ok(())
+    }
+}
+
+/// The rate limiters shared by every worker of the app.
+#[derive(Debug)]
+pub struct RateLimiters {
+    /// Account creation and verification links sent, 5 per hour per IP by default.
+    pub registration: RateLimiter,
+    /// Password login attempts, 5 per minute per account by default.
+    pub login: RateLimiter,
+    /// Mobile verification codes sent, 3 per 15 minutes per IP by default.
+    pub mobile_otp: RateLimiter,
+    /// Magic login links sent, 5 per 15 minutes per IP by default.
+    pub magic_link: RateLimiter,
+    /// Password reset links sent, 5 per 15 minutes per IP by default.
+    pub password_reset: RateLimiter,
+    /// Favorites added or removed, 4 per minute per user and mosque by default.
+    pub favorite_toggle: RateLimiter,
+    /// Announcements sent to an event's attendees, 3 per hour per admin and event by default.
+    pub attendee_notification: RateLimiter,
+}
+
+impl RateLimiters {
+    pub fn from_env() -> Self {
+        Self {
+            registration: RateLimiter::from_env("REGISTER", 5, 60 * 60),
+            login: RateLimiter::from_env("LOGIN", 5, 60),
+            mobile_otp: RateLimiter::from_env("MOBILE_OTP", 3, 15 * 60),
+            magic_link: RateLimiter::from_env("MAGIC_LINK", 5, 15 * 60),
+            password_reset: RateLimiter::from_env("PASSWORD_RESET", 5, 15 * 60),
+            favorite_toggle: RateLimiter::from_env("FAVORITE_TOGGLE", 4, 60),
+            attendee_notification: RateLimiter::from_env("ATTENDEE_NOTIFICATION", 3, 60 * 60),
+        }
+    }
+}
+
+/// Counts the current request against the limiter picked by `limiter`, keyed by the
+/// client's IP. Returns how long the client has to wait when the limit is exceeded.
+pub async fn check_client_rate_limit(
+    limiter: fn(&RateLimiters) -> &RateLimiter,
+) -> Result<(), Duration> {
+    let Some(limiters) = rate_limiters().await else {
+        return Ok(());
+    };
+
+    // Clients whose IP can't be told share one key, so they are still limited together
+    let client_ip = match current_request::<()>().await {
+        Ok(req) => req.peer_addr().map(|addr| addr.ip().to_string()),
+        Err(_) => {
+            warn!("Rate limiting a request without its IP along with other clients of unknown IP");
+            None
+        }
+    }
+    .unwrap_or_else(|| UNKNOWN_CLIENT.to_string());
+
+    limiter(&limiters).check(&client_ip)
+}
+
+/// The key for limits on attempts against one account, the same however its email or mobile
+/// number was typed.
+pub fn identifier_rate_limit_key(identifier: &Identifier) -> String {
+    let (identifier_type, identifier_value) = match identifier.clone().normalized() {
+        Identifier::Email(email) => ("email", email.trim().to_lowercase()),
+        Identifier::Mobile(mobile) => ("mobile", mobile),
+        Identifier::Google(id) => ("google", id),
+        Identifier::Meta(id) => ("meta", id),
+        Identifier::Instagram(id) => ("instagram", id),
+        Identifier::Workos(id) => ("workos", id),
+    };
+
+    format!("{identifier_type}:{identifier_value}")
+}
+
+/// Same as `check_client_rate_limit`, keyed by `key` instead of the client's IP, for limits
+/// on what a user does rather than where they connect from.
+pub async fn check_rate_limit(
+    limiter: fn(&RateLimiters) -> &RateLimiter,
+    key: &str,
+) -> Result<(), Duration> {
+    let Some(limiters) = rate_limiters().await else {
+        return Ok(());
+    };
+
+    limiter(&limiters).check(key)
+}
+
+async fn rate_limiters() -> Option<web::Data<RateLimiters>> {
+    match leptos_actix::extract::<web::Data<RateLimiters>>().await {
+        Ok(limiters) => Some(limiters),
+        Err(e) => {
+            error!(
+                ?e,
+                "Failed to extract rate limiters, skipping the rate limit"
+            );
+            None
+        }
+    }
+}
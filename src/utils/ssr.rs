@@ -1,18 +1,85 @@
 #[cfg(feature = "ssr")]
-use crate::auth::session::get_user_by_session;
-use crate::models::api_responses::ApiResponse;
+use crate::auth::session::{resolve_session, set_session_cookie};
+#[cfg(feature = "ssr")]
+use crate::database::instrumented::CountingDb;
+#[cfg(feature = "multi_tenant")]
+use crate::database::tenants::db_for_request;
+#[cfg(feature = "multi_tenant")]
+use crate::errors::tenant::TenantError;
+use crate::models::api_responses::{ApiResponse, ErrorCode};
+#[cfg(feature = "ssr")]
+use crate::models::auth::Platform;
 #[cfg(feature = "ssr")]
 use crate::models::user::User;
 #[cfg(feature = "ssr")]
-use actix_web::{http::StatusCode, web};
+use actix_web::{
+    HttpRequest,
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::{
+        StatusCode,
+        header::{CACHE_CONTROL, HeaderName, HeaderValue, RETRY_AFTER, VARY},
+    },
+    middleware::Next,
+    web,
+};
 #[cfg(feature = "ssr")]
-use leptos::prelude::use_context;
+use leptos::prelude::{provide_context, use_context};
 #[cfg(feature = "ssr")]
 use leptos_actix::ResponseOptions;
 #[cfg(feature = "ssr")]
+use std::time::Duration;
+#[cfg(feature = "ssr")]
 use surrealdb::{Surreal, engine::remote::ws::Client};
 #[cfg(feature = "ssr")]
-use tracing::error;
+use tracing::{Instrument, error, info_span, warn};
+
+/// The header a request's correlation ID is read from and echoed back in.
+#[cfg(feature = "ssr")]
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Gives every request an ID, taken from its `X-Request-Id` header or a fresh UUID when it
+/// has none. Everything logged while handling the request is in a `request` span carrying the
+/// ID, and the ID is sent back in the response's `X-Request-Id` header. Register it with
+/// `actix_web::middleware::from_fn`.
+#[cfg(feature = "ssr")]
+pub async fn request_id_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let request_id = req
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|request_id| is_valid_request_id(request_id))
+        .map(str::to_string)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    let span = info_span!(
+        "request",
+        request_id = %request_id,
+        method = %req.method(),
+        path = %req.path(),
+    );
+    let mut res = next.call(req).instrument(span).await?;
+
+    // Only printable ASCII gets this far, which is always a valid header value
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        res.headers_mut()
+            .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+    }
+
+    Ok(res)
+}
+
+/// Incoming IDs end up in every log line of the request, so only short, printable ones are
+/// trusted.
+#[cfg(feature = "ssr")]
+fn is_valid_request_id(request_id: &str) -> bool {
+    !request_id.is_empty()
+        && request_id.len() <= 128
+        && request_id.bytes().all(|byte| byte.is_ascii_graphic())
+}
 
 #[cfg(feature = "ssr")]
 pub async fn get_server_context<T>() -> Result<(ResponseOptions, Surreal<Client>), ApiResponse<T>> {
@@ -20,7 +87,10 @@ pub async fn get_server_context<T>() -> Result<(ResponseOptions, Surreal<Client>
         Some(ro) => ro,
         None => {
             error!("Failed to get ResponseOptions from context");
-            return Err(ApiResponse::error("Internal Server Error".to_string()));
+            return Err(ApiResponse::error_with_code(
+                ErrorCode::Internal,
+                "Internal Server Error".to_string(),
+            ));
         }
     };
 
@@ -29,54 +99,219 @@ pub async fn get_server_context<T>() -> Result<(ResponseOptions, Surreal<Client>
         Err(e) => {
             error!(?e, "Failed to extract database client");
             response_options.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            return Err(ApiResponse::error("Internal Server Error".to_string()));
+            return Err(ApiResponse::error_with_code(
+                ErrorCode::Internal,
+                "Internal Server Error".to_string(),
+            ));
+        }
+    };
+
+    #[cfg(feature = "multi_tenant")]
+    let db = match db_for_request(db.get_ref().clone()).await {
+        Ok(db) => db,
+        Err(e @ TenantError::UnknownTenant(_)) => {
+            response_options.set_status(StatusCode::BAD_REQUEST);
+            return Err(ApiResponse::error_with_code(
+                ErrorCode::BadRequest,
+                e.to_string(),
+            ));
+        }
+        Err(e) => {
+            error!(?e, "Failed to pick the tenant's database");
+            response_options.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            return Err(ApiResponse::error_with_code(
+                ErrorCode::Internal,
+                "Internal Server Error".to_string(),
+            ));
         }
     };
+    #[cfg(not(feature = "multi_tenant"))]
+    let db = db.get_ref().clone();
 
-    Ok((response_options, db.get_ref().clone()))
+    Ok((response_options, db))
 }
 
+/// The actix request behind the current server function, and the only place it is extracted
+/// from. When it can't be extracted the response is set to 500 and the error is returned
+/// ready to send.
 #[cfg(feature = "ssr")]
-pub async fn get_authenticated_user<T>()
--> Result<(ResponseOptions, Surreal<Client>, User), ApiResponse<T>> {
-    let (response_options, db) = get_server_context::<T>().await?;
-
-    let req = match leptos_actix::extract::<actix_web::HttpRequest>().await {
-        Ok(req) => req,
+pub async fn current_request<T>() -> Result<HttpRequest, ApiResponse<T>> {
+    match leptos_actix::extract::<HttpRequest>().await {
+        Ok(req) => Ok(req),
         Err(e) => {
             error!(?e, "Failed to extract request");
-            response_options.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-            return Err(ApiResponse::error("Internal Server Error".to_string()));
+            if let Some(response_options) = use_context::<ResponseOptions>() {
+                response_options.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+            Err(ApiResponse::error_with_code(
+                ErrorCode::Internal,
+                "Internal Server Error".to_string(),
+            ))
         }
+    }
+}
+
+/// Cross-checks the `platform` a client asked for against how the request was made, see
+/// `Platform` for the contract. Returns an error message when the `X-Client` header names
+/// the other platform.
+#[cfg(feature = "ssr")]
+pub async fn check_platform_consistency(platform: Platform) -> Result<(), String> {
+    // `current_request` has logged why the request is missing, there is nothing to check
+    let Ok(req) = current_request::<()>().await else {
+        return Ok(());
     };
 
-    let session_token = if let Some(cookie) = req.cookie("__Host-session") {
-        cookie.value().to_string()
-    } else if let Some(auth_header) = req.headers().get("Authorization") {
-        let auth_str = auth_header.to_str().unwrap_or("");
-        if auth_str.starts_with("Bearer ") {
-            auth_str.trim_start_matches("Bearer ").to_string()
-        } else {
-            response_options.set_status(StatusCode::UNAUTHORIZED);
-            return Err(ApiResponse::error("You are not logged in".to_string()));
+    if let Some(client_header) = req.headers().get("X-Client") {
+        let client_header = client_header.to_str().unwrap_or("");
+        match Platform::from_client_header(client_header) {
+            Some(client) if client != platform => {
+                warn!(
+                    client = client.as_str(),
+                    platform = platform.as_str(),
+                    "The X-Client header contradicts the requested platform"
+                );
+                return Err(format!(
+                    "The request asked for the {} platform but the X-Client header says {}",
+                    platform.as_str(),
+                    client.as_str()
+                ));
+            }
+            Some(_) => {}
+            None => warn!(client_header, "Unrecognised X-Client header"),
+        }
+    }
+
+    let has_bearer_token = req
+        .headers()
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("Bearer "));
+    let has_session_cookie = req.cookie("__Host-session").is_some();
+
+    match platform {
+        Platform::Web if has_bearer_token => {
+            warn!("A web login was sent with a bearer token, it will get a cookie it may not use");
         }
-    } else {
+        Platform::Mobile if has_session_cookie => {
+            warn!("A mobile login was sent with a session cookie, it will get a token instead");
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// The logged in user, answering 401 when the request has no valid session. The session is
+/// looked up once per request, so helpers that authenticate again cost no round trip.
+#[cfg(feature = "ssr")]
+pub async fn get_authenticated_user<T>()
+-> Result<(ResponseOptions, Surreal<Client>, User), ApiResponse<T>> {
+    let (response_options, db) = get_server_context::<T>().await?;
+
+    let req = current_request::<T>().await?;
+
+    if session_token_of(&req).is_none() {
         response_options.set_status(StatusCode::UNAUTHORIZED);
-        return Err(ApiResponse::error("You are not logged in".to_string()));
-    };
+        return Err(ApiResponse::error_with_code(
+            ErrorCode::Unauthenticated,
+            "You are not logged in".to_string(),
+        ));
+    }
+
+    let counted_db = CountingDb::from_request(db.clone()).await;
+    match authenticated_user_of(&req, &counted_db).await {
+        Some(user) => Ok((response_options, db, user)),
+        None => {
+            response_options.set_status(StatusCode::UNAUTHORIZED);
+            Err(ApiResponse::error_with_code(
+                ErrorCode::Unauthenticated,
+                "Invalid or expired session".to_string(),
+            ))
+        }
+    }
+}
+
+/// Same as `get_authenticated_user` for endpoints open to everyone, where a logged in user
+/// only gets extra details. Requests without a valid session are served with no user
+/// instead of being turned away.
+#[cfg(feature = "ssr")]
+pub async fn get_optional_authenticated_user<T>()
+-> Result<(ResponseOptions, Surreal<Client>, Option<User>), ApiResponse<T>> {
+    let (response_options, db) = get_server_context::<T>().await?;
+    let req = current_request::<T>().await?;
+
+    let counted_db = CountingDb::from_request(db.clone()).await;
+    let user = authenticated_user_of(&req, &counted_db).await;
+
+    Ok((response_options, db, user))
+}
+
+/// The user authenticated for the current request, kept in the request's context.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone)]
+struct AuthenticatedUser(User);
+
+/// The user whose session `req` carries. The session is only looked up in `db` the first
+/// time in a request, after which the user is kept in the request's context. Requests
+/// without a valid session get `None`, and are looked up again when asked a second time.
+#[cfg(feature = "ssr")]
+pub async fn authenticated_user_of(req: &HttpRequest, db: &CountingDb) -> Option<User> {
+    if let Some(AuthenticatedUser(user)) = use_context::<AuthenticatedUser>() {
+        return Some(user);
+    }
+
+    let (session_token, from_cookie) = session_token_of(req)?;
+    let user = user_of_session(&session_token, from_cookie, db).await?;
+    provide_context(AuthenticatedUser(user.clone()));
+
+    Some(user)
+}
 
-    let user = match get_user_by_session(&session_token, &db).await {
-        Ok(user) => user,
+/// The session token sent with `req` and whether it came in the session cookie rather than
+/// an `Authorization: Bearer` header.
+#[cfg(feature = "ssr")]
+fn session_token_of(req: &HttpRequest) -> Option<(String, bool)> {
+    if let Some(cookie) = req.cookie("__Host-session") {
+        return Some((cookie.value().to_string(), true));
+    }
+
+    let auth_str = req.headers().get("Authorization")?.to_str().unwrap_or("");
+    auth_str
+        .strip_prefix("Bearer ")
+        .map(|session_token| (session_token.to_string(), false))
+}
+
+#[cfg(feature = "ssr")]
+async fn user_of_session(session_token: &str, from_cookie: bool, db: &CountingDb) -> Option<User> {
+    let session = match resolve_session(session_token, db).await {
+        Ok(session) => session,
         Err(e) => {
             error!(?e, "Failed to get user by session");
-            response_options.set_status(StatusCode::UNAUTHORIZED);
-            return Err(ApiResponse::error("Invalid or expired session".to_string()));
+            return None;
         }
     };
 
-    Ok((response_options, db, user))
+    // Web clients need the cookie's Max-Age pushed back along with the session itself
+    if session.refreshed && from_cookie {
+        if let Err(e) = set_session_cookie(session_token) {
+            error!(?e, "Failed to refresh the session cookie");
+        }
+    }
+
+    Some(session.user)
 }
 
+/// `Cache-Control` of public reads that rarely change, such as mosque details and event lists.
+#[cfg(feature = "ssr")]
+pub const PUBLIC_CACHE_CONTROL: &str = "public, max-age=300";
+/// `Cache-Control` of responses that depend on who asked.
+#[cfg(feature = "ssr")]
+pub const PRIVATE_CACHE_CONTROL: &str = "private, no-store";
+/// `Vary` of publicly cached reads. Callers are told apart by their session cookie or bearer
+/// token, so a response cached for an anonymous caller is never handed to a logged in one.
+#[cfg(feature = "ssr")]
+pub const PUBLIC_CACHE_VARY: &str = "Cookie, Authorization";
+
 #[cfg(feature = "ssr")]
 pub struct ServerResponse {
     options: ResponseOptions,
@@ -96,6 +331,25 @@ impl ServerResponse {
         self.options.insert_header(name, value);
     }
 
+    /// Lets browsers and shared caches reuse the response for a few minutes, so popular
+    /// mosques aren't read from the database on every view. Only for successful public reads
+    /// served over GET, since caches don't reuse responses to POST.
+    pub fn cache_publicly(&self) {
+        self.insert_header(
+            CACHE_CONTROL,
+            HeaderValue::from_static(PUBLIC_CACHE_CONTROL),
+        );
+        self.insert_header(VARY, HeaderValue::from_static(PUBLIC_CACHE_VARY));
+    }
+
+    /// Keeps a personalized response out of every cache.
+    pub fn cache_privately(&self) {
+        self.insert_header(
+            CACHE_CONTROL,
+            HeaderValue::from_static(PRIVATE_CACHE_CONTROL),
+        );
+    }
+
     pub fn append_header(
         &self,
         name: actix_web::http::header::HeaderName,
@@ -125,47 +379,70 @@ impl ServerResponse {
     }
 
     pub fn bad_request<T>(&self, error: String) -> ApiResponse<T> {
-        self.options.set_status(StatusCode::BAD_REQUEST);
-        ApiResponse::error(error)
+        self.error(StatusCode::BAD_REQUEST, ErrorCode::BadRequest, error)
     }
 
     pub fn unauthorized<T>(&self, error: String) -> ApiResponse<T> {
-        self.options.set_status(StatusCode::UNAUTHORIZED);
-        ApiResponse::error(error)
+        self.error(StatusCode::UNAUTHORIZED, ErrorCode::Unauthenticated, error)
     }
 
     pub fn forbidden<T>(&self, error: String) -> ApiResponse<T> {
-        self.options.set_status(StatusCode::FORBIDDEN);
-        ApiResponse::error(error)
+        self.error(StatusCode::FORBIDDEN, ErrorCode::Forbidden, error)
     }
 
     pub fn not_found<T>(&self, error: String) -> ApiResponse<T> {
-        self.options.set_status(StatusCode::NOT_FOUND);
-        ApiResponse::error(error)
+        self.error(StatusCode::NOT_FOUND, ErrorCode::NotFound, error)
     }
 
     pub fn method_not_allowed<T>(&self, error: String) -> ApiResponse<T> {
-        self.options.set_status(StatusCode::METHOD_NOT_ALLOWED);
-        ApiResponse::error(error)
+        self.error(
+            StatusCode::METHOD_NOT_ALLOWED,
+            ErrorCode::MethodNotAllowed,
+            error,
+        )
     }
 
     pub fn unprocessable_entity<T>(&self, error: String) -> ApiResponse<T> {
-        self.options.set_status(StatusCode::UNPROCESSABLE_ENTITY);
-        ApiResponse::error(error)
+        self.error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ErrorCode::ValidationFailed,
+            error,
+        )
     }
 
     pub fn internal_server_error<T>(&self, error: String) -> ApiResponse<T> {
-        self.options.set_status(StatusCode::INTERNAL_SERVER_ERROR);
-        ApiResponse::error(error)
+        self.error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ErrorCode::Internal,
+            error,
+        )
     }
 
     pub fn conflict<T>(&self, error: String) -> ApiResponse<T> {
-        self.options.set_status(StatusCode::CONFLICT);
-        ApiResponse::error(error)
+        self.error(StatusCode::CONFLICT, ErrorCode::Conflict, error)
+    }
+
+    pub fn too_many_requests<T>(&self, error: String) -> ApiResponse<T> {
+        self.error(StatusCode::TOO_MANY_REQUESTS, ErrorCode::RateLimited, error)
+    }
+
+    /// Answers a client that hit a rate limit, with a `Retry-After` of the whole seconds
+    /// until it may try again.
+    pub fn rate_limited<T>(&self, retry_after: Duration, error: String) -> ApiResponse<T> {
+        self.insert_header(RETRY_AFTER, HeaderValue::from(retry_after.as_secs().max(1)));
+        self.too_many_requests(error)
     }
 
     pub fn service_unavailable<T>(&self, error: String) -> ApiResponse<T> {
-        self.options.set_status(StatusCode::SERVICE_UNAVAILABLE);
-        ApiResponse::error(error)
+        self.error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            ErrorCode::Unavailable,
+            error,
+        )
+    }
+
+    fn error<T>(&self, status: StatusCode, code: ErrorCode, error: String) -> ApiResponse<T> {
+        self.options.set_status(status);
+        ApiResponse::error_with_code(code, error)
     }
 }
@@ -3,15 +3,26 @@ use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
 use crate::{
     errors::user_elevation::UserElevationError,
     models::mosque::MosqueRecord,
-    models::user::{UpdateUser, User},
+    models::user::{Role, UpdateUser, User},
 };
 
+/// Gives a user a higher mosque role. Only `mosque_supervisor` can be handed out this way, and
+/// never to someone who already holds that role or a higher one. Users holding an education
+/// role keep it, since it sits outside the mosque roles.
 pub async fn elevate_user(
     app_admin: RecordId,
     user_being_elevated_id: RecordId,
-    elevation_degree: String,
+    elevation_degree: Role,
     db: &Surreal<Client>,
 ) -> Result<String, UserElevationError> {
+    if app_admin == user_being_elevated_id {
+        return Err(UserElevationError::SelfElevationNotAllowed);
+    }
+
+    if !elevation_degree.is_elevation_target() {
+        return Err(UserElevationError::InvalidTargetRole(elevation_degree));
+    }
+
     let admin_check: Option<User> = db
         .select(app_admin)
         .await
@@ -37,20 +48,71 @@ pub async fn elevate_user(
         None => return Err(UserElevationError::TargetUserNotFound),
     };
 
-    if user_being_elevated.is_mosque_supervisor() {
-        Err(UserElevationError::AlreadyElevated(
-            "mosque supervisor".to_string(),
-        ))?
+    match user_being_elevated.parsed_role() {
+        Some(current_role) if current_role >= elevation_degree => {
+            Err(UserElevationError::AlreadyElevated(current_role.label()))?
+        }
+        Some(_) => {}
+        None => Err(UserElevationError::UnmanagedRole(
+            user_being_elevated.role.replace('_', " "),
+        ))?,
     }
 
-    user_being_elevated.elevate_to(elevation_degree.clone());
+    user_being_elevated.elevate_to(elevation_degree);
 
     db.update::<Option<User>>(user_being_elevated.id.clone()) // Clone ID so struct isn't partially moved
         .merge::<UpdateUser>(user_being_elevated.into()) // Move the struct
         .await
         .map_err(UserElevationError::DatabaseError)?;
 
-    Ok(format!("Elevated the user to {elevation_degree}"))
+    Ok(format!(
+        "Elevated the user to {}",
+        elevation_degree.as_str()
+    ))
+}
+
+/// Sets a user's role back to `regular`, and with `remove_admin_rights` also removes every
+/// mosque they administer. Only app admins can demote, and app admins themselves can't be
+/// demoted this way, which also keeps an app admin from demoting themselves.
+pub async fn demote_to_regular(
+    app_admin: RecordId,
+    user_being_demoted_id: RecordId,
+    remove_admin_rights: bool,
+    db: &Surreal<Client>,
+) -> Result<String, UserElevationError> {
+    let admin_check: Option<User> = db.select(app_admin).await?;
+    match admin_check {
+        Some(admin) if admin.is_app_admin() => {}
+        Some(_) => return Err(UserElevationError::Unauthorized),
+        None => return Err(UserElevationError::AdminNotFound),
+    }
+
+    let user_being_demoted: Option<User> = db.select(user_being_demoted_id).await?;
+    let mut user_being_demoted = match user_being_demoted {
+        Some(user) if user.is_app_admin() => return Err(UserElevationError::Unauthorized),
+        Some(user) => user,
+        None => return Err(UserElevationError::TargetUserNotFound),
+    };
+
+    user_being_demoted.elevate_to(Role::Regular);
+
+    db.query(
+        r#"
+            BEGIN TRANSACTION;
+            UPDATE $user MERGE $update;
+            IF $remove_admin_rights {
+                DELETE handles WHERE in = $user;
+            };
+            COMMIT TRANSACTION;
+        "#,
+    )
+    .bind(("user", user_being_demoted.id.clone()))
+    .bind(("update", UpdateUser::from(user_being_demoted)))
+    .bind(("remove_admin_rights", remove_admin_rights))
+    .await?
+    .check()?;
+
+    Ok("Demoted the user to regular".to_string())
 }
 
 pub async fn is_mosque_admin(
@@ -0,0 +1,7 @@
+mod common;
+#[path = "benches/mosque_search.rs"]
+mod mosque_search;
+#[path = "benches/password_hashing.rs"]
+mod password_hashing;
+#[path = "benches/timing.rs"]
+mod timing;
@@ -0,0 +1,81 @@
+use crate::{common::get_test_db, timing::Samples};
+use merzah::{
+    models::{
+        api_responses::{ApiResponse, MosqueResponse},
+        mosque::MosqueRecord,
+    },
+    spawn_app,
+};
+use reqwest::Client;
+use serde::Serialize;
+use surrealdb::sql::Geometry;
+
+/// Mosques seeded on a grid around the search point, spaced roughly 400m apart.
+const GRID_SIDE: usize = 40;
+const RUNS: usize = 20;
+
+#[derive(Serialize)]
+struct CreateMosque {
+    location: Geometry,
+    name: String,
+}
+
+#[derive(Serialize)]
+struct FetchMosqueWithRadiusParams {
+    lat: f64,
+    lon: f64,
+    radius_meters: Option<u32>,
+}
+
+#[tokio::test]
+async fn bench_fetch_mosques_for_location() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let mosques: Vec<CreateMosque> = (0..GRID_SIDE * GRID_SIDE)
+        .map(|i| CreateMosque {
+            location: Geometry::Point(
+                (
+                    77.10 + (i % GRID_SIDE) as f64 * 0.004,
+                    28.55 + (i / GRID_SIDE) as f64 * 0.004,
+                )
+                    .into(),
+            ),
+            name: format!("Bench Mosque {i}"),
+        })
+        .collect();
+    let _: Vec<MosqueRecord> = db
+        .insert("mosques")
+        .content(mosques)
+        .await
+        .expect("Failed to seed the mosques");
+
+    let params = FetchMosqueWithRadiusParams {
+        lat: 28.63,
+        lon: 77.18,
+        radius_meters: None,
+    };
+    let (client, addr, params) = (&client, addr.as_str(), &params);
+    let mut samples = Samples::new("fetch_mosques_for_location");
+    samples
+        .measure_async(RUNS, || async move {
+            let response = client
+                .get(format!("{}/mosques/fetch-mosques-for-location", addr))
+                .query(params)
+                .send()
+                .await
+                .expect("Failed to fetch mosques");
+            assert_eq!(response.status(), 200);
+            let mosques = response
+                .json::<ApiResponse<Vec<MosqueResponse>>>()
+                .await
+                .expect("Failed to deserialize")
+                .data
+                .expect("No mosques data");
+            assert!(!mosques.is_empty());
+        })
+        .await;
+
+    samples.assert_within_budget("BENCH_FETCH_MOSQUES_BUDGET_MS", 250);
+}
@@ -0,0 +1,29 @@
+use crate::timing::Samples;
+use merzah::auth::password::{argon2_from_env, hash_password, hash_password_with};
+
+const RUNS: usize = 10;
+
+/// The hashing `register_user` and `change_password` do, with the `ARGON2_*` settings of the
+/// environment the benchmark runs in.
+#[test]
+fn bench_hash_password() {
+    let mut samples = Samples::new("hash_password");
+    samples.measure(RUNS, || {
+        hash_password("thisisasecret").expect("Failed to hash the password");
+    });
+
+    samples.assert_within_budget("BENCH_HASH_PASSWORD_BUDGET_MS", 500);
+}
+
+/// Building the hasher reads the environment on every call, which should stay negligible
+/// next to the hashing itself.
+#[test]
+fn bench_hash_password_with_a_prebuilt_hasher() {
+    let argon2 = argon2_from_env();
+    let mut samples = Samples::new("hash_password_with");
+    samples.measure(RUNS, || {
+        hash_password_with(&argon2, "thisisasecret").expect("Failed to hash the password");
+    });
+
+    samples.assert_within_budget("BENCH_HASH_PASSWORD_BUDGET_MS", 500);
+}
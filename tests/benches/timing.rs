@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+/// Timings of repeated runs of one operation.
+pub struct Samples {
+    name: &'static str,
+    durations: Vec<Duration>,
+}
+
+impl Samples {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            durations: Vec::new(),
+        }
+    }
+
+    /// Runs `operation` `runs` times, recording how long each run took.
+    pub fn measure(&mut self, runs: usize, mut operation: impl FnMut()) {
+        for _ in 0..runs {
+            let start = Instant::now();
+            operation();
+            self.durations.push(start.elapsed());
+        }
+    }
+
+    pub async fn measure_async<F, Fut>(&mut self, runs: usize, mut operation: F)
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        for _ in 0..runs {
+            let start = Instant::now();
+            operation().await;
+            self.durations.push(start.elapsed());
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut durations = self.durations.clone();
+        durations.sort();
+        durations[durations.len() / 2]
+    }
+
+    /// Prints the timings and fails when the median is over the budget, which is read from
+    /// `budget_env` in milliseconds so slower machines can raise it.
+    pub fn assert_within_budget(&self, budget_env: &str, default_budget_ms: u64) {
+        let budget_ms = std::env::var(budget_env)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default_budget_ms);
+        let median = self.median();
+        let max = self.durations.iter().max().copied().unwrap_or_default();
+
+        println!(
+            "{}: {} runs, median {:?}, max {:?}, budget {}ms",
+            self.name,
+            self.durations.len(),
+            median,
+            max,
+            budget_ms
+        );
+        assert!(
+            median <= Duration::from_millis(budget_ms),
+            "{} took a median of {:?}, over the {}ms budget set by {}",
+            self.name,
+            median,
+            budget_ms,
+            budget_env
+        );
+    }
+}
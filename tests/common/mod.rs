@@ -1,10 +1,21 @@
 #[cfg(feature = "ssr")]
+use std::sync::Mutex;
+
+#[cfg(feature = "ssr")]
+use futures::future::BoxFuture;
+#[cfg(feature = "ssr")]
+use merzah::{errors::notifier::NotifierError, services::notifier::Notifier};
+#[cfg(feature = "ssr")]
+use serde::Serialize;
+#[cfg(feature = "ssr")]
 use surrealdb::Surreal;
 #[cfg(feature = "ssr")]
 use surrealdb::engine::remote::ws::{Client, Ws};
 #[cfg(feature = "ssr")]
 use surrealdb::opt::auth::Root;
 #[cfg(feature = "ssr")]
+use surrealdb::{Datetime, RecordId};
+#[cfg(feature = "ssr")]
 use testcontainers::runners::AsyncRunner;
 #[cfg(feature = "ssr")]
 use testcontainers_modules::surrealdb::SurrealDb;
@@ -13,6 +24,90 @@ use tokio::sync::OnceCell;
 #[cfg(feature = "ssr")]
 use uuid::Uuid;
 
+/// Mirrors `User` for seeding rows directly, since `User` never serializes its password hash.
+#[cfg(feature = "ssr")]
+#[allow(dead_code)]
+#[derive(Serialize)]
+pub struct SeedUser {
+    pub id: RecordId,
+    pub created_at: Datetime,
+    pub display_name: String,
+    pub password_hash: String,
+    pub role: String,
+    pub updated_at: Datetime,
+    pub verified: bool,
+}
+
+/// Keeps every message instead of sending it, so tests can read back codes and links.
+#[cfg(feature = "ssr")]
+#[allow(dead_code)]
+#[derive(Default)]
+pub struct RecordingNotifier {
+    /// `(to, message)` of every SMS.
+    pub sms: Mutex<Vec<(String, String)>>,
+    /// `(to, body)` of every email.
+    pub emails: Mutex<Vec<(String, String)>>,
+}
+
+#[cfg(feature = "ssr")]
+#[allow(dead_code)]
+impl RecordingNotifier {
+    /// The one-time code of the last SMS, which always leads the message.
+    pub fn last_sms_code(&self) -> String {
+        let sms = self.sms.lock().unwrap();
+        let (_, message) = sms.last().expect("No SMS was sent");
+        message
+            .split_whitespace()
+            .next()
+            .expect("The SMS has no code")
+            .to_string()
+    }
+
+    /// The `token` query parameter of the link in the last email.
+    pub fn last_email_token(&self) -> String {
+        let emails = self.emails.lock().unwrap();
+        let (_, body) = emails.last().expect("No email was sent");
+        let (_, token) = body.split_once("?token=").expect("The email has no link");
+        token
+            .split_whitespace()
+            .next()
+            .expect("The link has no token")
+            .to_string()
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl Notifier for RecordingNotifier {
+    fn send_sms<'a>(
+        &'a self,
+        to: &'a str,
+        message: &'a str,
+    ) -> BoxFuture<'a, Result<(), NotifierError>> {
+        Box::pin(async move {
+            self.sms
+                .lock()
+                .unwrap()
+                .push((to.to_string(), message.to_string()));
+            Ok(())
+        })
+    }
+
+    fn send_email<'a>(
+        &'a self,
+        to: &'a str,
+        _subject: &'a str,
+        body: &'a str,
+    ) -> BoxFuture<'a, Result<(), NotifierError>> {
+        Box::pin(async move {
+            self.emails
+                .lock()
+                .unwrap()
+                .push((to.to_string(), body.to_string()));
+            Ok(())
+        })
+    }
+}
+
 #[cfg(feature = "ssr")]
 static DB_CONTAINER: OnceCell<testcontainers::ContainerAsync<SurrealDb>> = OnceCell::const_new();
 
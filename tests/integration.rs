@@ -3,9 +3,36 @@ mod auth;
 mod common;
 #[path = "integration/education.rs"]
 mod education;
+#[path = "integration/event_announcements.rs"]
+mod event_announcements;
+#[path = "integration/event_feed.rs"]
+mod event_feed;
 #[path = "integration/events.rs"]
 mod events;
+#[path = "integration/health.rs"]
+mod health;
+#[path = "integration/magic_link.rs"]
+mod magic_link;
+#[path = "integration/mobile_otp.rs"]
+mod mobile_otp;
 #[path = "integration/mosque.rs"]
 mod mosque;
 #[path = "integration/mosque_admin.rs"]
 mod mosque_admin;
+#[path = "integration/mosque_cache.rs"]
+mod mosque_cache;
+#[cfg(feature = "multi_tenant")]
+#[path = "integration/multi_tenant.rs"]
+mod multi_tenant;
+#[path = "integration/password_reset.rs"]
+mod password_reset;
+#[path = "integration/query_count.rs"]
+mod query_count;
+#[path = "integration/request_id.rs"]
+mod request_id;
+#[path = "integration/token_cleanup.rs"]
+mod token_cleanup;
+#[path = "integration/verification.rs"]
+mod verification;
+#[path = "integration/waitlist.rs"]
+mod waitlist;
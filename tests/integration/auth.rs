@@ -1,15 +1,29 @@
 use crate::common::get_test_db;
+use chrono::{DateTime, Duration, Utc};
 use merzah::{
+    auth::{
+        custom_auth::{authenticate, register_user},
+        oauth::{
+            google::GoogleProvider,
+            helpers::OAuthCallback,
+            provider::{OAuthProvider, ProviderUser},
+            workos::WorkosProvider,
+        },
+        session::create_session,
+    },
+    messages,
     models::{
-        api_responses::ApiResponse,
+        api_responses::{ApiResponse, CurrentUserResponse, ErrorCode},
         auth::{LoginFormData, Platform, RegistrationFormData},
-        user::Identifier,
+        user::{Identifier, User, UserIdentifier, UserIdentifierOnClient, UserOnClient},
     },
+    services::user_verification::backfill_verified_users,
     spawn_app,
 };
 use reqwest::Client;
 use rstest::rstest;
 use serde::Serialize;
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client as SurrealClient};
 
 #[derive(Serialize)]
 pub struct RegisterationFormWrapper {
@@ -21,9 +35,45 @@ struct LoginFormWrapper {
     form: LoginFormData,
 }
 
+#[derive(Serialize)]
+struct NoArgs {}
+
+#[derive(Serialize)]
+struct ChangePasswordPayload {
+    current_password: String,
+    new_password: String,
+}
+
+#[derive(Serialize)]
+struct DeleteAccountPayload {
+    password: String,
+}
+
+#[derive(Serialize)]
+struct UpdateProfilePayload {
+    display_name: Option<String>,
+}
+
+#[derive(Serialize)]
+struct AddIdentifierPayload {
+    identifier: Identifier,
+}
+
+#[derive(Serialize)]
+struct RemoveIdentifierQuery {
+    identifier_value: String,
+}
+
+#[derive(Serialize)]
+struct OAuthCallbackPayload {
+    code: String,
+    state: String,
+    platform: Platform,
+}
+
 #[rstest]
-#[case::mobile("Armaan Ali".to_string(), Identifier::Mobile("+91 1234567890".to_string()), "thisisasecret".to_string(), Some("The user has been registered successfully".to_string()), "Payload with Identifier Type mobile")]
-#[case::email("Armaan Ali".to_string(), Identifier::Email("armaanali@gmail.com".to_string()), "thisisasecret".to_string(), Some("The user has been registered successfully".to_string()), "Payload with Identifier Type email")]
+#[case::mobile("Armaan Ali".to_string(), Identifier::Mobile("+91 1234567890".to_string()), "thisisasecret".to_string(), Some(messages::USER_REGISTERED.to_string()), "Payload with Identifier Type mobile")]
+#[case::email("Armaan Ali".to_string(), Identifier::Email("armaanali@gmail.com".to_string()), "thisisasecret".to_string(), Some(messages::USER_REGISTERED.to_string()), "Payload with Identifier Type email")]
 #[tokio::test]
 async fn register_server_fn_successfully_register_a_user(
     #[case] name: String,
@@ -74,7 +124,7 @@ async fn register_server_fn_successfully_register_a_user(
     assert!(api_response.error.is_none());
 
     // Verify DB State
-    let (id_type, id_value) = match identifier {
+    let (id_type, id_value) = match identifier.normalized() {
         Identifier::Email(e) => ("email", e),
         Identifier::Mobile(m) => ("mobile", m),
         _ => panic!(),
@@ -186,7 +236,7 @@ async fn logout_server_fn_successfully_logs_out_user() {
 
     assert_eq!(
         api_response.data,
-        Some("Successfully logged out the user".to_string())
+        Some(messages::USER_LOGGED_OUT.to_string())
     );
     assert!(api_response.error.is_none());
 
@@ -276,7 +326,7 @@ async fn login_server_fn_successfully_logs_in_user() {
 
     assert_eq!(
         api_response.data,
-        Some("Successfully logged out the user".to_string())
+        Some(messages::USER_LOGGED_OUT.to_string())
     );
     assert!(api_response.error.is_none());
 
@@ -309,7 +359,7 @@ async fn login_server_fn_successfully_logs_in_user() {
 
     assert_eq!(
         api_response.data,
-        Some("The user has been logged in successfully".to_string())
+        Some(messages::USER_LOGGED_IN.to_string())
     );
     assert!(api_response.error.is_none());
 
@@ -544,7 +594,7 @@ async fn test_authenticated_user_can_logout_with_any_method(#[case] auth_method:
 
     assert_eq!(
         api_response.data,
-        Some("Successfully logged out the user".to_string())
+        Some(messages::USER_LOGGED_OUT.to_string())
     );
     assert!(api_response.error.is_none());
 }
@@ -588,18 +638,17 @@ async fn test_unauthenticated_request_returns_401(
 
     let status = response.status().as_u16();
 
-    let error = response
+    let api_response = response
         .json::<ApiResponse<String>>()
         .await
-        .unwrap_or(ApiResponse::error("you are not logged in".to_string()))
-        .error
-        .unwrap_or_default();
+        .expect("Failed to deserialize response");
 
     assert_eq!(
         status, 401,
-        "Unauthenticated {:?} request should return 401, error: {error}",
-        auth_method,
+        "Unauthenticated {:?} request should return 401, error: {:?}",
+        auth_method, api_response.error,
     );
+    assert_eq!(api_response.code, Some(ErrorCode::Unauthenticated));
 }
 
 #[rstest]
@@ -658,3 +707,993 @@ async fn test_auth_flow_registration_returns_correct_response_for_platform(
         }
     }
 }
+
+#[tokio::test]
+async fn register_is_throttled_per_ip() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let register_url = format!("{}/auth/register", addr);
+
+    let mut statuses = Vec::new();
+    for attempt in 0..6 {
+        let body = RegisterationFormWrapper {
+            form: RegistrationFormData::new(
+                "Throttled User".to_string(),
+                Identifier::Email(format!(
+                    "throttled_{}_{}@example.com",
+                    attempt,
+                    uuid::Uuid::new_v4()
+                )),
+                "thisisasecret".to_string(),
+                Platform::Mobile,
+            ),
+        };
+
+        let response = client
+            .post(&register_url)
+            .json(&body)
+            .send()
+            .await
+            .expect("Failed to send a request");
+        statuses.push(response.status().as_u16());
+    }
+
+    assert_eq!(statuses, vec![200, 200, 200, 200, 200, 429]);
+}
+
+#[tokio::test]
+async fn login_is_throttled_per_account() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let login_url = format!("{}/auth/login", addr);
+
+    let email = format!("login_throttled_{}@example.com", uuid::Uuid::new_v4());
+    register_user(
+        RegistrationFormData::new_web(
+            "Throttled User".to_string(),
+            Identifier::Email(email.clone()),
+            "thisisasecret".to_string(),
+        ),
+        &db,
+    )
+    .await
+    .expect("Failed to register user");
+
+    let mut statuses = Vec::new();
+    for _ in 0..6 {
+        let body = LoginFormWrapper {
+            form: LoginFormData {
+                identifier: Identifier::Email(email.clone()),
+                password: "notthesecret".to_string(),
+                platform: Platform::Mobile,
+            },
+        };
+
+        let response = client
+            .post(&login_url)
+            .json(&body)
+            .send()
+            .await
+            .expect("Failed to send a request");
+        statuses.push(response.status().as_u16());
+    }
+
+    assert_eq!(statuses, vec![401, 401, 401, 401, 401, 429]);
+
+    // Other accounts logging in from the same IP are not held back
+    let other_account = LoginFormWrapper {
+        form: LoginFormData {
+            identifier: Identifier::Email(format!(
+                "login_neighbour_{}@example.com",
+                uuid::Uuid::new_v4()
+            )),
+            password: "notthesecret".to_string(),
+            platform: Platform::Mobile,
+        },
+    };
+    let response = client
+        .post(&login_url)
+        .json(&other_account)
+        .send()
+        .await
+        .expect("Failed to send a request");
+    assert_eq!(response.status().as_u16(), 401);
+}
+
+#[rstest]
+#[case::web(AuthMethod::Web)]
+#[case::mobile(AuthMethod::Mobile)]
+#[tokio::test]
+async fn test_session_near_expiry_is_extended_on_use(#[case] auth_method: AuthMethod) {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+
+    let platform = match auth_method {
+        AuthMethod::Web => Platform::Web,
+        AuthMethod::Mobile => Platform::Mobile,
+    };
+    let body = RegisterationFormWrapper {
+        form: RegistrationFormData::new(
+            "Sliding Session User".to_string(),
+            Identifier::Email(format!("sliding_{}@example.com", uuid::Uuid::new_v4())),
+            "password123".to_string(),
+            platform,
+        ),
+    };
+    let register_response = client
+        .post(format!("{}/auth/register", addr))
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to register");
+    assert!(register_response.status().is_success());
+
+    let session = extract_session(register_response, auth_method).await;
+    let session_token = match auth_method {
+        AuthMethod::Web => session
+            .split_once('=')
+            .map(|(_, token)| token.to_string())
+            .expect("Malformed session cookie"),
+        AuthMethod::Mobile => session.clone(),
+    };
+
+    db.query("UPDATE sessions SET expires_at = time::now() + 5m WHERE session_token = $token")
+        .bind(("token", session_token.clone()))
+        .await
+        .expect("Failed to move the session close to expiry");
+
+    let mut me_request = client.post(format!("{}/auth/me", addr)).json(&NoArgs {});
+    if let Some((name, value)) = get_auth_header(&session, auth_method) {
+        me_request = me_request.header(name, value);
+    } else {
+        me_request = me_request.header("Cookie", session.clone());
+    }
+    let me_response = me_request.send().await.expect("Failed to call me");
+    assert_eq!(me_response.status(), 200);
+
+    let refreshed_cookie = me_response.headers().get("set-cookie");
+    match auth_method {
+        AuthMethod::Web => {
+            let cookie = refreshed_cookie
+                .expect("Web clients should get a refreshed cookie")
+                .to_str()
+                .expect("Failed to convert cookie to string");
+            assert!(cookie.starts_with(&session));
+            assert!(cookie.contains("Max-Age=3600"));
+        }
+        AuthMethod::Mobile => assert!(refreshed_cookie.is_none()),
+    }
+
+    let stored: Option<merzah::models::session::Session> = db
+        .query("SELECT * FROM sessions WHERE session_token = $token")
+        .bind(("token", session_token))
+        .await
+        .expect("Failed to query sessions")
+        .take(0)
+        .expect("Failed to parse session");
+    let expires_at: DateTime<Utc> = stored.expect("Session missing").expires_at.into();
+    assert!(expires_at > Utc::now() + Duration::minutes(50));
+}
+
+#[rstest]
+#[case::disposable_domain("mailinator.com", 422)]
+#[case::invalid_domain("localhost", 422)]
+#[case::regular_domain("example.com", 200)]
+#[tokio::test]
+async fn register_checks_the_email_domain(#[case] domain: &str, #[case] expected_status: u16) {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+
+    let body = RegisterationFormWrapper {
+        form: RegistrationFormData::new(
+            "Domain Check User".to_string(),
+            Identifier::Email(format!("domain_{}@{}", uuid::Uuid::new_v4(), domain)),
+            "thisisasecret".to_string(),
+            Platform::Mobile,
+        ),
+    };
+
+    let response = client
+        .post(format!("{}/auth/register", addr))
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), expected_status);
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert_eq!(api_response.error.is_some(), expected_status != 200);
+}
+
+#[tokio::test]
+async fn google_callback_rejects_a_state_not_matching_the_cookie() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+
+    let response = client
+        .post(format!("{}/auth/google-callback", addr))
+        .header("Cookie", "__Host-google_oauth_state=expected-state")
+        .json(&OAuthCallbackPayload {
+            code: "authorization-code".to_string(),
+            state: "forged-state".to_string(),
+            platform: Platform::Web,
+        })
+        .send()
+        .await
+        .expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), 400);
+    assert!(response.headers().get("set-cookie").is_none());
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert_eq!(
+        api_response.error.as_deref(),
+        Some("Invalid authentication state")
+    );
+}
+
+#[rstest]
+#[case::google("google-callback", "__Host-google_oauth_state")]
+#[case::discord("discord-callback", "discord_oauth_state")]
+#[case::microsoft("microsoft-callback", "microsoft_oauth_state")]
+#[case::github("github-callback", "github_oauth_state")]
+#[case::workos("workos-callback", "workos_oauth_state")]
+#[tokio::test]
+async fn mobile_oauth_callbacks_never_set_cookies(
+    #[case] endpoint: &str,
+    #[case] state_cookie: &str,
+) {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let callback_url = format!("{}/auth/{}", addr, endpoint);
+    let payload = OAuthCallbackPayload {
+        code: "authorization-code".to_string(),
+        state: "forged-state".to_string(),
+        platform: Platform::Mobile,
+    };
+
+    let response = client
+        .post(&callback_url)
+        .header("X-Client", "mobile")
+        .header("Cookie", format!("{}=expected-state", state_cookie))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to send a request");
+    assert_eq!(response.status().as_u16(), 400);
+    assert!(response.headers().get("set-cookie").is_none());
+
+    let response = client
+        .post(&callback_url)
+        .header("X-Client", "web")
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to send a request");
+    assert_eq!(response.status().as_u16(), 400);
+    assert!(response.headers().get("set-cookie").is_none());
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response");
+    let error = api_response.error.expect("The callback should fail");
+    assert!(
+        error.contains("X-Client"),
+        "A platform contradicting the client header should be rejected first, got: {error}"
+    );
+}
+
+#[rstest]
+#[case::contradicting_header(Platform::Web, "mobile", 400)]
+#[case::matching_header(Platform::Mobile, "mobile", 200)]
+#[case::unknown_header(Platform::Web, "desktop", 200)]
+#[tokio::test]
+async fn register_rejects_a_platform_contradicting_the_client_header(
+    #[case] platform: Platform,
+    #[case] client_header: &str,
+    #[case] expected_status: u16,
+) {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+
+    let body = RegisterationFormWrapper {
+        form: RegistrationFormData::new(
+            "Platform Check User".to_string(),
+            Identifier::Email(format!("platform_{}@example.com", uuid::Uuid::new_v4())),
+            "thisisasecret".to_string(),
+            platform,
+        ),
+    };
+
+    let response = client
+        .post(format!("{}/auth/register", addr))
+        .header("X-Client", client_header)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), expected_status);
+    if expected_status == 400 {
+        assert!(response.headers().get("set-cookie").is_none());
+        let api_response = response
+            .json::<ApiResponse<String>>()
+            .await
+            .expect("Failed to deserialize response");
+        assert!(api_response.error.is_some());
+    }
+}
+
+/// Registers an email user with two sessions, as if logged in on two devices.
+async fn user_with_two_sessions(db: &Surreal<SurrealClient>) -> (Identifier, String, String) {
+    let identifier = Identifier::Email(format!("password_{}@example.com", uuid::Uuid::new_v4()));
+    let form = RegistrationFormData::new(
+        "Password Change User".to_string(),
+        identifier.clone(),
+        "thisisasecret".to_string(),
+        Platform::Mobile,
+    );
+    let user = register_user(form, db)
+        .await
+        .expect("Failed to register user");
+    let current_session = create_session(user.clone(), db)
+        .await
+        .expect("Failed to create a session");
+    let other_session = create_session(user, db)
+        .await
+        .expect("Failed to create a session");
+    (identifier, current_session, other_session)
+}
+
+async fn session_exists(session_token: &str, db: &Surreal<SurrealClient>) -> bool {
+    let sessions: Vec<merzah::models::session::Session> = db
+        .query("SELECT * FROM sessions WHERE session_token = $t")
+        .bind(("t", session_token.to_string()))
+        .await
+        .expect("Failed to query sessions")
+        .take(0)
+        .expect("Failed to parse sessions");
+    !sessions.is_empty()
+}
+
+async fn can_log_in(identifier: &Identifier, password: &str, db: &Surreal<SurrealClient>) -> bool {
+    let form = LoginFormData {
+        identifier: identifier.clone(),
+        password: password.to_string(),
+        platform: Platform::Mobile,
+    };
+    authenticate(form, db).await.is_ok()
+}
+
+#[tokio::test]
+async fn change_password_updates_the_password_and_ends_other_sessions() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let (identifier, current_session, other_session) = user_with_two_sessions(&db).await;
+
+    let response = client
+        .post(format!("{}/auth/change-password", addr))
+        .header("Authorization", format!("Bearer {}", current_session))
+        .json(&ChangePasswordPayload {
+            current_password: "thisisasecret".to_string(),
+            new_password: "anewsecretpassword".to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), 200);
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert_eq!(
+        api_response.data,
+        Some(messages::PASSWORD_CHANGED.to_string())
+    );
+
+    assert!(can_log_in(&identifier, "anewsecretpassword", &db).await);
+    assert!(!can_log_in(&identifier, "thisisasecret", &db).await);
+    assert!(session_exists(&current_session, &db).await);
+    assert!(!session_exists(&other_session, &db).await);
+}
+
+#[rstest]
+#[case::wrong_current_password("notthesecret", "anewsecretpassword", 401)]
+#[case::too_short_new_password("thisisasecret", "short", 422)]
+#[tokio::test]
+async fn change_password_rejects_invalid_requests(
+    #[case] current_password: &str,
+    #[case] new_password: &str,
+    #[case] expected_status: u16,
+) {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let (identifier, current_session, other_session) = user_with_two_sessions(&db).await;
+
+    let response = client
+        .post(format!("{}/auth/change-password", addr))
+        .header("Authorization", format!("Bearer {}", current_session))
+        .json(&ChangePasswordPayload {
+            current_password: current_password.to_string(),
+            new_password: new_password.to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), expected_status);
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert!(api_response.error.is_some());
+
+    assert!(can_log_in(&identifier, "thisisasecret", &db).await);
+    assert!(session_exists(&other_session, &db).await);
+}
+
+async fn user_of_identifier(identifier: &Identifier, db: &Surreal<SurrealClient>) -> RecordId {
+    let identifier_value = match identifier.clone().normalized() {
+        Identifier::Email(value) | Identifier::Mobile(value) => value,
+        _ => unreachable!("The test users use an email or a mobile"),
+    };
+    let user: Option<RecordId> = db
+        .query("SELECT VALUE user FROM user_identifier WHERE identifier_value = $value")
+        .bind(("value", identifier_value))
+        .await
+        .expect("Failed to query the identifier")
+        .take(0)
+        .expect("Failed to read the identifier");
+    user.expect("The identifier has no user")
+}
+
+async fn rows_of_user(table: &str, user: &RecordId, db: &Surreal<SurrealClient>) -> usize {
+    let rows: Vec<RecordId> = db
+        .query("SELECT VALUE id FROM type::table($table) WHERE user = $user")
+        .bind(("table", table.to_string()))
+        .bind(("user", user.clone()))
+        .await
+        .expect("Failed to query the table")
+        .take(0)
+        .expect("Failed to read the rows");
+    rows.len()
+}
+
+#[tokio::test]
+async fn delete_account_removes_the_user_and_their_records() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let (identifier, current_session, _other_session) = user_with_two_sessions(&db).await;
+    let user = user_of_identifier(&identifier, &db).await;
+
+    let response = client
+        .post(format!("{}/auth/delete-account", addr))
+        .header("Authorization", format!("Bearer {}", current_session))
+        .json(&DeleteAccountPayload {
+            password: "thisisasecret".to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), 200);
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert_eq!(
+        api_response.data,
+        Some(messages::ACCOUNT_DELETED.to_string())
+    );
+
+    let deleted_user: Option<User> = db.select(user.clone()).await.expect("Failed to query");
+    assert!(deleted_user.is_none());
+    assert_eq!(rows_of_user("user_identifier", &user, &db).await, 0);
+    assert_eq!(rows_of_user("sessions", &user, &db).await, 0);
+    assert!(!can_log_in(&identifier, "thisisasecret", &db).await);
+}
+
+#[tokio::test]
+async fn delete_account_requires_the_password() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let (identifier, current_session, _other_session) = user_with_two_sessions(&db).await;
+    let user = user_of_identifier(&identifier, &db).await;
+
+    let response = client
+        .post(format!("{}/auth/delete-account", addr))
+        .header("Authorization", format!("Bearer {}", current_session))
+        .json(&DeleteAccountPayload {
+            password: "notthesecret".to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), 401);
+    let remaining_user: Option<User> = db.select(user.clone()).await.expect("Failed to query");
+    assert!(remaining_user.is_some());
+    assert_eq!(rows_of_user("user_identifier", &user, &db).await, 1);
+    assert_eq!(rows_of_user("sessions", &user, &db).await, 2);
+}
+
+#[tokio::test]
+async fn get_current_user_returns_the_logged_in_profile() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let email = format!("current_{}@example.com", uuid::Uuid::new_v4());
+    let form = RegistrationFormData::new(
+        "Current User".to_string(),
+        Identifier::Email(email.clone()),
+        "thisisasecret".to_string(),
+        Platform::Mobile,
+    );
+    register_user(form, &db)
+        .await
+        .expect("Failed to register user");
+
+    let response = client
+        .post(format!("{}/auth/login", addr))
+        .json(&LoginFormWrapper {
+            form: LoginFormData {
+                identifier: Identifier::Email(email.clone()),
+                password: "thisisasecret".to_string(),
+                platform: Platform::Mobile,
+            },
+        })
+        .send()
+        .await
+        .expect("Failed to log in");
+    assert!(response.status().is_success());
+    let session_token = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response")
+        .data
+        .expect("Mobile login should return a session token");
+
+    let response = client
+        .post(format!("{}/auth/current-user", addr))
+        .header("Authorization", format!("Bearer {}", session_token))
+        .json(&NoArgs {})
+        .send()
+        .await
+        .expect("Failed to fetch the current user");
+
+    assert_eq!(response.status().as_u16(), 200);
+    let current_user = response
+        .json::<ApiResponse<CurrentUserResponse>>()
+        .await
+        .expect("Failed to deserialize response")
+        .data
+        .expect("No user returned");
+    assert_eq!(current_user.user.display_name, "Current User");
+    assert_eq!(current_user.user.role, "regular");
+    assert_eq!(
+        current_user.identifiers,
+        vec![UserIdentifierOnClient::new("email".to_string(), email)]
+    );
+}
+
+#[tokio::test]
+async fn get_current_user_requires_a_session() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db);
+
+    let response = client
+        .post(format!("{}/auth/current-user", addr))
+        .json(&NoArgs {})
+        .send()
+        .await
+        .expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), 401);
+}
+
+#[rstest]
+#[case::valid_rename(Some("Renamed User"), 200, "Renamed User")]
+#[case::too_short_name(Some("R"), 422, "Password Change User")]
+#[case::nothing_to_change(None, 200, "Password Change User")]
+#[tokio::test]
+async fn update_profile_renames_the_user(
+    #[case] display_name: Option<&str>,
+    #[case] expected_status: u16,
+    #[case] expected_display_name: &str,
+) {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let (identifier, current_session, _other_session) = user_with_two_sessions(&db).await;
+    let user = user_of_identifier(&identifier, &db).await;
+
+    let response = client
+        .patch(format!("{}/auth/update-profile", addr))
+        .header("Authorization", format!("Bearer {}", current_session))
+        .json(&UpdateProfilePayload {
+            display_name: display_name.map(str::to_string),
+        })
+        .send()
+        .await
+        .expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), expected_status);
+    let api_response = response
+        .json::<ApiResponse<UserOnClient>>()
+        .await
+        .expect("Failed to deserialize response");
+    if expected_status == 200 {
+        assert_eq!(
+            api_response.data.map(|user| user.display_name),
+            Some(expected_display_name.to_string())
+        );
+    } else {
+        assert!(api_response.error.is_some());
+    }
+
+    let stored_user: User = db
+        .select(user)
+        .await
+        .expect("Failed to query")
+        .expect("User not found");
+    assert_eq!(stored_user.display_name, expected_display_name);
+}
+
+async fn send_add_identifier(
+    client: &Client,
+    addr: &str,
+    session_token: &str,
+    identifier: Identifier,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/add-identifier", addr))
+        .header("Authorization", format!("Bearer {}", session_token))
+        .json(&AddIdentifierPayload { identifier })
+        .send()
+        .await
+        .expect("Failed to send a request")
+}
+
+#[tokio::test]
+async fn add_identifier_links_a_mobile_to_an_email_user() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let (email, current_session, _other_session) = user_with_two_sessions(&db).await;
+    let mobile = Identifier::Mobile("+91 9876543210".to_string());
+
+    let response = send_add_identifier(&client, &addr, &current_session, mobile.clone()).await;
+
+    assert_eq!(response.status().as_u16(), 200);
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert_eq!(
+        api_response.data,
+        Some(messages::IDENTIFIER_ADDED.to_string())
+    );
+
+    let user = user_of_identifier(&email, &db).await;
+    assert_eq!(user_of_identifier(&mobile, &db).await, user);
+    assert_eq!(rows_of_user("user_identifier", &user, &db).await, 2);
+    assert!(can_log_in(&email, "thisisasecret", &db).await);
+    assert!(can_log_in(&mobile, "thisisasecret", &db).await);
+}
+
+#[rstest]
+#[case::second_email(Identifier::Email("second@example.com".to_string()), 409, ErrorCode::Conflict)]
+#[case::taken_mobile(Identifier::Mobile("+91 1234567890".to_string()), 409, ErrorCode::Conflict)]
+#[case::invalid_mobile(Identifier::Mobile("not a number".to_string()), 422, ErrorCode::ValidationFailed)]
+#[case::oauth_identifier(Identifier::Google("google_1234".to_string()), 422, ErrorCode::ValidationFailed)]
+#[tokio::test]
+async fn add_identifier_rejects_identifiers_that_cannot_be_linked(
+    #[case] identifier: Identifier,
+    #[case] expected_status: u16,
+    #[case] expected_code: ErrorCode,
+) {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let (email, current_session, _other_session) = user_with_two_sessions(&db).await;
+    let other_user = RegistrationFormData::new(
+        "Other User".to_string(),
+        Identifier::Mobile("+91 1234567890".to_string()),
+        "thisisasecret".to_string(),
+        Platform::Mobile,
+    );
+    register_user(other_user, &db)
+        .await
+        .expect("Failed to register user");
+
+    let response = send_add_identifier(&client, &addr, &current_session, identifier).await;
+
+    assert_eq!(response.status().as_u16(), expected_status);
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert!(api_response.error.is_some());
+    assert_eq!(api_response.code, Some(expected_code));
+    let user = user_of_identifier(&email, &db).await;
+    assert_eq!(rows_of_user("user_identifier", &user, &db).await, 1);
+}
+
+async fn send_remove_identifier(
+    client: &Client,
+    addr: &str,
+    session_token: &str,
+    identifier_value: &str,
+) -> reqwest::Response {
+    client
+        .delete(format!("{}/auth/remove-identifier", addr))
+        .query(&RemoveIdentifierQuery {
+            identifier_value: identifier_value.to_string(),
+        })
+        .header("Authorization", format!("Bearer {}", session_token))
+        .send()
+        .await
+        .expect("Failed to send a request")
+}
+
+#[tokio::test]
+async fn remove_identifier_unlinks_one_of_several_identifiers() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let (email, current_session, _other_session) = user_with_two_sessions(&db).await;
+    let user = user_of_identifier(&email, &db).await;
+    let mobile = Identifier::Mobile("+91 9876543210".to_string());
+    let response = send_add_identifier(&client, &addr, &current_session, mobile.clone()).await;
+    assert_eq!(response.status().as_u16(), 200);
+
+    let response = send_remove_identifier(&client, &addr, &current_session, "+919876543210").await;
+
+    assert_eq!(response.status().as_u16(), 200);
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert_eq!(
+        api_response.data,
+        Some(messages::IDENTIFIER_REMOVED.to_string())
+    );
+    assert_eq!(rows_of_user("user_identifier", &user, &db).await, 1);
+    assert!(can_log_in(&email, "thisisasecret", &db).await);
+    assert!(!can_log_in(&mobile, "thisisasecret", &db).await);
+}
+
+#[rstest]
+#[case::last_identifier(true, 400)]
+#[case::identifier_of_another_user(false, 404)]
+#[tokio::test]
+async fn remove_identifier_keeps_identifiers_it_must_not_remove(
+    #[case] own_identifier: bool,
+    #[case] expected_status: u16,
+) {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let (email, current_session, _other_session) = user_with_two_sessions(&db).await;
+    let (other_email, _, _) = user_with_two_sessions(&db).await;
+    let target = if own_identifier { &email } else { &other_email };
+    let identifier_value = match target {
+        Identifier::Email(value) => value.clone(),
+        _ => unreachable!("The test users register with an email"),
+    };
+
+    let response =
+        send_remove_identifier(&client, &addr, &current_session, &identifier_value).await;
+
+    assert_eq!(response.status().as_u16(), expected_status);
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert!(api_response.error.is_some());
+    assert!(can_log_in(&email, "thisisasecret", &db).await);
+    assert!(can_log_in(&other_email, "thisisasecret", &db).await);
+}
+
+#[tokio::test]
+async fn workos_users_are_created_once_and_found_afterwards() {
+    let db = get_test_db().await;
+    let provider = WorkosProvider::new();
+    let profile_id = format!("prof_{}", uuid::Uuid::new_v4().simple());
+    let profile = ProviderUser {
+        id: profile_id.clone(),
+        email: "member@example.org".to_string(),
+        name: Some("Armaan Ali".to_string()),
+        picture: None,
+    };
+
+    let created = provider
+        .find_or_create_user(profile.clone(), &db)
+        .await
+        .expect("Failed to create the WorkOS user");
+    let found = provider
+        .find_or_create_user(profile, &db)
+        .await
+        .expect("Failed to find the WorkOS user");
+    assert_eq!(created, found);
+
+    let identifiers: Vec<UserIdentifier> = db
+        .query("SELECT * FROM user_identifier WHERE user = $user")
+        .bind(("user", created.clone()))
+        .await
+        .expect("Failed to query identifiers")
+        .take(0)
+        .expect("Failed to parse identifiers");
+    assert_eq!(identifiers.len(), 1);
+    assert_eq!(identifiers[0].identifier_type, "workos");
+    assert_eq!(identifiers[0].identifier_value, profile_id);
+
+    let user: Option<User> = db.select(created).await.expect("Failed to fetch user");
+    let user = user.expect("User not found");
+    assert_eq!(user.display_name, "Armaan Ali");
+    assert!(user.is_verified());
+
+    let password_login = authenticate(
+        LoginFormData {
+            identifier: Identifier::Workos(profile_id),
+            password: "thisisasecret".to_string(),
+            platform: Platform::Web,
+        },
+        &db,
+    )
+    .await;
+    assert!(
+        password_login.is_err(),
+        "WorkOS accounts can only sign in through SSO"
+    );
+}
+
+async fn set_verified(user: &RecordId, verified: Option<bool>, db: &Surreal<SurrealClient>) {
+    let query = match verified {
+        Some(_) => "UPDATE $user SET verified = $verified",
+        None => "UPDATE $user UNSET verified",
+    };
+    db.query(query)
+        .bind(("user", user.clone()))
+        .bind(("verified", verified))
+        .await
+        .expect("Failed to set verified")
+        .check()
+        .expect("Failed to set verified");
+}
+
+async fn is_verified(user: &RecordId, db: &Surreal<SurrealClient>) -> bool {
+    let user: Option<User> = db.select(user.clone()).await.expect("Failed to fetch user");
+    user.expect("User not found").is_verified()
+}
+
+#[tokio::test]
+async fn oauth_sign_in_verifies_existing_accounts() {
+    let db = get_test_db().await;
+    let provider = WorkosProvider::new();
+    let profile = ProviderUser {
+        id: format!("prof_{}", uuid::Uuid::new_v4().simple()),
+        email: "returning@example.org".to_string(),
+        name: Some("Returning Member".to_string()),
+        picture: None,
+    };
+
+    let user = provider
+        .find_or_create_user(profile.clone(), &db)
+        .await
+        .expect("Failed to create the WorkOS user");
+    set_verified(&user, Some(false), &db).await;
+
+    provider
+        .find_or_create_user(profile, &db)
+        .await
+        .expect("Failed to find the WorkOS user");
+    assert!(is_verified(&user, &db).await);
+}
+
+#[tokio::test]
+async fn backfill_verifies_legacy_and_oauth_users_only() {
+    let db = get_test_db().await;
+    let register = |name: &str| {
+        RegistrationFormData::new(
+            name.to_string(),
+            Identifier::Email(format!("{}@example.com", uuid::Uuid::new_v4())),
+            "thisisasecret".to_string(),
+            Platform::Web,
+        )
+    };
+    let legacy_user = register_user(register("Legacy User"), &db)
+        .await
+        .expect("Failed to register user");
+    set_verified(&legacy_user, None, &db).await;
+    let unverified_user = register_user(register("New User"), &db)
+        .await
+        .expect("Failed to register user");
+    let oauth_user = WorkosProvider::new()
+        .find_or_create_user(
+            ProviderUser {
+                id: format!("prof_{}", uuid::Uuid::new_v4().simple()),
+                email: "legacy.sso@example.org".to_string(),
+                name: Some("Legacy SSO User".to_string()),
+                picture: None,
+            },
+            &db,
+        )
+        .await
+        .expect("Failed to create the WorkOS user");
+    set_verified(&oauth_user, Some(false), &db).await;
+
+    backfill_verified_users(&db)
+        .await
+        .expect("Failed to backfill verified users");
+
+    assert!(is_verified(&legacy_user, &db).await);
+    assert!(is_verified(&oauth_user, &db).await);
+    assert!(
+        !is_verified(&unverified_user, &db).await,
+        "Users who registered after verification existed still have to verify"
+    );
+}
+
+#[tokio::test]
+async fn mobile_google_login_yields_a_bearer_usable_token() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let profile = ProviderUser {
+        id: format!("google_{}", uuid::Uuid::new_v4().simple()),
+        email: "mobile.google@example.com".to_string(),
+        name: Some("Mobile Google User".to_string()),
+        picture: None,
+    };
+
+    let user = GoogleProvider::for_platform(Platform::Mobile)
+        .find_or_create_user(profile, &db)
+        .await
+        .expect("Failed to create the Google user");
+    let session_token = create_session(user, &db)
+        .await
+        .expect("Failed to create a session");
+    let login = OAuthCallback::login_response(
+        Platform::Mobile,
+        &session_token,
+        "__Host-google_oauth_state",
+        "google",
+    );
+    assert!(login.cookies.is_empty());
+
+    let response = client
+        .post(format!("{}/auth/me", addr))
+        .header("Authorization", format!("Bearer {}", login.data))
+        .json(&NoArgs {})
+        .send()
+        .await
+        .expect("Failed to call me");
+    assert_eq!(response.status().as_u16(), 200);
+    let api_response = response
+        .json::<ApiResponse<UserOnClient>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert_eq!(
+        api_response.data.map(|user| user.display_name),
+        Some("Mobile Google User".to_string())
+    );
+}
@@ -1,6 +1,7 @@
-use crate::common::get_test_db;
+use crate::common::{SeedUser, get_test_db};
 use merzah::{
     auth::session::create_session,
+    messages,
     models::{
         api_responses::ApiResponse,
         education::{
@@ -197,13 +198,14 @@ async fn create_user_with_role(
     let user_id = RecordId::from(("users", format!("user_{}", uuid::Uuid::new_v4())));
     let user: User = db
         .create(user_id.clone())
-        .content(User {
+        .content(SeedUser {
             id: user_id,
             created_at: Datetime::default(),
             display_name: display_name.to_string(),
             password_hash: "hash".to_string(),
             role: role.to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("failed to create user")
@@ -621,7 +623,10 @@ async fn education_enrollment_flow_updates_progress_and_certificate() {
         .await
         .expect("failed to deserialize enroll response");
 
-    assert_eq!(enroll_body.data, Some("Enrolled successfully".to_string()));
+    assert_eq!(
+        enroll_body.data,
+        Some(messages::COURSE_ENROLLED.to_string())
+    );
 
     let duplicate_response = auth_post(&client, &student_session, &enroll_url)
         .json(&CourseIdParam {
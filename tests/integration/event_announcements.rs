@@ -0,0 +1,105 @@
+use crate::common::{RecordingNotifier, get_test_db};
+use chrono::{Duration, FixedOffset, Utc};
+use merzah::{
+    auth::custom_auth::register_user,
+    models::{
+        auth::{Platform, RegistrationFormData},
+        events::{Event, EventCategory, EventRecord},
+        mosque::MosqueRecord,
+        user::Identifier,
+    },
+    services::event_announcements::notify_attendees,
+};
+use serde::Serialize;
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client, sql::Geometry};
+
+#[derive(Serialize)]
+struct CreateMosque {
+    location: Geometry,
+    name: String,
+}
+
+async fn register(identifier: Identifier, db: &Surreal<Client>) -> RecordId {
+    let form = RegistrationFormData::new(
+        "Armaan Ali".to_string(),
+        identifier,
+        "thisisasecret".to_string(),
+        Platform::Web,
+    );
+    register_user(form, db)
+        .await
+        .expect("Failed to register user")
+}
+
+async fn create_event(db: &Surreal<Client>) -> Event {
+    let mosque: MosqueRecord = db
+        .create("mosques")
+        .content(CreateMosque {
+            location: Geometry::Point((0.0, 0.0).into()),
+            name: "Announcement Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Not returned");
+
+    db.create("events")
+        .content(EventRecord {
+            title: "Friday Halaqah".to_string(),
+            description: "Weekly circle".to_string(),
+            category: EventCategory::Halaqah,
+            date: Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) + Duration::days(2),
+            mosque: mosque.id,
+            speaker: None,
+            recurrence_pattern: None,
+            recurrence_end_date: None,
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
+        })
+        .await
+        .expect("Failed to create event")
+        .expect("Not returned")
+}
+
+async fn rsvp(user: &RecordId, event: &Event, db: &Surreal<Client>) {
+    db.query("RELATE $user -> attending -> $event")
+        .bind(("user", user.clone()))
+        .bind(("event", event.id.clone()))
+        .await
+        .expect("Failed to RSVP")
+        .check()
+        .expect("Failed to RSVP");
+}
+
+#[tokio::test]
+async fn test_announcement_reaches_every_attendee() {
+    let db = get_test_db().await;
+    let notifier = RecordingNotifier::default();
+    let event = create_event(&db).await;
+
+    let email_attendee = register(Identifier::Email("attendee@example.com".to_string()), &db).await;
+    let mobile_attendee = register(Identifier::Mobile("+919876543210".to_string()), &db).await;
+    let _absentee = register(Identifier::Email("absentee@example.com".to_string()), &db).await;
+    rsvp(&email_attendee, &event, &db).await;
+    rsvp(&mobile_attendee, &event, &db).await;
+
+    let reached = notify_attendees(&event, "Please bring a prayer mat", &notifier, &db)
+        .await
+        .expect("Failed to notify the attendees");
+    assert_eq!(reached, 2);
+
+    let emails = notifier.emails.lock().unwrap();
+    assert_eq!(
+        *emails,
+        vec![(
+            "attendee@example.com".to_string(),
+            "Please bring a prayer mat".to_string()
+        )]
+    );
+
+    let sms = notifier.sms.lock().unwrap();
+    assert_eq!(sms.len(), 1);
+    assert_eq!(sms[0].0, "+919876543210");
+    assert!(sms[0].1.contains("Please bring a prayer mat"));
+}
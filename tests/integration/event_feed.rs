@@ -0,0 +1,138 @@
+use crate::common::get_test_db;
+use chrono::{Duration, FixedOffset, Utc};
+use merzah::{
+    models::{
+        events::{Event, EventCategory, EventRecord},
+        mosque::MosqueRecord,
+    },
+    server_functions::event_feed::RSS_CONTENT_TYPE,
+    spawn_app,
+};
+use reqwest::Client;
+use serde::Serialize;
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client as SurrealClient, sql::Geometry};
+
+#[derive(Serialize)]
+struct CreateMosque {
+    location: Geometry,
+    name: String,
+}
+
+#[derive(Serialize)]
+struct EventFeedParams {
+    mosque_id: String,
+}
+
+async fn create_hosted_event(
+    db: &Surreal<SurrealClient>,
+    mosque: &RecordId,
+    title: &str,
+    days_from_now: i64,
+) -> Event {
+    let event: Event = db
+        .create("events")
+        .content(EventRecord {
+            title: title.to_string(),
+            description: format!("Details of {title}"),
+            category: EventCategory::Community,
+            date: Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap())
+                + Duration::days(days_from_now),
+            mosque: mosque.clone(),
+            speaker: None,
+            recurrence_pattern: None,
+            recurrence_end_date: None,
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
+        })
+        .await
+        .expect("Failed to create event")
+        .expect("Not returned");
+
+    db.query("RELATE $mosque -> hosts -> $event")
+        .bind(("mosque", mosque.clone()))
+        .bind(("event", event.id.clone()))
+        .await
+        .expect("Failed to relate the event")
+        .check()
+        .expect("Failed to relate the event");
+    event
+}
+
+#[tokio::test]
+async fn test_rss_feed_lists_upcoming_events_as_items() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let mosque: MosqueRecord = db
+        .create("mosques")
+        .content(CreateMosque {
+            location: Geometry::Point((0.0, 0.0).into()),
+            name: "Feed Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Not returned");
+    let upcoming = create_hosted_event(&db, &mosque.id, "Tea & Talk", 3).await;
+    create_hosted_event(&db, &mosque.id, "Last Week's Halaqah", -7).await;
+
+    let response = client
+        .get(format!("{}/mosques/events/rss", addr))
+        .query(&EventFeedParams {
+            mosque_id: mosque.id.to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to fetch the feed");
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response
+            .headers()
+            .get("content-type")
+            .and_then(|value| value.to_str().ok()),
+        Some(RSS_CONTENT_TYPE)
+    );
+
+    let rss = response.text().await.expect("Failed to read the feed");
+    assert!(rss.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    assert!(rss.contains("<rss version=\"2.0\">"));
+    assert!(rss.contains("<title>Feed Mosque events</title>"));
+    assert!(rss.trim_end().ends_with("</channel>\n</rss>"));
+
+    assert_eq!(
+        rss.matches("<item>").count(),
+        1,
+        "Only upcoming events belong in the feed"
+    );
+    assert_eq!(rss.matches("</item>").count(), 1);
+    let item = rss
+        .split("<item>")
+        .nth(1)
+        .and_then(|item| item.split("</item>").next())
+        .expect("The feed has no item");
+    assert!(item.contains("<title>Tea &amp; Talk</title>"));
+    assert!(item.contains("<description>Details of Tea &amp; Talk</description>"));
+    assert!(item.contains(&format!(
+        "<pubDate>{}</pubDate>",
+        upcoming.date.to_rfc2822()
+    )));
+    assert!(item.contains(&format!("event_id={}</link>", upcoming.id)));
+}
+
+#[tokio::test]
+async fn test_rss_feed_of_an_unknown_mosque_is_not_found() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+
+    let response = Client::new()
+        .get(format!("{}/mosques/events/rss", addr))
+        .query(&EventFeedParams {
+            mosque_id: "mosques:missing".to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to fetch the feed");
+    assert_eq!(response.status(), 404);
+}
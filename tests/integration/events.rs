@@ -1,18 +1,26 @@
-use crate::common::get_test_db;
-use chrono::{Duration, FixedOffset, Utc};
+use crate::common::{SeedUser, get_test_db};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+use chrono_tz::{America::Detroit, Asia::Kolkata, Tz};
 use merzah::{
     auth::session::create_session,
+    jobs::event_rotation::start_scheduler_or_log,
+    messages,
     models::{
         api_responses::ApiResponse,
         events::{
-            CreateEvent, Event, EventCategory, EventRecord, EventRecurrence, Interval,
-            PersonalEvent, UpdatedEvent,
+            CreateEvent, DeleteScope, Event, EventCategory, EventCategoryCount, EventCursor,
+            EventPage, EventRecord, EventRecurrence, FetchedEvents, Interval, MAX_OCCURRENCES,
+            MosqueEvents, PersonalEvent, PublicEvent, RotationLogEntry, RotationOutcome, RsvpEvent,
+            UpdatedEvent,
         },
         mosque::MosqueRecord,
         user::User,
     },
-    services::recurrence::{calculate_next_date, check_and_rotate_events},
+    services::recurrence::{
+        RotationMode, calculate_next_date, check_and_rotate_events, rotate_event,
+    },
     spawn_app,
+    utils::ssr::{PRIVATE_CACHE_CONTROL, PUBLIC_CACHE_CONTROL, PUBLIC_CACHE_VARY},
 };
 use reqwest::Client;
 use rstest::rstest;
@@ -25,6 +33,40 @@ struct CreateMosque {
     pub name: String,
 }
 
+#[derive(Serialize)]
+struct NoArgs {}
+
+#[derive(Serialize)]
+struct FetchMosqueEventsParams {
+    mosque_id: String,
+    include_past: Option<bool>,
+    as_timezone: Option<String>,
+    after: Option<EventCursor>,
+    limit: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct MosqueIdParams {
+    mosque_id: String,
+}
+
+#[derive(Serialize)]
+struct DeleteEventParams {
+    event_id: String,
+    delete_scope: Option<DeleteScope>,
+}
+
+#[derive(Serialize)]
+struct RestoreEventParams {
+    event_id: String,
+}
+
+#[derive(Serialize)]
+struct EventExceptionParams {
+    event_id: String,
+    date: DateTime<FixedOffset>,
+}
+
 #[derive(Serialize)]
 struct AddEventParams {
     pub create_event: CreateEvent,
@@ -41,10 +83,32 @@ struct RsvpParams {
     pub event_id: String,
 }
 
+#[derive(Serialize)]
+struct FetchEventsForMosquesParams {
+    mosque_ids: Vec<String>,
+    from: Option<DateTime<FixedOffset>>,
+    to: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Serialize)]
+struct FetchPublicMosqueEventsParams {
+    mosque_id: String,
+    from: Option<DateTime<FixedOffset>>,
+    to: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Serialize)]
+struct EventWindowParams {
+    from: Option<DateTime<FixedOffset>>,
+    to: Option<DateTime<FixedOffset>>,
+}
+
 #[derive(Serialize)]
 struct FetchUsersFavoriteMosquesEventsParams {
     pub lat: f64,
     pub lon: f64,
+    pub after: Option<EventCursor>,
+    pub limit: Option<u32>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -85,6 +149,22 @@ fn build_auth_patch(
     }
 }
 
+fn build_auth_get(
+    client: &Client,
+    session: &str,
+    auth_method: AuthMethod,
+    url: &str,
+) -> reqwest::RequestBuilder {
+    match auth_method {
+        AuthMethod::Web => client
+            .get(url)
+            .header("Cookie", format!("__Host-session={}", session)),
+        AuthMethod::Mobile => client
+            .get(url)
+            .header("Authorization", format!("Bearer {}", session)),
+    }
+}
+
 fn build_auth_delete(
     client: &Client,
     session: &str,
@@ -103,17 +183,25 @@ fn build_auth_delete(
 
 async fn setup_user_and_session(
     db: &surrealdb::Surreal<surrealdb::engine::remote::ws::Client>,
+) -> (User, String) {
+    setup_user_with_role(db, "regular").await
+}
+
+async fn setup_user_with_role(
+    db: &surrealdb::Surreal<surrealdb::engine::remote::ws::Client>,
+    role: &str,
 ) -> (User, String) {
     let user_id = RecordId::from(("users", format!("user_{}", uuid::Uuid::new_v4())));
     let user: User = db
         .create(user_id.clone())
-        .content(User {
+        .content(SeedUser {
             id: user_id.clone(),
             created_at: Datetime::default(),
             display_name: "Test User".to_string(),
             password_hash: "hash".to_string(),
-            role: "regular".to_string(),
+            role: role.to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create user")
@@ -125,6 +213,20 @@ async fn setup_user_and_session(
     (user, session)
 }
 
+async fn make_mosque_admin(
+    db: &surrealdb::Surreal<surrealdb::engine::remote::ws::Client>,
+    user: &User,
+    mosque: &MosqueRecord,
+) {
+    db.query("RELATE $user -> handles -> $mosque SET granted_by = $user")
+        .bind(("user", user.id.clone()))
+        .bind(("mosque", mosque.id.clone()))
+        .await
+        .expect("Failed to relate")
+        .check()
+        .expect("Failed to relate");
+}
+
 async fn setup_mosque(
     db: &surrealdb::Surreal<surrealdb::engine::remote::ws::Client>,
 ) -> MosqueRecord {
@@ -161,7 +263,16 @@ async fn create_hosted_event(
 ) -> Event {
     let event_date =
         Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) + Duration::days(3);
+    create_hosted_event_at(db, mosque_id, title, event_date, None).await
+}
 
+async fn create_hosted_event_at(
+    db: &surrealdb::Surreal<surrealdb::engine::remote::ws::Client>,
+    mosque_id: &RecordId,
+    title: &str,
+    event_date: DateTime<FixedOffset>,
+    recurrence_pattern: Option<EventRecurrence>,
+) -> Event {
     let event: Event = db
         .create("events")
         .content(EventRecord {
@@ -171,8 +282,14 @@ async fn create_hosted_event(
             date: event_date,
             mosque: mosque_id.clone(),
             speaker: None,
-            recurrence_pattern: None,
-            recurrence_end_date: None,
+            recurrence_end_date: recurrence_pattern
+                .as_ref()
+                .map(|_| event_date + Duration::days(365)),
+            recurrence_pattern,
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
         })
         .await
         .expect("Failed to create event")
@@ -241,6 +358,9 @@ async fn test_create_recurring_event_via_api(#[case] auth_method: AuthMethod) {
         speaker: Some("Imam Ahmed".to_string()),
         recurrence_pattern: Some(EventRecurrence::Weekly),
         recurrence_duration: Some(Interval::ThreeMonths),
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
     };
 
     let response = create_event_via_api(&client, &addr, &session, auth_method, create_event).await;
@@ -296,6 +416,9 @@ async fn test_create_one_time_event_via_api() {
         speaker: Some("Scholar Yusuf".to_string()),
         recurrence_pattern: None,
         recurrence_duration: None,
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
     };
 
     let response =
@@ -352,6 +475,9 @@ async fn test_create_event_with_different_recurrence_patterns(
         speaker: None,
         recurrence_pattern: Some(pattern.clone()),
         recurrence_duration: duration,
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
     };
 
     let response =
@@ -396,6 +522,9 @@ async fn test_update_event_title() {
         speaker: None,
         recurrence_pattern: None,
         recurrence_duration: None,
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
     };
 
     let _ = create_event_via_api(&client, &addr, &session, AuthMethod::Mobile, create_event).await;
@@ -470,6 +599,9 @@ async fn test_delete_event() {
         speaker: None,
         recurrence_pattern: None,
         recurrence_duration: None,
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
     };
 
     let _ = create_event_via_api(&client, &addr, &session, AuthMethod::Mobile, create_event).await;
@@ -508,20 +640,20 @@ async fn test_delete_event() {
 
     let api_response: ApiResponse<String> = response.json().await.expect("Failed to deserialize");
     assert!(api_response.error.is_none());
-    assert_eq!(
-        api_response.data,
-        Some("Successfully deleted the event record".to_string())
-    );
+    assert_eq!(api_response.data, Some(messages::EVENT_DELETED.to_string()));
 
-    let deleted_events: Vec<Event> = db
-        .query("SELECT * FROM $event_id")
-        .bind(("event_id", event_id))
+    let deleted_event: Option<Event> = db
+        .select(event_id)
         .await
-        .expect("Failed to query deleted event")
-        .take(0)
-        .expect("Take failed");
+        .expect("Failed to query deleted event");
 
-    assert!(deleted_events.is_empty(), "Event should be deleted");
+    assert!(
+        deleted_event
+            .expect("The deleted event should be kept")
+            .deleted_at
+            .is_some(),
+        "Event should be marked deleted"
+    );
 }
 
 #[rstest]
@@ -563,7 +695,12 @@ async fn test_fetch_users_favorite_mosques_events_includes_nearby_and_deduplicat
         "{}/mosques/events/fetch-users-favorite-mosques-events",
         addr
     );
-    let params = FetchUsersFavoriteMosquesEventsParams { lat: 0.0, lon: 0.0 };
+    let params = FetchUsersFavoriteMosquesEventsParams {
+        lat: 0.0,
+        lon: 0.0,
+        after: None,
+        limit: None,
+    };
 
     let req = build_auth_headers(&client, &session, auth_method, &url);
     let response = req
@@ -578,13 +715,13 @@ async fn test_fetch_users_favorite_mosques_events_includes_nearby_and_deduplicat
         response.text().await
     );
 
-    let api_response: ApiResponse<Vec<PersonalEvent>> = response
+    let api_response: ApiResponse<EventPage<Vec<PersonalEvent>>> = response
         .json()
         .await
         .expect("Failed to deserialize events response");
 
     assert!(api_response.error.is_none());
-    let events = api_response.data.expect("Expected event data");
+    let events = api_response.data.expect("Expected event data").events;
     assert_eq!(
         events.len(),
         2,
@@ -659,6 +796,10 @@ async fn test_manual_rotation_trigger() {
             speaker: None,
             recurrence_pattern: Some(EventRecurrence::Weekly),
             recurrence_end_date: Some(past_date + Duration::days(365)),
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
         })
         .await
         .expect("Failed to create event")
@@ -686,20 +827,28 @@ async fn test_manual_rotation_trigger() {
     assert_eq!(rotated_event.date, expected_next);
 }
 
+#[rstest]
+#[case::default_clears_rsvps(None, 0)]
+#[case::clear_attendance(Some(RotationMode::ClearAttendance), 0)]
+#[case::keep_attendance(Some(RotationMode::KeepAttendance), 1)]
 #[tokio::test]
-async fn test_rsvp_persistence_across_rotation() {
+async fn test_rsvps_across_rotation(
+    #[case] mode: Option<RotationMode>,
+    #[case] expected_rsvps: usize,
+) {
     let db = get_test_db().await;
 
     let user_id = RecordId::from(("users", "rsvp_user"));
     let user: User = db
         .create(user_id.clone())
-        .content(User {
+        .content(SeedUser {
             id: user_id.clone(),
             created_at: Datetime::default(),
             display_name: "RSVP User".to_string(),
             password_hash: "hash".to_string(),
             role: "regular".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create user")
@@ -729,6 +878,10 @@ async fn test_rsvp_persistence_across_rotation() {
             speaker: None,
             recurrence_pattern: Some(EventRecurrence::Weekly),
             recurrence_end_date: Some(past_date + Duration::days(365)),
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
         })
         .await
         .expect("Failed to create event")
@@ -749,9 +902,20 @@ async fn test_rsvp_persistence_across_rotation() {
         .expect("Take failed");
     assert_eq!(rsvp_before.len(), 1);
 
-    let _ = check_and_rotate_events(&db)
-        .await
-        .expect("Failed to rotate events");
+    match mode {
+        Some(mode) => {
+            let rotated = rotate_event(event.clone(), mode, &db)
+                .await
+                .expect("Failed to rotate event");
+            assert!(rotated);
+        }
+        None => {
+            let rotated_count = check_and_rotate_events(&db)
+                .await
+                .expect("Failed to rotate events");
+            assert_eq!(rotated_count, 1);
+        }
+    }
 
     let rsvp_after: Vec<RecordId> = db
         .query("SELECT VALUE in FROM attending WHERE out = $event")
@@ -760,7 +924,11 @@ async fn test_rsvp_persistence_across_rotation() {
         .expect("Failed to query RSVP after rotation")
         .take(0)
         .expect("Take failed");
-    assert_eq!(rsvp_after.len(), 1, "RSVP should persist after rotation");
+    assert_eq!(
+        rsvp_after.len(),
+        expected_rsvps,
+        "Unexpected RSVPs left after rotation"
+    );
 }
 
 #[tokio::test]
@@ -793,6 +961,10 @@ async fn test_rotation_deletes_event_past_end_date() {
             speaker: None,
             recurrence_pattern: Some(EventRecurrence::Weekly),
             recurrence_end_date: Some(end_date),
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
         })
         .await
         .expect("Failed to create event")
@@ -844,6 +1016,10 @@ async fn test_query_returns_correct_events_not_rotated_yet() {
             speaker: None,
             recurrence_pattern: Some(EventRecurrence::Weekly),
             recurrence_end_date: Some(future_date + Duration::days(90)),
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
         })
         .await
         .expect("Failed to create event")
@@ -894,6 +1070,10 @@ async fn test_non_recurring_event_not_rotated() {
             speaker: None,
             recurrence_pattern: None,
             recurrence_end_date: None,
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
         })
         .await
         .expect("Failed to create event")
@@ -921,3 +1101,2004 @@ async fn test_non_recurring_event_not_rotated() {
         "Non-recurring event date should remain unchanged"
     );
 }
+
+#[rstest]
+#[case::web(AuthMethod::Web)]
+#[case::mobile(AuthMethod::Mobile)]
+#[tokio::test]
+async fn test_rsvp_and_cancel_rsvp(#[case] auth_method: AuthMethod) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+    let event = create_hosted_event(&db, &mosque.id, "RSVP Event").await;
+
+    let rsvp_url = format!("{}/mosques/events/rsvp", addr);
+    let params = RsvpParams {
+        event_id: event.id.to_string(),
+    };
+
+    let response = build_auth_headers(&client, &session, auth_method, &rsvp_url)
+        .json(&params)
+        .send()
+        .await
+        .expect("Failed to send RSVP");
+    assert_eq!(response.status(), 200);
+
+    let duplicate = build_auth_headers(&client, &session, auth_method, &rsvp_url)
+        .json(&params)
+        .send()
+        .await
+        .expect("Failed to send duplicate RSVP");
+    assert_eq!(duplicate.status(), 409);
+
+    let rsvps: Vec<RecordId> = db
+        .query("SELECT VALUE id FROM attending WHERE in = $user AND out = $event")
+        .bind(("user", user.id.clone()))
+        .bind(("event", event.id.clone()))
+        .await
+        .expect("Failed to query RSVPs")
+        .take(0)
+        .expect("Take failed");
+    assert_eq!(
+        rsvps.len(),
+        1,
+        "Duplicate RSVP must not create a second edge"
+    );
+
+    let cancel_url = format!(
+        "{}/mosques/events/cancel-rsvp?event_id={}",
+        addr,
+        urlencoding::encode(&event.id.to_string())
+    );
+    let response = build_auth_delete(&client, &session, auth_method, &cancel_url)
+        .send()
+        .await
+        .expect("Failed to cancel RSVP");
+    assert_eq!(response.status(), 200);
+
+    let response = build_auth_delete(&client, &session, auth_method, &cancel_url)
+        .send()
+        .await
+        .expect("Failed to cancel RSVP");
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_rsvp_to_missing_event_returns_not_found() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+
+    let params = RsvpParams {
+        event_id: "events:does_not_exist".to_string(),
+    };
+    let response = build_auth_headers(
+        &client,
+        &session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/rsvp", addr),
+    )
+    .json(&params)
+    .send()
+    .await
+    .expect("Failed to send RSVP");
+
+    assert_eq!(response.status(), 404);
+    let api_response: ApiResponse<String> = response.json().await.expect("Failed to deserialize");
+    assert_eq!(
+        api_response.error,
+        Some("No event found with the provided ID".to_string())
+    );
+}
+
+#[rstest]
+#[case::before_the_deadline(Duration::hours(1), 200)]
+#[case::after_the_deadline(-Duration::hours(1), 400)]
+#[tokio::test]
+async fn test_rsvp_respects_the_deadline(
+    #[case] deadline_from_now: Duration,
+    #[case] expected_status: u16,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+    let event = create_hosted_event(&db, &mosque.id, "Headcount Dinner").await;
+
+    let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let deadline = now + deadline_from_now;
+    db.query("UPDATE $event SET rsvp_deadline = $deadline")
+        .bind(("event", event.id.clone()))
+        .bind(("deadline", deadline.to_rfc3339()))
+        .await
+        .expect("Failed to set the deadline")
+        .check()
+        .expect("Failed to set the deadline");
+
+    let events = fetch_public_mosque_events(
+        client.get(format!(
+            "{}/mosques/events/fetch-public-mosque-events",
+            addr
+        )),
+        &mosque,
+    )
+    .await;
+    assert_eq!(
+        events[0]
+            .event
+            .rsvp_deadline
+            .map(|deadline| deadline.timestamp()),
+        Some(deadline.timestamp())
+    );
+
+    let response = build_auth_headers(
+        &client,
+        &session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/rsvp", addr),
+    )
+    .json(&RsvpParams {
+        event_id: event.id.to_string(),
+    })
+    .send()
+    .await
+    .expect("Failed to send RSVP");
+    assert_eq!(response.status().as_u16(), expected_status);
+
+    if expected_status == 400 {
+        let api_response: ApiResponse<String> =
+            response.json().await.expect("Failed to deserialize");
+        assert_eq!(api_response.error, Some("RSVPs closed".to_string()));
+    }
+}
+
+#[rstest]
+#[case::invalid_cron("not a cron")]
+#[case::out_of_range_cron("0 0 25 * * *")]
+#[tokio::test]
+async fn test_scheduler_failure_does_not_stop_the_app(#[case] schedule: &str) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    assert!(!start_scheduler_or_log(db.clone(), schedule).await);
+
+    let response = client
+        .post(format!("{}/auth/me", addr))
+        .json(&NoArgs {})
+        .send()
+        .await
+        .expect("The app should keep serving requests");
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn test_rotation_failure_is_recorded_in_the_rotation_log() {
+    let db = get_test_db().await;
+
+    let mosque: MosqueRecord = db
+        .create("mosques")
+        .content(CreateMosque {
+            location: Geometry::Point((0.0, 0.0).into()),
+            name: "Rotation Log Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Not returned");
+
+    let past_date =
+        Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) - Duration::days(1);
+
+    let mut event: Event = db
+        .create("events")
+        .content(EventRecord {
+            title: "Stuck Yearly Event".to_string(),
+            description: "This event cannot move to a next year".to_string(),
+            category: EventCategory::Eid,
+            date: past_date,
+            mosque: mosque.id.clone(),
+            speaker: None,
+            recurrence_pattern: Some(EventRecurrence::Yearly),
+            recurrence_end_date: Some(past_date + Duration::days(365)),
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
+        })
+        .await
+        .expect("Failed to create event")
+        .expect("Not returned");
+
+    // There is no year after the last one chrono can represent, so no next date exists.
+    event.date = FixedOffset::east_opt(0)
+        .unwrap()
+        .from_utc_datetime(&NaiveDate::MAX.and_hms_opt(0, 0, 0).unwrap());
+
+    let rotated = rotate_event(event.clone(), RotationMode::default(), &db)
+        .await
+        .expect("A stuck event should not fail the rotation run");
+    assert!(!rotated);
+
+    let entries: Vec<RotationLogEntry> = db
+        .query("SELECT * FROM rotation_log WHERE event = $event")
+        .bind(("event", event.id.clone()))
+        .await
+        .expect("Failed to query the rotation log")
+        .take(0)
+        .expect("Take failed");
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].outcome, RotationOutcome::Failed);
+    assert!(
+        entries[0]
+            .reason
+            .as_deref()
+            .is_some_and(|reason| reason.contains("Yearly"))
+    );
+}
+
+#[derive(serde::Deserialize)]
+struct RotationError {
+    rotation_error: Option<String>,
+}
+
+#[tokio::test]
+async fn test_events_without_a_next_date_are_flagged_and_not_retried() {
+    let db = get_test_db().await;
+
+    let mosque: MosqueRecord = db
+        .create("mosques")
+        .content(CreateMosque {
+            location: Geometry::Point((0.0, 0.0).into()),
+            name: "Flagged Rotation Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Not returned");
+
+    let past_date =
+        Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) - Duration::days(1);
+
+    let event: Event = db
+        .create("events")
+        .content(EventRecord {
+            title: "Flagged Yearly Event".to_string(),
+            description: "This event gets flagged on rotation".to_string(),
+            category: EventCategory::Eid,
+            date: past_date,
+            mosque: mosque.id.clone(),
+            speaker: None,
+            recurrence_pattern: Some(EventRecurrence::Yearly),
+            recurrence_end_date: Some(past_date + Duration::days(365)),
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
+        })
+        .await
+        .expect("Failed to create event")
+        .expect("Not returned");
+
+    let mut unrotatable = event.clone();
+    unrotatable.date = FixedOffset::east_opt(0)
+        .unwrap()
+        .from_utc_datetime(&NaiveDate::MAX.and_hms_opt(0, 0, 0).unwrap());
+    let rotated = rotate_event(unrotatable, RotationMode::default(), &db)
+        .await
+        .expect("Failed to rotate event");
+    assert!(!rotated);
+
+    let flagged: Option<RotationError> = db
+        .select(event.id.clone())
+        .await
+        .expect("Failed to select event");
+    assert!(flagged.and_then(|event| event.rotation_error).is_some());
+
+    // The stored date could rotate, but the flag keeps the scheduler away from the event.
+    let rotated_count = check_and_rotate_events(&db)
+        .await
+        .expect("Failed to rotate events");
+    assert_eq!(rotated_count, 0);
+
+    let unchanged: Option<Event> = db
+        .select(event.id.clone())
+        .await
+        .expect("Failed to select event");
+    assert_eq!(unchanged.expect("Event was deleted").date, event.date);
+}
+
+#[tokio::test]
+async fn test_count_limited_series_is_deleted_after_its_last_occurrence() {
+    let db = get_test_db().await;
+
+    let mosque: MosqueRecord = db
+        .create("mosques")
+        .content(CreateMosque {
+            location: Geometry::Point((0.0, 0.0).into()),
+            name: "Counted Series Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Not returned");
+
+    let past_date =
+        Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) - Duration::days(1);
+
+    let event: Event = db
+        .create("events")
+        .content(EventRecord {
+            title: "Three Week Course".to_string(),
+            description: "Runs for exactly three sessions".to_string(),
+            category: EventCategory::Workshop,
+            date: past_date,
+            mosque: mosque.id.clone(),
+            speaker: None,
+            recurrence_pattern: Some(EventRecurrence::Weekly),
+            recurrence_end_date: None,
+            recurrence_count: Some(3),
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
+        })
+        .await
+        .expect("Failed to create event")
+        .expect("Not returned");
+
+    for expected_rotations in 1..=2 {
+        let current: Event = db
+            .select(event.id.clone())
+            .await
+            .expect("Failed to select event")
+            .expect("Event deleted too early");
+        let rotated = rotate_event(current, RotationMode::default(), &db)
+            .await
+            .expect("Failed to rotate event");
+        assert!(rotated);
+
+        let rotated_event: Event = db
+            .select(event.id.clone())
+            .await
+            .expect("Failed to select event")
+            .expect("Event deleted too early");
+        assert_eq!(rotated_event.occurrences_rotated, expected_rotations);
+        assert_eq!(
+            rotated_event.date,
+            past_date + Duration::weeks(expected_rotations as i64)
+        );
+    }
+
+    let last: Event = db
+        .select(event.id.clone())
+        .await
+        .expect("Failed to select event")
+        .expect("Event deleted too early");
+    let rotated = rotate_event(last, RotationMode::default(), &db)
+        .await
+        .expect("Failed to rotate event");
+    assert!(!rotated);
+
+    let deleted: Option<Event> = db
+        .select(event.id.clone())
+        .await
+        .expect("Failed to select event");
+    assert!(deleted.is_none());
+}
+
+#[tokio::test]
+async fn test_rotation_skips_cancelled_occurrences() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+    make_mosque_admin(&db, &user, &mosque).await;
+
+    let past_date =
+        Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) - Duration::days(1);
+
+    let event: Event = db
+        .create("events")
+        .content(EventRecord {
+            title: "Weekly Tafseer".to_string(),
+            description: "The imam is away for one week".to_string(),
+            category: EventCategory::Halaqah,
+            date: past_date,
+            mosque: mosque.id.clone(),
+            speaker: None,
+            recurrence_pattern: Some(EventRecurrence::Weekly),
+            recurrence_end_date: Some(past_date + Duration::days(365)),
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
+        })
+        .await
+        .expect("Failed to create event")
+        .expect("Not returned");
+
+    let exception = EventExceptionParams {
+        event_id: event.id.to_string(),
+        date: past_date + Duration::weeks(1),
+    };
+    let response = build_auth_headers(
+        &client,
+        &session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/add-exception", addr),
+    )
+    .json(&exception)
+    .send()
+    .await
+    .expect("Failed to add the exception");
+    assert_eq!(response.status(), 200);
+
+    let current: Event = db
+        .select(event.id.clone())
+        .await
+        .expect("Failed to select event")
+        .expect("Event not found");
+    assert_eq!(current.exception_dates.len(), 1);
+
+    let rotated = rotate_event(current, RotationMode::default(), &db)
+        .await
+        .expect("Failed to rotate event");
+    assert!(rotated);
+
+    let rotated_event: Event = db
+        .select(event.id.clone())
+        .await
+        .expect("Failed to select event")
+        .expect("Event not found");
+    assert_eq!(rotated_event.date, past_date + Duration::weeks(2));
+    assert_eq!(rotated_event.occurrences_rotated, 2);
+
+    let remove_url = format!("{}/mosques/events/remove-exception", addr);
+    for expected_status in [200, 404] {
+        let response = build_auth_delete(&client, &session, AuthMethod::Mobile, &remove_url)
+            .query(&exception)
+            .send()
+            .await
+            .expect("Failed to remove the exception");
+        assert_eq!(response.status(), expected_status);
+    }
+}
+
+#[rstest]
+#[case::whole_series(
+    Some(EventRecurrence::Weekly),
+    Some(DeleteScope::Series),
+    messages::EVENT_DELETED,
+    false
+)]
+#[case::default_is_the_series(Some(EventRecurrence::Weekly), None, messages::EVENT_DELETED, false)]
+#[case::one_occurrence(
+    Some(EventRecurrence::Weekly),
+    Some(DeleteScope::This),
+    messages::EVENT_OCCURRENCE_DELETED,
+    true
+)]
+#[case::one_off_event(None, Some(DeleteScope::This), messages::EVENT_DELETED, false)]
+#[tokio::test]
+async fn test_delete_event_scopes(
+    #[case] pattern: Option<EventRecurrence>,
+    #[case] delete_scope: Option<DeleteScope>,
+    #[case] expected_message: &str,
+    #[case] moves_to_next_occurrence: bool,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+    make_mosque_admin(&db, &user, &mosque).await;
+
+    let event_date =
+        Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) + Duration::days(3);
+    let event: Event = db
+        .create("events")
+        .content(EventRecord {
+            title: "Scoped Delete Event".to_string(),
+            description: "Deleted with a scope".to_string(),
+            category: EventCategory::Lecture,
+            date: event_date,
+            mosque: mosque.id.clone(),
+            speaker: None,
+            recurrence_end_date: pattern.as_ref().map(|_| event_date + Duration::days(90)),
+            recurrence_pattern: pattern,
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
+        })
+        .await
+        .expect("Failed to create event")
+        .expect("Not returned");
+
+    let response = build_auth_delete(
+        &client,
+        &session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/delete/", addr),
+    )
+    .query(&DeleteEventParams {
+        event_id: event.id.to_string(),
+        delete_scope,
+    })
+    .send()
+    .await
+    .expect("Failed to send delete");
+    assert_eq!(response.status(), 200);
+    let api_response: ApiResponse<String> = response.json().await.expect("Failed to deserialize");
+    assert_eq!(api_response.data.as_deref(), Some(expected_message));
+
+    let remaining: Option<Event> = db
+        .select(event.id.clone())
+        .await
+        .expect("Failed to select event");
+    match remaining.filter(|remaining| remaining.deleted_at.is_none()) {
+        Some(remaining) => {
+            assert!(moves_to_next_occurrence, "The event should be deleted");
+            assert_eq!(remaining.date, event_date + Duration::weeks(1));
+        }
+        None => assert!(!moves_to_next_occurrence, "The series should be kept"),
+    }
+}
+
+/// The personal view a member gets of the mosque's upcoming events.
+async fn fetch_member_view(
+    client: &Client,
+    addr: &str,
+    session: &str,
+    mosque: &MosqueRecord,
+) -> Vec<PersonalEvent> {
+    let fetched = fetch_mosque_events_in(client, addr, session, mosque, None)
+        .await
+        .json::<ApiResponse<EventPage<FetchedEvents>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No events returned")
+        .events;
+    let FetchedEvents::Personal(events) = fetched else {
+        panic!("Members should get their personal view");
+    };
+    events
+}
+
+#[tokio::test]
+async fn test_deleted_event_is_hidden_until_an_admin_restores_it() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (admin, admin_session) = setup_user_and_session(&db).await;
+    let (member, member_session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+    make_mosque_admin(&db, &admin, &mosque).await;
+
+    let event_date = Utc::now().fixed_offset() + Duration::days(2);
+    let event =
+        create_hosted_event_at(&db, &mosque.id, "Restorable Halaqah", event_date, None).await;
+    db.query("RELATE $user -> attending -> $event")
+        .bind(("user", member.id.clone()))
+        .bind(("event", event.id.clone()))
+        .await
+        .expect("Failed to RSVP")
+        .check()
+        .expect("Failed to RSVP");
+
+    let response = build_auth_delete(
+        &client,
+        &admin_session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/delete/", addr),
+    )
+    .query(&DeleteEventParams {
+        event_id: event.id.to_string(),
+        delete_scope: None,
+    })
+    .send()
+    .await
+    .expect("Failed to send delete");
+    assert_eq!(response.status(), 200);
+    assert!(
+        fetch_member_view(&client, &addr, &member_session, &mosque)
+            .await
+            .is_empty(),
+        "Deleted events should not be fetched"
+    );
+
+    let restore = |session: &str| {
+        build_auth_headers(
+            &client,
+            session,
+            AuthMethod::Mobile,
+            &format!("{}/mosques/events/restore", addr),
+        )
+        .json(&RestoreEventParams {
+            event_id: event.id.to_string(),
+        })
+        .send()
+    };
+    let response = restore(&member_session)
+        .await
+        .expect("Failed to send restore");
+    assert_eq!(response.status(), 403);
+
+    let response = restore(&admin_session)
+        .await
+        .expect("Failed to send restore");
+    assert_eq!(response.status(), 200);
+    let api_response: ApiResponse<String> = response.json().await.expect("Failed to deserialize");
+    assert_eq!(api_response.data.as_deref(), Some(messages::EVENT_RESTORED));
+
+    let events = fetch_member_view(&client, &addr, &member_session, &mosque).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event.title, "Restorable Halaqah");
+    assert!(events[0].rsvp, "The RSVP should survive the deletion");
+}
+
+#[rstest]
+#[case::upcoming_for_members(false, None, vec!["Tomorrow", "Rotated Weekly", "Next Week"])]
+#[case::upcoming_for_admins(true, Some(false), vec!["Tomorrow", "Rotated Weekly", "Next Week"])]
+#[case::with_past_events(false, Some(true), vec!["Last Week", "Tomorrow", "Rotated Weekly", "Next Week"])]
+#[tokio::test]
+async fn test_fetch_mosque_events_lists_upcoming_events_in_order(
+    #[case] is_admin: bool,
+    #[case] include_past: Option<bool>,
+    #[case] expected_titles: Vec<&str>,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+    if is_admin {
+        make_mosque_admin(&db, &user, &mosque).await;
+    }
+
+    let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+    create_hosted_event_at(&db, &mosque.id, "Next Week", now + Duration::days(7), None).await;
+    create_hosted_event_at(&db, &mosque.id, "Last Week", now - Duration::days(7), None).await;
+    create_hosted_event_at(&db, &mosque.id, "Tomorrow", now + Duration::days(1), None).await;
+    let weekly = create_hosted_event_at(
+        &db,
+        &mosque.id,
+        "Rotated Weekly",
+        now - Duration::days(3),
+        Some(EventRecurrence::Weekly),
+    )
+    .await;
+    assert!(
+        rotate_event(weekly, RotationMode::default(), &db)
+            .await
+            .expect("Failed to rotate event")
+    );
+
+    let response = build_auth_headers(
+        &client,
+        &session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/fetch-mosque-events", addr),
+    )
+    .json(&FetchMosqueEventsParams {
+        mosque_id: mosque.id.to_string(),
+        include_past,
+        as_timezone: None,
+        after: None,
+        limit: None,
+    })
+    .send()
+    .await
+    .expect("Failed to fetch events");
+    assert_eq!(response.status(), 200);
+
+    let fetched = response
+        .json::<ApiResponse<EventPage<FetchedEvents>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No events returned")
+        .events;
+    let titles: Vec<String> = match fetched {
+        FetchedEvents::Summary(events) => {
+            assert!(is_admin, "Members should get their personal view");
+            events.into_iter().map(|e| e.event.title).collect()
+        }
+        FetchedEvents::Personal(events) => {
+            assert!(!is_admin, "Admins should get the summary view");
+            events.into_iter().map(|e| e.event.title).collect()
+        }
+    };
+    assert_eq!(titles, expected_titles);
+}
+
+#[derive(Debug, Clone, Copy)]
+enum EventCaller {
+    Anonymous,
+    Creator,
+    MosqueAdmin,
+    AppAdmin,
+    Stranger,
+}
+
+#[rstest]
+#[case::anonymous(EventCaller::Anonymous, 401)]
+#[case::creator(EventCaller::Creator, 200)]
+#[case::mosque_admin(EventCaller::MosqueAdmin, 200)]
+#[case::app_admin(EventCaller::AppAdmin, 200)]
+#[case::stranger(EventCaller::Stranger, 403)]
+#[tokio::test]
+async fn test_only_event_managers_can_update_and_delete_events(
+    #[case] caller: EventCaller,
+    #[case] expected_status: u16,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_creator, creator_session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+
+    let event_date =
+        Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) + Duration::days(7);
+    let create_event = CreateEvent {
+        title: format!("Managed Event {}", uuid::Uuid::new_v4()),
+        description: "Only its managers may change it".to_string(),
+        category: EventCategory::Lecture,
+        date: event_date,
+        mosque: mosque.id.to_string(),
+        speaker: None,
+        recurrence_pattern: None,
+        recurrence_duration: None,
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
+    };
+    let title = create_event.title.clone();
+    let _ = create_event_via_api(
+        &client,
+        &addr,
+        &creator_session,
+        AuthMethod::Mobile,
+        create_event,
+    )
+    .await;
+
+    let events: Vec<Event> = db
+        .query("SELECT * FROM events WHERE title = $title")
+        .bind(("title", title))
+        .await
+        .expect("Failed to query events")
+        .take(0)
+        .expect("Take failed");
+    let event_id = events[0].id.clone();
+
+    let session = match caller {
+        EventCaller::Anonymous => None,
+        EventCaller::Creator => Some(creator_session),
+        EventCaller::MosqueAdmin => {
+            let (admin, session) = setup_user_and_session(&db).await;
+            make_mosque_admin(&db, &admin, &mosque).await;
+            Some(session)
+        }
+        EventCaller::AppAdmin => Some(setup_user_with_role(&db, "app_admin").await.1),
+        EventCaller::Stranger => Some(setup_user_and_session(&db).await.1),
+    };
+
+    let update_url = format!("{}/mosques/events/update-event", addr);
+    let update_request = match &session {
+        Some(session) => build_auth_patch(&client, session, AuthMethod::Mobile, &update_url),
+        None => client.patch(&update_url),
+    };
+    let response = update_request
+        .json(&UpdateEventParams {
+            event_id: event_id.to_string(),
+            updated_event: UpdatedEvent {
+                title: Some("Renamed Event".to_string()),
+                description: None,
+                category: None,
+                date: None,
+                mosque: None,
+                speaker: None,
+                recurrence_pattern: None,
+                recurrence_end_date: None,
+            },
+        })
+        .send()
+        .await
+        .expect("Failed to send update");
+    assert_eq!(response.status().as_u16(), expected_status);
+
+    let event: Event = db
+        .select(event_id.clone())
+        .await
+        .expect("Failed to select event")
+        .expect("Event not found");
+    assert_eq!(event.title == "Renamed Event", expected_status == 200);
+
+    let delete_url = format!("{}/mosques/events/delete/", addr);
+    let delete_request = match &session {
+        Some(session) => build_auth_delete(&client, session, AuthMethod::Mobile, &delete_url),
+        None => client.delete(&delete_url),
+    };
+    let response = delete_request
+        .query(&DeleteEventParams {
+            event_id: event_id.to_string(),
+            delete_scope: None,
+        })
+        .send()
+        .await
+        .expect("Failed to send delete");
+    assert_eq!(response.status().as_u16(), expected_status);
+
+    let remaining: Option<Event> = db.select(event_id).await.expect("Failed to select event");
+    let deleted = remaining
+        .expect("Deleted events should be kept")
+        .deleted_at
+        .is_some();
+    assert_eq!(deleted, expected_status == 200);
+}
+
+#[rstest]
+#[case::zero(0, "The number of occurrences must be at least 1")]
+#[case::too_many(MAX_OCCURRENCES + 1, "The number of occurrences must be at most 1000")]
+#[tokio::test]
+async fn test_create_event_rejects_invalid_occurrence_counts(
+    #[case] occurrences: u32,
+    #[case] expected_error: &str,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+
+    let create_event = CreateEvent {
+        title: format!("Counted Event {}", uuid::Uuid::new_v4()),
+        description: "An event repeated a set number of times".to_string(),
+        category: EventCategory::Community,
+        date: Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) + Duration::days(1),
+        mosque: mosque.id.to_string(),
+        speaker: None,
+        recurrence_pattern: Some(EventRecurrence::Daily),
+        recurrence_duration: Some(Interval::Count(occurrences)),
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
+    };
+
+    let url = format!("{}/mosques/events/add-event", addr);
+    let response = build_auth_headers(&client, &session, AuthMethod::Mobile, &url)
+        .json(&AddEventParams { create_event })
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status().as_u16(), 422);
+
+    let api_response: ApiResponse<String> = response.json().await.expect("Failed to deserialize");
+    assert_eq!(api_response.error.as_deref(), Some(expected_error));
+}
+
+#[rstest]
+#[case::one_off(None, None)]
+#[case::recurring(Some(EventRecurrence::Weekly), Some(Interval::ThreeMonths))]
+#[tokio::test]
+async fn test_create_event_in_the_past_is_rejected(
+    #[case] recurrence_pattern: Option<EventRecurrence>,
+    #[case] recurrence_duration: Option<Interval>,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+
+    let event_date =
+        Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) - Duration::days(1);
+    let title = format!("Past Event {}", uuid::Uuid::new_v4());
+    let create_event = CreateEvent {
+        title: title.clone(),
+        description: "This event has already happened".to_string(),
+        category: EventCategory::Community,
+        date: event_date,
+        mosque: mosque.id.to_string(),
+        speaker: None,
+        recurrence_pattern,
+        recurrence_duration,
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
+    };
+
+    let url = format!("{}/mosques/events/add-event", addr);
+    let response = build_auth_headers(&client, &session, AuthMethod::Mobile, &url)
+        .json(&AddEventParams { create_event })
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status().as_u16(), 422);
+
+    let events: Vec<Event> = db
+        .query("SELECT * FROM events WHERE title = $title")
+        .bind(("title", title))
+        .await
+        .expect("Failed to query events")
+        .take(0)
+        .expect("Take failed");
+    assert!(events.is_empty(), "The past event should not be created");
+}
+
+#[tokio::test]
+async fn test_update_event_without_fields_is_rejected() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+    make_mosque_admin(&db, &user, &mosque).await;
+    let event = create_hosted_event(&db, &mosque.id, "Unchanged Event").await;
+
+    let update_url = format!("{}/mosques/events/update-event", addr);
+    let response = build_auth_patch(&client, &session, AuthMethod::Mobile, &update_url)
+        .json(&UpdateEventParams {
+            event_id: event.id.to_string(),
+            updated_event: UpdatedEvent {
+                title: None,
+                description: None,
+                category: None,
+                date: None,
+                mosque: None,
+                speaker: None,
+                recurrence_pattern: None,
+                recurrence_end_date: None,
+            },
+        })
+        .send()
+        .await
+        .expect("Failed to send update");
+    assert_eq!(response.status().as_u16(), 400);
+    let api_response: ApiResponse<String> = response.json().await.expect("Failed to deserialize");
+    assert_eq!(api_response.error.as_deref(), Some("No fields to update"));
+
+    let unchanged: Event = db
+        .select(event.id)
+        .await
+        .expect("Failed to select event")
+        .expect("Event not found");
+    assert_eq!(unchanged.title, "Unchanged Event");
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DateChange {
+    DatePastTheEnd,
+    EndBeforeTheDate,
+    DateInThePast,
+    DateWithinTheSeries,
+}
+
+#[rstest]
+#[case::date_past_the_end(DateChange::DatePastTheEnd, 422)]
+#[case::end_before_the_date(DateChange::EndBeforeTheDate, 422)]
+#[case::date_in_the_past(DateChange::DateInThePast, 422)]
+#[case::date_within_the_series(DateChange::DateWithinTheSeries, 200)]
+#[tokio::test]
+async fn test_update_event_keeps_the_date_within_the_series(
+    #[case] change: DateChange,
+    #[case] expected_status: u16,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+    make_mosque_admin(&db, &user, &mosque).await;
+
+    let event_date =
+        Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) + Duration::days(3);
+    let event = create_hosted_event_at(
+        &db,
+        &mosque.id,
+        "Bounded Series",
+        event_date,
+        Some(EventRecurrence::Weekly),
+    )
+    .await;
+    let end_date = event
+        .recurrence_end_date
+        .expect("The series should have an end date");
+
+    let (date, recurrence_end_date) = match change {
+        DateChange::DatePastTheEnd => (Some(end_date + Duration::days(1)), None),
+        DateChange::EndBeforeTheDate => (None, Some(event_date - Duration::days(1))),
+        DateChange::DateInThePast => (Some(event_date - Duration::days(7)), None),
+        DateChange::DateWithinTheSeries => (Some(event_date + Duration::days(1)), None),
+    };
+
+    let update_url = format!("{}/mosques/events/update-event", addr);
+    let response = build_auth_patch(&client, &session, AuthMethod::Mobile, &update_url)
+        .json(&UpdateEventParams {
+            event_id: event.id.to_string(),
+            updated_event: UpdatedEvent {
+                title: None,
+                description: None,
+                category: None,
+                date,
+                mosque: None,
+                speaker: None,
+                recurrence_pattern: None,
+                recurrence_end_date,
+            },
+        })
+        .send()
+        .await
+        .expect("Failed to send update");
+    assert_eq!(response.status().as_u16(), expected_status);
+
+    let stored: Event = db
+        .select(event.id)
+        .await
+        .expect("Failed to select event")
+        .expect("Event not found");
+    let expected_date = match expected_status {
+        200 => date.unwrap_or(event_date),
+        _ => event_date,
+    };
+    assert_eq!(stored.date, expected_date);
+    assert_eq!(stored.recurrence_end_date, Some(end_date));
+}
+
+#[tokio::test]
+async fn test_rotation_catches_up_on_missed_occurrences() {
+    let db = get_test_db().await;
+    let mosque = setup_mosque(&db).await;
+
+    let past_date = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap())
+        - Duration::weeks(5)
+        - Duration::days(1);
+    let event: Event = db
+        .create("events")
+        .content(EventRecord {
+            title: "Long Overdue Halaqah".to_string(),
+            description: "The scheduler missed five of these".to_string(),
+            category: EventCategory::Halaqah,
+            date: past_date,
+            mosque: mosque.id.clone(),
+            speaker: None,
+            recurrence_pattern: Some(EventRecurrence::Weekly),
+            recurrence_end_date: Some(past_date + Duration::days(365)),
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: None,
+        })
+        .await
+        .expect("Failed to create event")
+        .expect("Not returned");
+
+    let rotated = rotate_event(event.clone(), RotationMode::default(), &db)
+        .await
+        .expect("Failed to rotate event");
+    assert!(rotated);
+
+    let rotated_event: Event = db
+        .select(event.id)
+        .await
+        .expect("Failed to select event")
+        .expect("Event not found");
+    assert_eq!(rotated_event.date, past_date + Duration::weeks(6));
+    assert!(rotated_event.date > Utc::now());
+    assert_eq!(rotated_event.occurrences_rotated, 6);
+}
+
+#[rstest]
+#[case::app_admin("app_admin", 200)]
+#[case::regular_user("regular", 403)]
+#[tokio::test]
+async fn test_trigger_event_rotation_endpoint(#[case] role: &str, #[case] expected_status: u16) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_with_role(&db, role).await;
+    let mosque = setup_mosque(&db).await;
+
+    let past_date =
+        Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) - Duration::days(1);
+    let event = create_hosted_event_at(
+        &db,
+        &mosque.id,
+        "Overdue Weekly Event",
+        past_date,
+        Some(EventRecurrence::Weekly),
+    )
+    .await;
+
+    let url = format!("{}/mosques/events/trigger-rotation", addr);
+    let response = build_auth_headers(&client, &session, AuthMethod::Mobile, &url)
+        .json(&NoArgs {})
+        .send()
+        .await
+        .expect("Failed to trigger the rotation");
+    assert_eq!(response.status().as_u16(), expected_status);
+
+    let stored: Event = db
+        .select(event.id)
+        .await
+        .expect("Failed to select event")
+        .expect("Event not found");
+    if expected_status == 200 {
+        let api_response: ApiResponse<usize> =
+            response.json().await.expect("Failed to deserialize");
+        assert_eq!(api_response.data, Some(1));
+        assert_eq!(stored.date, past_date + Duration::weeks(1));
+    } else {
+        assert_eq!(stored.date, past_date);
+    }
+}
+
+async fn fetch_mosque_events_in(
+    client: &Client,
+    addr: &str,
+    session: &str,
+    mosque: &MosqueRecord,
+    as_timezone: Option<&str>,
+) -> reqwest::Response {
+    build_auth_headers(
+        client,
+        session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/fetch-mosque-events", addr),
+    )
+    .json(&FetchMosqueEventsParams {
+        mosque_id: mosque.id.to_string(),
+        include_past: None,
+        as_timezone: as_timezone.map(str::to_string),
+        after: None,
+        limit: None,
+    })
+    .send()
+    .await
+    .expect("Failed to fetch events")
+}
+
+#[rstest]
+#[case::mosque_zone(None, Detroit)]
+#[case::requested_zone(Some("Asia/Kolkata"), Kolkata)]
+#[tokio::test]
+async fn test_fetched_event_dates_carry_the_mosque_offset(
+    #[case] as_timezone: Option<&str>,
+    #[case] expected_zone: Tz,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+    db.query("UPDATE $mosque SET timezone = 'America/Detroit'")
+        .bind(("mosque", mosque.id.clone()))
+        .await
+        .expect("Failed to set the mosque's timezone")
+        .check()
+        .expect("Failed to set the mosque's timezone");
+
+    let event_date = (Utc::now() + Duration::days(3))
+        .with_timezone(&Detroit)
+        .fixed_offset();
+    create_hosted_event_at(&db, &mosque.id, "Local Time Halaqah", event_date, None).await;
+
+    let response = fetch_mosque_events_in(&client, &addr, &session, &mosque, as_timezone).await;
+    assert_eq!(response.status(), 200);
+
+    let fetched = response
+        .json::<ApiResponse<EventPage<FetchedEvents>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No events returned")
+        .events;
+    let FetchedEvents::Personal(events) = fetched else {
+        panic!("Members should get their personal view");
+    };
+    let event = &events[0].event;
+
+    assert_eq!(event.timezone.as_deref(), Some("America/Detroit"));
+    let expected_date = event_date.with_timezone(&expected_zone).fixed_offset();
+    assert_eq!(event.date, expected_date);
+    assert_eq!(event.date.offset(), expected_date.offset());
+}
+
+#[tokio::test]
+async fn test_fetch_mosque_events_pages_with_the_cursor() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+    let start = Utc::now().fixed_offset() + Duration::days(1);
+    let expected_titles: Vec<String> = (0..5).map(|day| format!("Halaqah {day}")).collect();
+    for (day, title) in expected_titles.iter().enumerate() {
+        let date = start + Duration::days(day as i64);
+        create_hosted_event_at(&db, &mosque.id, title, date, None).await;
+    }
+
+    let mut titles = Vec::new();
+    let mut after = None;
+    for expected_len in [3, 2] {
+        let page = build_auth_headers(
+            &client,
+            &session,
+            AuthMethod::Mobile,
+            &format!("{}/mosques/events/fetch-mosque-events", addr),
+        )
+        .json(&FetchMosqueEventsParams {
+            mosque_id: mosque.id.to_string(),
+            include_past: None,
+            as_timezone: None,
+            after,
+            limit: Some(3),
+        })
+        .send()
+        .await
+        .expect("Failed to fetch events")
+        .json::<ApiResponse<EventPage<FetchedEvents>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No events returned");
+
+        let FetchedEvents::Personal(events) = page.events else {
+            panic!("Members should get their personal view");
+        };
+        assert_eq!(events.len(), expected_len);
+        titles.extend(events.into_iter().map(|personal| personal.event.title));
+        after = page.next_cursor;
+    }
+
+    assert_eq!(
+        titles, expected_titles,
+        "Pages should neither repeat nor skip events"
+    );
+    assert!(after.is_none(), "The last page should have no cursor");
+}
+
+#[tokio::test]
+async fn test_favorite_feed_pages_through_events_sharing_a_date() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (user, session) = setup_user_and_session(&db).await;
+    let favorite_mosque = setup_mosque_at(&db, 0.0, 0.0, "Favorite Mosque").await;
+    let nearby_mosque = setup_mosque_at(&db, 0.01, 0.01, "Nearby Mosque").await;
+    db.query("RELATE $user -> favorited -> $mosque")
+        .bind(("user", user.id.clone()))
+        .bind(("mosque", favorite_mosque.id.clone()))
+        .await
+        .expect("Failed to favorite mosque");
+
+    let date = Utc::now().fixed_offset() + Duration::days(1);
+    let mut expected_ids = Vec::new();
+    for (mosque, title) in [
+        (&favorite_mosque, "Favorite Halaqah"),
+        (&favorite_mosque, "Favorite Lecture"),
+        (&nearby_mosque, "Nearby Halaqah"),
+        (&nearby_mosque, "Nearby Lecture"),
+    ] {
+        let event = create_hosted_event_at(&db, &mosque.id, title, date, None).await;
+        expected_ids.push(event.id.to_string());
+    }
+    expected_ids.sort();
+
+    let mut ids = Vec::new();
+    let mut after = None;
+    for expected_len in [3, 1] {
+        let page = build_auth_headers(
+            &client,
+            &session,
+            AuthMethod::Mobile,
+            &format!(
+                "{}/mosques/events/fetch-users-favorite-mosques-events",
+                addr
+            ),
+        )
+        .json(&FetchUsersFavoriteMosquesEventsParams {
+            lat: 0.0,
+            lon: 0.0,
+            after,
+            limit: Some(3),
+        })
+        .send()
+        .await
+        .expect("Failed to fetch events")
+        .json::<ApiResponse<EventPage<Vec<PersonalEvent>>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No events returned");
+
+        assert_eq!(page.events.len(), expected_len);
+        ids.extend(page.events.into_iter().map(|personal| personal.event.id));
+        after = page.next_cursor;
+    }
+
+    assert_eq!(
+        ids, expected_ids,
+        "Events sharing a date should be paged in id order without repeats or gaps"
+    );
+    assert!(after.is_none(), "The last page should have no cursor");
+}
+
+#[tokio::test]
+async fn test_fetch_mosque_events_rejects_unknown_timezones() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+
+    let response =
+        fetch_mosque_events_in(&client, &addr, &session, &mosque, Some("Mars/Olympus_Mons")).await;
+    assert_eq!(response.status(), 422);
+}
+
+#[tokio::test]
+async fn test_fetch_events_for_mosques_groups_events_by_mosque() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+    let lucknow = setup_mosque_at(&db, 26.85, 80.95, "Lucknow Mosque").await;
+    let delhi = setup_mosque_at(&db, 28.61, 77.21, "Delhi Mosque").await;
+    let mumbai = setup_mosque_at(&db, 19.08, 72.88, "Mumbai Mosque").await;
+
+    let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+    create_hosted_event_at(
+        &db,
+        &lucknow.id,
+        "Lucknow Past",
+        now - Duration::days(2),
+        None,
+    )
+    .await;
+    create_hosted_event_at(
+        &db,
+        &lucknow.id,
+        "Lucknow Later",
+        now + Duration::days(5),
+        None,
+    )
+    .await;
+    create_hosted_event_at(
+        &db,
+        &lucknow.id,
+        "Lucknow Soon",
+        now + Duration::days(1),
+        None,
+    )
+    .await;
+    create_hosted_event_at(
+        &db,
+        &lucknow.id,
+        "Lucknow Next Month",
+        now + Duration::days(30),
+        None,
+    )
+    .await;
+    create_hosted_event_at(&db, &delhi.id, "Delhi Soon", now + Duration::days(2), None).await;
+    create_hosted_event_at(
+        &db,
+        &mumbai.id,
+        "Mumbai Soon",
+        now + Duration::days(2),
+        None,
+    )
+    .await;
+
+    let response = build_auth_headers(
+        &client,
+        &session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/fetch-events-for-mosques", addr),
+    )
+    .json(&FetchEventsForMosquesParams {
+        mosque_ids: vec![delhi.id.to_string(), lucknow.id.to_string()],
+        from: None,
+        to: Some(now + Duration::days(7)),
+    })
+    .send()
+    .await
+    .expect("Failed to fetch events");
+    assert_eq!(response.status(), 200);
+
+    let fetched: Vec<MosqueEvents> = response
+        .json::<ApiResponse<Vec<MosqueEvents>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No events returned");
+    let grouped: Vec<(String, Vec<String>)> = fetched
+        .into_iter()
+        .map(|mosque| {
+            let titles = mosque.events.into_iter().map(|e| e.event.title).collect();
+            (mosque.mosque_id, titles)
+        })
+        .collect();
+
+    assert_eq!(
+        grouped,
+        vec![
+            (delhi.id.to_string(), vec!["Delhi Soon".to_string()]),
+            (
+                lucknow.id.to_string(),
+                vec!["Lucknow Soon".to_string(), "Lucknow Later".to_string()]
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_events_for_mosques_rejects_unknown_mosques() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+
+    let response = build_auth_headers(
+        &client,
+        &session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/fetch-events-for-mosques", addr),
+    )
+    .json(&FetchEventsForMosquesParams {
+        mosque_ids: vec![mosque.id.to_string(), "mosques:missing".to_string()],
+        from: None,
+        to: None,
+    })
+    .send()
+    .await
+    .expect("Failed to fetch events");
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_fetch_my_rsvp_events_lists_rsvps_across_mosques() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+    let lucknow = setup_mosque_at(&db, 26.85, 80.95, "Lucknow Mosque").await;
+    let delhi = setup_mosque_at(&db, 28.61, 77.21, "Delhi Mosque").await;
+
+    let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let lucknow_event = create_hosted_event_at(
+        &db,
+        &lucknow.id,
+        "Lucknow Talk",
+        now + Duration::days(4),
+        None,
+    )
+    .await;
+    let delhi_event =
+        create_hosted_event_at(&db, &delhi.id, "Delhi Talk", now + Duration::days(2), None).await;
+    create_hosted_event_at(
+        &db,
+        &delhi.id,
+        "Delhi Skipped",
+        now + Duration::days(3),
+        None,
+    )
+    .await;
+
+    for event in [&lucknow_event, &delhi_event] {
+        let response = build_auth_headers(
+            &client,
+            &session,
+            AuthMethod::Mobile,
+            &format!("{}/mosques/events/rsvp", addr),
+        )
+        .json(&RsvpParams {
+            event_id: event.id.to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to send RSVP");
+        assert_eq!(response.status(), 200);
+    }
+
+    let response = build_auth_headers(
+        &client,
+        &session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/fetch-my-rsvp-events", addr),
+    )
+    .json(&EventWindowParams {
+        from: None,
+        to: None,
+    })
+    .send()
+    .await
+    .expect("Failed to fetch RSVP'd events");
+    assert_eq!(response.status(), 200);
+
+    let events: Vec<(String, Option<String>)> = response
+        .json::<ApiResponse<Vec<RsvpEvent>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No events returned")
+        .into_iter()
+        .map(|rsvp| (rsvp.event.title, rsvp.mosque_name))
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            ("Delhi Talk".to_string(), Some("Delhi Mosque".to_string())),
+            (
+                "Lucknow Talk".to_string(),
+                Some("Lucknow Mosque".to_string())
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_favorite_events_feed_survives_a_deleted_favorite_mosque() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (user, session) = setup_user_and_session(&db).await;
+    let kept_mosque = setup_mosque_at(&db, 10.0, 10.0, "Kept Mosque").await;
+    let deleted_mosque = setup_mosque_at(&db, 12.0, 12.0, "Deleted Mosque").await;
+
+    for mosque in [&kept_mosque, &deleted_mosque] {
+        db.query("RELATE $user -> favorited -> $mosque")
+            .bind(("user", user.id.clone()))
+            .bind(("mosque", mosque.id.clone()))
+            .await
+            .expect("Failed to favorite mosque")
+            .check()
+            .expect("Failed to favorite mosque");
+    }
+
+    let kept_event = create_hosted_event(&db, &kept_mosque.id, "Kept Event").await;
+    create_hosted_event(&db, &deleted_mosque.id, "Orphaned Event").await;
+
+    db.query("DELETE $mosque")
+        .bind(("mosque", deleted_mosque.id.clone()))
+        .await
+        .expect("Failed to delete the mosque")
+        .check()
+        .expect("Failed to delete the mosque");
+
+    let response = build_auth_headers(
+        &client,
+        &session,
+        AuthMethod::Mobile,
+        &format!(
+            "{}/mosques/events/fetch-users-favorite-mosques-events",
+            addr
+        ),
+    )
+    .json(&FetchUsersFavoriteMosquesEventsParams {
+        lat: 0.0,
+        lon: 0.0,
+        after: None,
+        limit: None,
+    })
+    .send()
+    .await
+    .expect("Failed to fetch favorite events");
+    assert_eq!(response.status(), 200);
+
+    let event_ids: Vec<String> = response
+        .json::<ApiResponse<EventPage<Vec<PersonalEvent>>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No events returned")
+        .events
+        .into_iter()
+        .map(|personal| personal.event.id)
+        .collect();
+    assert_eq!(event_ids, vec![kept_event.id.to_string()]);
+}
+
+/// Sets up a mosque hosting an event tomorrow, one next month and one last week, with the
+/// returned user RSVP'd to the one tomorrow.
+async fn setup_public_mosque_events(
+    db: &surrealdb::Surreal<surrealdb::engine::remote::ws::Client>,
+) -> (MosqueRecord, String) {
+    let (user, session) = setup_user_and_session(db).await;
+    let mosque = setup_mosque(db).await;
+    let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let tomorrow =
+        create_hosted_event_at(db, &mosque.id, "Tomorrow", now + Duration::days(1), None).await;
+    create_hosted_event_at(db, &mosque.id, "Next Month", now + Duration::days(30), None).await;
+    create_hosted_event_at(db, &mosque.id, "Last Week", now - Duration::days(7), None).await;
+    db.query("RELATE $user -> attending -> $event")
+        .bind(("user", user.id))
+        .bind(("event", tomorrow.id))
+        .await
+        .expect("Failed to RSVP")
+        .check()
+        .expect("Failed to RSVP");
+    (mosque, session)
+}
+
+async fn fetch_public_mosque_events(
+    request: reqwest::RequestBuilder,
+    mosque: &MosqueRecord,
+) -> Vec<PublicEvent> {
+    let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let response = request
+        .query(&FetchPublicMosqueEventsParams {
+            mosque_id: mosque.id.to_string(),
+            from: None,
+            to: Some(now + Duration::days(7)),
+        })
+        .send()
+        .await
+        .expect("Failed to fetch events");
+    assert_eq!(response.status(), 200);
+
+    response
+        .json::<ApiResponse<Vec<PublicEvent>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No events returned")
+}
+
+#[tokio::test]
+async fn test_fetch_public_mosque_events_without_a_session() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let (mosque, _session) = setup_public_mosque_events(&db).await;
+
+    let events = fetch_public_mosque_events(
+        client.get(format!(
+            "{}/mosques/events/fetch-public-mosque-events",
+            addr
+        )),
+        &mosque,
+    )
+    .await;
+
+    let titles: Vec<String> = events.iter().map(|e| e.event.title.clone()).collect();
+    assert_eq!(titles, vec!["Tomorrow".to_string()]);
+    assert!(events.iter().all(|e| e.rsvp.is_none()));
+}
+
+#[rstest]
+#[case::web(AuthMethod::Web)]
+#[case::mobile(AuthMethod::Mobile)]
+#[tokio::test]
+async fn test_fetch_public_mosque_events_includes_the_rsvp_when_logged_in(
+    #[case] auth_method: AuthMethod,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let (mosque, session) = setup_public_mosque_events(&db).await;
+
+    let events = fetch_public_mosque_events(
+        build_auth_get(
+            &client,
+            &session,
+            auth_method,
+            &format!("{}/mosques/events/fetch-public-mosque-events", addr),
+        ),
+        &mosque,
+    )
+    .await;
+
+    let rsvps: Vec<(String, Option<bool>)> = events
+        .into_iter()
+        .map(|e| (e.event.title, e.rsvp))
+        .collect();
+    assert_eq!(rsvps, vec![("Tomorrow".to_string(), Some(true))]);
+}
+
+#[rstest]
+#[case::anonymous(false, PUBLIC_CACHE_CONTROL, Some(PUBLIC_CACHE_VARY))]
+#[case::logged_in(true, PRIVATE_CACHE_CONTROL, None)]
+#[tokio::test]
+async fn test_public_mosque_events_are_only_shared_with_caches_when_anonymous(
+    #[case] logged_in: bool,
+    #[case] expected_cache_control: &str,
+    #[case] expected_vary: Option<&str>,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let (mosque, session) = setup_public_mosque_events(&db).await;
+
+    let url = format!("{}/mosques/events/fetch-public-mosque-events", addr);
+    let request = if logged_in {
+        build_auth_get(&client, &session, AuthMethod::Mobile, &url)
+    } else {
+        client.get(&url)
+    };
+    let response = request
+        .query(&FetchPublicMosqueEventsParams {
+            mosque_id: mosque.id.to_string(),
+            from: None,
+            to: None,
+        })
+        .send()
+        .await
+        .expect("Failed to fetch events");
+    assert_eq!(response.status(), 200);
+
+    let cache_control = response
+        .headers()
+        .get("cache-control")
+        .and_then(|value| value.to_str().ok());
+    assert_eq!(cache_control, Some(expected_cache_control));
+    let vary = response
+        .headers()
+        .get("vary")
+        .and_then(|value| value.to_str().ok());
+    assert_eq!(vary, expected_vary);
+}
+
+#[tokio::test]
+async fn test_fetch_public_mosque_events_ignores_an_expired_session() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let (mosque, session) = setup_public_mosque_events(&db).await;
+    db.query("DELETE sessions WHERE session_token = $session")
+        .bind(("session", session.clone()))
+        .await
+        .expect("Failed to end the session");
+
+    let events = fetch_public_mosque_events(
+        build_auth_get(
+            &client,
+            &session,
+            AuthMethod::Mobile,
+            &format!("{}/mosques/events/fetch-public-mosque-events", addr),
+        ),
+        &mosque,
+    )
+    .await;
+
+    assert_eq!(events.len(), 1);
+    assert!(events[0].rsvp.is_none());
+}
+
+#[tokio::test]
+async fn test_mosque_event_category_counts_cover_upcoming_events() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let mosque = setup_mosque(&db).await;
+    let other_mosque = setup_mosque(&db).await;
+
+    let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+    for (mosque_id, title, days_from_now, category) in [
+        (&mosque.id, "Halaqah One", 1, EventCategory::Halaqah),
+        (&mosque.id, "Halaqah Two", 8, EventCategory::Halaqah),
+        (&mosque.id, "Past Halaqah", -7, EventCategory::Halaqah),
+        (&mosque.id, "Iftar", 3, EventCategory::Iftar),
+        (&mosque.id, "Community", 5, EventCategory::Community),
+        (&other_mosque.id, "Elsewhere", 2, EventCategory::Iftar),
+    ] {
+        let event = create_hosted_event_at(
+            &db,
+            mosque_id,
+            title,
+            now + Duration::days(days_from_now),
+            None,
+        )
+        .await;
+        db.query("UPDATE $event SET category = $category")
+            .bind(("event", event.id))
+            .bind(("category", category))
+            .await
+            .expect("Failed to set the category")
+            .check()
+            .expect("Failed to set the category");
+    }
+
+    let response = client
+        .get(format!("{}/mosques/events/event-category-counts", addr))
+        .query(&MosqueIdParams {
+            mosque_id: mosque.id.to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to fetch the category counts");
+    assert_eq!(response.status(), 200);
+
+    let counts = response
+        .json::<ApiResponse<Vec<EventCategoryCount>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No counts returned");
+    assert_eq!(
+        counts,
+        vec![
+            EventCategoryCount {
+                category: EventCategory::Halaqah,
+                count: 2,
+            },
+            EventCategoryCount {
+                category: EventCategory::Community,
+                count: 1,
+            },
+            EventCategoryCount {
+                category: EventCategory::Iftar,
+                count: 1,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_add_event_db_failure_returns_a_generic_message() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+
+    db.query(
+        r#"DEFINE EVENT reject_events ON TABLE events WHEN $event = "CREATE" THEN {
+            THROW "raw database detail";
+        }"#,
+    )
+    .await
+    .expect("Failed to define the event")
+    .check()
+    .expect("Failed to define the event");
+
+    let create_event = CreateEvent {
+        title: "Doomed Lecture".to_string(),
+        description: "This event is never saved.".to_string(),
+        category: EventCategory::Lecture,
+        date: Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) + Duration::days(7),
+        mosque: mosque.id.to_string(),
+        speaker: None,
+        recurrence_pattern: None,
+        recurrence_duration: None,
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
+    };
+
+    let response = build_auth_headers(
+        &client,
+        &session,
+        AuthMethod::Mobile,
+        &format!("{}/mosques/events/add-event", addr),
+    )
+    .json(&AddEventParams { create_event })
+    .send()
+    .await
+    .expect("Failed to send request");
+    assert_eq!(response.status(), 500);
+
+    let error = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize")
+        .error
+        .expect("No error returned");
+    assert!(!error.contains("raw database detail"), "Leaked: {error}");
+    assert!(
+        !error.to_lowercase().contains("db error"),
+        "Leaked: {error}"
+    );
+}
+
+#[derive(serde::Deserialize)]
+struct HostsEdge {
+    #[serde(rename = "in")]
+    mosque: RecordId,
+}
+
+#[tokio::test]
+async fn test_hosts_relation_points_to_the_event_mosque() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (user, session) = setup_user_and_session(&db).await;
+    let mosque = setup_mosque(&db).await;
+
+    let event_date =
+        Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) + Duration::days(7);
+    let event = |mosque: String| CreateEvent {
+        title: "Hosted Lecture".to_string(),
+        description: "Checks who hosts the event.".to_string(),
+        category: EventCategory::Lecture,
+        date: event_date,
+        mosque,
+        speaker: None,
+        recurrence_pattern: None,
+        recurrence_duration: None,
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
+    };
+
+    create_event_via_api(
+        &client,
+        &addr,
+        &session,
+        AuthMethod::Web,
+        event(mosque.id.to_string()),
+    )
+    .await;
+
+    let events: Vec<Event> = db
+        .query("SELECT * FROM events")
+        .await
+        .expect("Failed to query events")
+        .take(0)
+        .expect("Take failed");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].mosque, mosque.id);
+
+    let hosts: Vec<HostsEdge> = db
+        .query("SELECT in FROM hosts WHERE out = $event")
+        .bind(("event", events[0].id.clone()))
+        .await
+        .expect("Failed to query hosts")
+        .take(0)
+        .expect("Take failed");
+    assert_eq!(hosts.len(), 1);
+    assert_eq!(hosts[0].mosque, mosque.id);
+
+    for forged in ["mosques:forged".to_string(), user.id.to_string()] {
+        let response = build_auth_headers(
+            &client,
+            &session,
+            AuthMethod::Web,
+            &format!("{}/mosques/events/add-event", addr),
+        )
+        .json(&AddEventParams {
+            create_event: event(forged),
+        })
+        .send()
+        .await
+        .expect("Failed to send request");
+        assert_eq!(response.status(), 404);
+    }
+
+    let host_count: Option<usize> = db
+        .query("RETURN count(SELECT * FROM hosts)")
+        .await
+        .expect("Failed to count hosts")
+        .take(0)
+        .expect("Take failed");
+    assert_eq!(host_count, Some(1));
+}
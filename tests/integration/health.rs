@@ -0,0 +1,28 @@
+use crate::common::get_test_db;
+use merzah::{
+    models::{api_responses::ApiResponse, health::HealthStatus},
+    spawn_app,
+};
+use reqwest::Client;
+
+#[tokio::test]
+async fn test_health_check_reports_a_reachable_database() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db);
+
+    let response = Client::new()
+        .get(format!("{}/system/health", addr))
+        .send()
+        .await
+        .expect("Failed to send a request");
+    assert_eq!(response.status().as_u16(), 200);
+
+    let status = response
+        .json::<ApiResponse<HealthStatus>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No health status returned");
+    assert!(status.database_connected);
+    assert_eq!(status.version, env!("CARGO_PKG_VERSION"));
+}
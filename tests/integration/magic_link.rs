@@ -0,0 +1,125 @@
+use crate::common::{RecordingNotifier, get_test_db};
+use merzah::{
+    auth::{custom_auth::register_user, magic_link::request_magic_link},
+    models::{
+        api_responses::ApiResponse,
+        auth::{Platform, RegistrationFormData},
+        user::Identifier,
+    },
+    spawn_app,
+};
+use reqwest::Client;
+use serde::Serialize;
+use surrealdb::{Surreal, engine::remote::ws::Client as SurrealClient};
+
+const LINK_URL: &str = "http://localhost:3000/auth/magic-link";
+
+#[derive(Serialize)]
+struct MagicLinkLoginPayload {
+    token: String,
+    platform: Platform,
+}
+
+async fn register_email_user(db: &Surreal<SurrealClient>) -> String {
+    let email = format!("magic_{}@example.com", uuid::Uuid::new_v4());
+    let form = RegistrationFormData::new(
+        "Armaan Ali".to_string(),
+        Identifier::Email(email.clone()),
+        "thisisasecret".to_string(),
+        Platform::Web,
+    );
+    register_user(form, db)
+        .await
+        .expect("Failed to register user");
+    email
+}
+
+async fn login(client: &Client, addr: &str, token: &str, platform: Platform) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/magic-link-login", addr))
+        .json(&MagicLinkLoginPayload {
+            token: token.to_string(),
+            platform,
+        })
+        .send()
+        .await
+        .expect("Failed to send a request")
+}
+
+#[tokio::test]
+async fn test_magic_link_logs_in_once() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let notifier = RecordingNotifier::default();
+    let email = register_email_user(&db).await;
+
+    request_magic_link(&email, LINK_URL, &notifier, &db)
+        .await
+        .expect("Failed to request a magic link");
+    assert_eq!(notifier.emails.lock().unwrap()[0].0, email);
+    let token = notifier.last_email_token();
+
+    let response = login(&client, &addr, &token, Platform::Web).await;
+    assert_eq!(response.status().as_u16(), 200);
+    assert!(response.headers().get("set-cookie").is_some());
+
+    let response = login(&client, &addr, &token, Platform::Web).await;
+    assert_eq!(response.status().as_u16(), 401);
+}
+
+#[tokio::test]
+async fn test_magic_link_returns_a_session_token_on_mobile() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let notifier = RecordingNotifier::default();
+    let email = register_email_user(&db).await;
+
+    request_magic_link(&email, LINK_URL, &notifier, &db)
+        .await
+        .expect("Failed to request a magic link");
+
+    let response = login(
+        &client,
+        &addr,
+        &notifier.last_email_token(),
+        Platform::Mobile,
+    )
+    .await;
+    assert_eq!(response.status().as_u16(), 200);
+    assert!(response.headers().get("set-cookie").is_none());
+    let api_response: ApiResponse<String> = response.json().await.expect("Failed to deserialize");
+    assert!(api_response.data.is_some());
+}
+
+#[tokio::test]
+async fn test_expired_magic_link_is_rejected() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let notifier = RecordingNotifier::default();
+    let email = register_email_user(&db).await;
+
+    request_magic_link(&email, LINK_URL, &notifier, &db)
+        .await
+        .expect("Failed to request a magic link");
+    db.query("UPDATE magic_links SET expires_at = time::now() - 1m")
+        .await
+        .expect("Failed to expire the link");
+
+    let response = login(&client, &addr, &notifier.last_email_token(), Platform::Web).await;
+    assert_eq!(response.status().as_u16(), 401);
+    assert!(response.headers().get("set-cookie").is_none());
+}
+
+#[tokio::test]
+async fn test_magic_link_is_not_sent_to_unknown_emails() {
+    let db = get_test_db().await;
+    let notifier = RecordingNotifier::default();
+
+    request_magic_link("nobody@example.com", LINK_URL, &notifier, &db)
+        .await
+        .expect("Unknown emails should be ignored");
+    assert!(notifier.emails.lock().unwrap().is_empty());
+}
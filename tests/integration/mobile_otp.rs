@@ -0,0 +1,118 @@
+use crate::common::{RecordingNotifier, get_test_db};
+use merzah::{
+    auth::custom_auth::register_user,
+    errors::mobile_otp::MobileOtpError,
+    models::{
+        auth::{Platform, RegistrationFormData},
+        user::Identifier,
+    },
+    services::mobile_otp::{MOBILE_OTP_MAX_ATTEMPTS, request_mobile_otp, verify_mobile_otp},
+};
+use serde::Deserialize;
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
+
+#[derive(Deserialize)]
+struct Verified {
+    verified: bool,
+}
+
+async fn create_mobile_user(db: &Surreal<Client>) -> RecordId {
+    let form = RegistrationFormData::new(
+        "Armaan Ali".to_string(),
+        Identifier::Mobile("+91 1234567890".to_string()),
+        "thisisasecret".to_string(),
+        Platform::Mobile,
+    );
+    register_user(form, db)
+        .await
+        .expect("Failed to register user")
+}
+
+async fn is_mobile_verified(user: &RecordId, db: &Surreal<Client>) -> bool {
+    let verified: Option<Verified> = db
+        .query("SELECT verified FROM user_identifier WHERE user = $user")
+        .bind(("user", user.clone()))
+        .await
+        .expect("Failed to query the identifier")
+        .take(0)
+        .expect("Failed to read the identifier");
+    verified.expect("Identifier not found").verified
+}
+
+fn wrong_code(code: &str) -> String {
+    let last_digit = code.chars().last().unwrap().to_digit(10).unwrap();
+    format!("{}{}", &code[..5], (last_digit + 1) % 10)
+}
+
+#[tokio::test]
+async fn test_correct_code_verifies_the_mobile() {
+    let db = get_test_db().await;
+    let user = create_mobile_user(&db).await;
+    let notifier = RecordingNotifier::default();
+
+    request_mobile_otp(user.clone(), &notifier, &db)
+        .await
+        .expect("Failed to request a code");
+    assert_eq!(notifier.sms.lock().unwrap()[0].0, "+911234567890");
+    assert!(!is_mobile_verified(&user, &db).await);
+
+    verify_mobile_otp(user.clone(), &notifier.last_sms_code(), &db)
+        .await
+        .expect("The correct code was rejected");
+    assert!(is_mobile_verified(&user, &db).await);
+
+    let result = request_mobile_otp(user, &notifier, &db).await;
+    assert!(matches!(result, Err(MobileOtpError::AlreadyVerified)));
+}
+
+#[tokio::test]
+async fn test_wrong_code_is_rejected_and_locks_after_max_attempts() {
+    let db = get_test_db().await;
+    let user = create_mobile_user(&db).await;
+    let notifier = RecordingNotifier::default();
+
+    request_mobile_otp(user.clone(), &notifier, &db)
+        .await
+        .expect("Failed to request a code");
+    let code = notifier.last_sms_code();
+
+    for _ in 1..MOBILE_OTP_MAX_ATTEMPTS {
+        let result = verify_mobile_otp(user.clone(), &wrong_code(&code), &db).await;
+        assert!(matches!(result, Err(MobileOtpError::InvalidCode)));
+    }
+    let result = verify_mobile_otp(user.clone(), &wrong_code(&code), &db).await;
+    assert!(matches!(result, Err(MobileOtpError::TooManyAttempts)));
+
+    let result = verify_mobile_otp(user.clone(), &code, &db).await;
+    assert!(matches!(result, Err(MobileOtpError::TooManyAttempts)));
+    assert!(!is_mobile_verified(&user, &db).await);
+
+    request_mobile_otp(user.clone(), &notifier, &db)
+        .await
+        .expect("Failed to request a new code");
+    verify_mobile_otp(user.clone(), &notifier.last_sms_code(), &db)
+        .await
+        .expect("The new code was rejected");
+    assert!(is_mobile_verified(&user, &db).await);
+}
+
+#[tokio::test]
+async fn test_expired_code_is_rejected() {
+    let db = get_test_db().await;
+    let user = create_mobile_user(&db).await;
+    let notifier = RecordingNotifier::default();
+
+    request_mobile_otp(user.clone(), &notifier, &db)
+        .await
+        .expect("Failed to request a code");
+    db.query("UPDATE mobile_otps SET expires_at = time::now() - 1m")
+        .await
+        .expect("Failed to expire the code");
+
+    let result = verify_mobile_otp(user.clone(), &notifier.last_sms_code(), &db).await;
+    assert!(matches!(result, Err(MobileOtpError::CodeExpired)));
+
+    let result = verify_mobile_otp(user.clone(), &notifier.last_sms_code(), &db).await;
+    assert!(matches!(result, Err(MobileOtpError::NoPendingCode)));
+    assert!(!is_mobile_verified(&user, &db).await);
+}
@@ -1,13 +1,21 @@
-use crate::common::get_test_db;
+use crate::common::{SeedUser, get_test_db};
 use chrono::NaiveTime;
 use merzah::auth::session::create_session;
 use merzah::{
+    messages,
     models::{
-        api_responses::{ApiResponse, MosqueResponse},
+        api_responses::{
+            AddMosquesResult, ApiResponse, MosqueListPage, MosqueResponse, PrayerTimesResponse,
+        },
         auth::{Platform, RegistrationFormData},
-        mosque::{MosqueRecord, MosqueSearchResult, PrayerTimes, PrayerTimesUpdate},
-        user::{Identifier, User},
+        mosque::{
+            GeoJsonFeature, GeoJsonFeatureCollection, GeoJsonGeometry, GeoJsonId,
+            GeoJsonMosqueProperties, MosqueListFilter, MosquePage, MosqueRecord,
+            MosqueSearchResult, PrayerTimes, PrayerTimesUpdate,
+        },
+        user::{Identifier, User, UserOnClient},
     },
+    services::mosque_names::backfill_normalized_names,
     spawn_app,
 };
 use reqwest::Client;
@@ -29,6 +37,74 @@ struct FetchMosqueParams {
     lon: f64,
 }
 
+#[derive(Serialize)]
+struct FetchMosqueWithRadiusParams {
+    lat: f64,
+    lon: f64,
+    radius_meters: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct ImportGeoJsonParams {
+    body: String,
+}
+
+#[derive(Serialize)]
+struct ExportGeoJsonParams {
+    south: f64,
+    west: f64,
+    north: f64,
+    east: f64,
+}
+
+#[derive(Serialize)]
+struct FetchMosquesInBoundsParams {
+    south: f64,
+    west: f64,
+    north: f64,
+    east: f64,
+}
+
+#[derive(Serialize)]
+struct SearchByNameParams {
+    query: String,
+    limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct SearchMosquesParams {
+    query: String,
+    limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct CreateMosqueParams {
+    name: String,
+    lat: f64,
+    lon: f64,
+    street: Option<String>,
+    city: Option<String>,
+}
+
+#[derive(Serialize)]
+struct MosqueIdParams {
+    mosque_id: String,
+}
+
+#[derive(Serialize)]
+struct NoParams {}
+
+#[derive(Serialize)]
+struct ListAllMosquesParams {
+    filter: MosqueListFilter,
+    page: MosquePage,
+}
+
+#[derive(serde::Deserialize)]
+struct MosqueCreatedBy {
+    created_by: Option<RecordId>,
+}
+
 #[derive(Serialize)]
 struct AddAdminParam {
     mosque_supervisor: String,
@@ -60,6 +136,11 @@ struct AddFavoriteParams {
     pub mosque_id: String,
 }
 
+#[derive(Serialize)]
+struct RemoveFavoriteParams {
+    mosque_id: String,
+}
+
 #[derive(serde::Deserialize)]
 struct Favorited {
     #[allow(dead_code)]
@@ -101,13 +182,14 @@ async fn test_update_mosque_personnel(
     let user_id = RecordId::from(("users", format!("user_{}", uuid::Uuid::new_v4())));
     let user: User = db
         .create(user_id.clone())
-        .content(User {
+        .content(SeedUser {
             id: user_id.clone(),
             created_at: Datetime::default(),
             display_name: "Acting User".to_string(),
             password_hash: "hash".to_string(),
             role: role.to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create user")
@@ -155,13 +237,14 @@ async fn test_update_mosque_personnel(
     let imam_id = RecordId::from(("users", "imam_user"));
     let _: User = db
         .create(imam_id.clone())
-        .content(User {
+        .content(SeedUser {
             id: imam_id.clone(),
             created_at: Datetime::default(),
             display_name: "Imam User".to_string(),
             password_hash: "hash".to_string(),
             role: "regular".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create imam")
@@ -210,13 +293,14 @@ async fn update_mosque_personnel_invalid_type() {
     // 1. Create app admin
     let app_admin: User = db
         .create("users")
-        .content(User {
+        .content(SeedUser {
             id: RecordId::from(("users", "app_admin")),
             created_at: Datetime::default(),
             display_name: "App Admin".to_string(),
             password_hash: "hash".to_string(),
             role: "app_admin".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create app admin")
@@ -254,13 +338,14 @@ async fn add_and_fetch_mosques() {
     // 1. Create an app_admin user directly in DB
     let app_admin: User = db
         .create("users")
-        .content(User {
+        .content(SeedUser {
             id: RecordId::from(("users", "test_admin")),
             created_at: Datetime::default(),
             display_name: "Test Admin".to_string(),
             password_hash: "somehash".to_string(),
             role: "app_admin".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create app admin")
@@ -296,6 +381,16 @@ async fn add_and_fetch_mosques() {
         panic!("Add mosques failed. Status: {}, Body: {}", status, text);
     }
 
+    let added = response
+        .json::<ApiResponse<AddMosquesResult>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No import result returned");
+    assert!(added.added > 0, "Should have added mosques in Dearborn");
+    assert_eq!(added.skipped, 0);
+    assert_eq!(added.region, [42.32, -83.24, 42.35, -83.20]);
+
     // 2. Fetch Mosques
     // Center point roughly in the middle
     let fetch_url = format!("{}/mosques/fetch-mosques-for-location", addr);
@@ -306,8 +401,8 @@ async fn add_and_fetch_mosques() {
 
     // Trying form urlencoded first as it is the default for server functions without input=Json
     let response = client
-        .post(&fetch_url)
-        .json(&fetch_params)
+        .get(&fetch_url)
+        .query(&fetch_params)
         .send()
         .await
         .expect("Failed to execute fetch_mosques_for_location");
@@ -354,13 +449,14 @@ async fn update_mosque_prayer_times() {
     // 1. Create an app_admin user and session
     let app_admin: User = db
         .create("users")
-        .content(User {
+        .content(SeedUser {
             id: RecordId::from(("users", "admin")),
             created_at: Datetime::default(),
             display_name: "Admin".to_string(),
             password_hash: "somehash".to_string(),
             role: "app_admin".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create an app admin")
@@ -401,8 +497,8 @@ async fn update_mosque_prayer_times() {
     };
 
     let response = client
-        .post(&fetch_url)
-        .json(&fetch_params)
+        .get(&fetch_url)
+        .query(&fetch_params)
         .send()
         .await
         .expect("Failed to execute fetch_mosques_for_location");
@@ -419,13 +515,14 @@ async fn update_mosque_prayer_times() {
     // 3. Create supervisor user
     let supervisor_user: User = db
         .create("users")
-        .content(User {
+        .content(SeedUser {
             id: RecordId::from(("users", format!("supervisor_{}", uuid::Uuid::new_v4()))),
             created_at: Datetime::default(),
             display_name: "Supervisor".to_string(),
             password_hash: "somehash".to_string(),
             role: "regular".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create supervisor user")
@@ -434,13 +531,14 @@ async fn update_mosque_prayer_times() {
     // 4. Create mosque admin user
     let mosque_admin_user: User = db
         .create("users")
-        .content(User {
+        .content(SeedUser {
             id: RecordId::from(("users", format!("mosque_admin_{}", uuid::Uuid::new_v4()))),
             created_at: Datetime::default(),
             display_name: "Mosque Admin".to_string(),
             password_hash: "somehash".to_string(),
             role: "regular".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create mosque admin user")
@@ -510,7 +608,7 @@ async fn update_mosque_prayer_times() {
         .expect("Failed to deserialize add admin response");
     assert_eq!(
         add_admin_response.data,
-        Some("Elevated the user to a requested_user".to_string())
+        Some(messages::USER_ELEVATED_TO_REQUESTED_USER.to_string())
     );
 
     // 5. Update Prayer Times
@@ -569,7 +667,7 @@ async fn update_mosque_prayer_times() {
         .expect("Failed to deserialize update response");
     assert_eq!(
         update_response.data,
-        Some("Successfully updated jamat and adhan times".to_string())
+        Some(messages::PRAYER_TIMES_UPDATED.to_string())
     );
 }
 
@@ -582,13 +680,14 @@ async fn favorite_and_unfavorite_mosques() {
     // 1. Create an app_admin user and session for adding mosques
     let app_admin: User = db
         .create("users")
-        .content(User {
+        .content(SeedUser {
             id: RecordId::from(("users", "test_admin")),
             created_at: Datetime::default(),
             display_name: "Test Admin".to_string(),
             password_hash: "somehash".to_string(),
             role: "app_admin".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create app admin")
@@ -617,13 +716,14 @@ async fn favorite_and_unfavorite_mosques() {
     // 2. Setup User
     let user: User = db
         .create("users")
-        .content(User {
+        .content(SeedUser {
             id: RecordId::from(("users", "fan_user")),
             created_at: Datetime::default(),
             display_name: "Fan User".to_string(),
             password_hash: "hash".to_string(),
             role: "regular".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create user")
@@ -641,8 +741,8 @@ async fn favorite_and_unfavorite_mosques() {
         lon: 77.295,
     };
     let response = client
-        .post(&fetch_url)
-        .json(&fetch_params)
+        .get(&fetch_url)
+        .query(&fetch_params)
         .send()
         .await
         .expect("Failed to fetch");
@@ -787,13 +887,14 @@ async fn test_favorite_mosque_with_both_auth_methods(
     // 1. Create admin and add mosques
     let app_admin: User = db
         .create("users")
-        .content(User {
+        .content(SeedUser {
             id: RecordId::from(("users", format!("admin_{}", uuid::Uuid::new_v4()))),
             created_at: Datetime::default(),
             display_name: "Test Admin".to_string(),
             password_hash: "hash".to_string(),
             role: "app_admin".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create admin")
@@ -830,13 +931,14 @@ async fn test_favorite_mosque_with_both_auth_methods(
     // 2. Create regular user
     let user: User = db
         .create("users")
-        .content(User {
+        .content(SeedUser {
             id: RecordId::from(("users", format!("user_{}", uuid::Uuid::new_v4()))),
             created_at: Datetime::default(),
             display_name: "Test User".to_string(),
             password_hash: "hash".to_string(),
             role: "regular".to_string(),
             updated_at: Datetime::default(),
+            verified: true,
         })
         .await
         .expect("Failed to create user")
@@ -854,8 +956,8 @@ async fn test_favorite_mosque_with_both_auth_methods(
     };
 
     let fetch_response = client
-        .post(&fetch_url)
-        .json(&fetch_params)
+        .get(&fetch_url)
+        .query(&fetch_params)
         .send()
         .await
         .expect("Failed to fetch");
@@ -939,3 +1041,1311 @@ async fn test_unauthenticated_access_to_protected_mosque_endpoints(
         response.status()
     );
 }
+
+async fn fetch_mosques_with_radius(
+    client: &Client,
+    addr: &str,
+    params: &FetchMosqueWithRadiusParams,
+) -> Vec<MosqueResponse> {
+    let response = client
+        .get(format!("{}/mosques/fetch-mosques-for-location", addr))
+        .query(params)
+        .send()
+        .await
+        .expect("Failed to fetch mosques");
+
+    assert_eq!(response.status(), 200);
+
+    response
+        .json::<ApiResponse<Vec<MosqueResponse>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No mosques data")
+}
+
+#[rstest]
+#[case::tiny_radius_is_clamped(Some(1), 0)]
+#[case::default_radius(None, 1)]
+#[case::explicit_default_radius(Some(5000), 1)]
+#[case::huge_radius_is_clamped(Some(10_000_000), 1)]
+#[tokio::test]
+async fn test_fetch_mosques_for_location_radius(
+    #[case] radius_meters: Option<u32>,
+    #[case] expected_count: usize,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    // Roughly 1.2 km away from the search point below
+    let _: Option<MosqueRecord> = db
+        .create(("mosques", "radius_mosque"))
+        .content(CreateMosque {
+            location: Geometry::Point((77.2100, 28.6100).into()),
+            name: "Radius Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque");
+
+    // Far outside even the largest allowed radius
+    let _: Option<MosqueRecord> = db
+        .create(("mosques", "distant_mosque"))
+        .content(CreateMosque {
+            location: Geometry::Point((72.8777, 19.0760).into()),
+            name: "Distant Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque");
+
+    let params = FetchMosqueWithRadiusParams {
+        lat: 28.6200,
+        lon: 77.2150,
+        radius_meters,
+    };
+    let mosques = fetch_mosques_with_radius(&client, &addr, &params).await;
+
+    assert_eq!(mosques.len(), expected_count);
+    if expected_count > 0 {
+        assert_eq!(mosques[0].name.as_deref(), Some("Radius Mosque"));
+    }
+}
+
+#[tokio::test]
+async fn test_fetch_mosques_for_location_reports_sorted_distances() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    // Created out of order, roughly 3 km, 0.5 km and 1.5 km from the search point
+    for (key, lon, lat) in [
+        ("far_mosque", 77.2150, 28.6470),
+        ("nearest_mosque", 77.2150, 28.6245),
+        ("middle_mosque", 77.2150, 28.6335),
+    ] {
+        let _: Option<MosqueRecord> = db
+            .create(("mosques", key))
+            .content(CreateMosque {
+                location: Geometry::Point((lon, lat).into()),
+                name: key.to_string(),
+            })
+            .await
+            .expect("Failed to create mosque");
+    }
+
+    let mosques = fetch_mosques_with_radius(
+        &client,
+        &addr,
+        &FetchMosqueWithRadiusParams {
+            lat: 28.6200,
+            lon: 77.2150,
+            radius_meters: None,
+        },
+    )
+    .await;
+
+    let names: Vec<&str> = mosques
+        .iter()
+        .map(|mosque| mosque.name.as_deref().unwrap_or_default())
+        .collect();
+    assert_eq!(names, vec!["nearest_mosque", "middle_mosque", "far_mosque"]);
+
+    let distances: Vec<f64> = mosques
+        .iter()
+        .map(|mosque| mosque.distance_meters.expect("No distance returned"))
+        .collect();
+    assert!(distances.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!((400.0..600.0).contains(&distances[0]), "{}", distances[0]);
+}
+
+#[tokio::test]
+async fn test_fetch_mosques_in_bounds_matches_the_radius_search() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    // Inside the Dearborn box, and so within the default radius of its center too
+    for (key, lon, lat) in [
+        ("icoa", -83.2225, 42.3370),
+        ("warren_avenue", -83.2350, 42.3450),
+        ("michigan_avenue", -83.2050, 42.3225),
+    ] {
+        let _: Option<MosqueRecord> = db
+            .create(("mosques", key))
+            .content(CreateMosque {
+                location: Geometry::Point((lon, lat).into()),
+                name: key.to_string(),
+            })
+            .await
+            .expect("Failed to create mosque");
+    }
+
+    // Downtown Detroit, outside both the box and the radius
+    let _: Option<MosqueRecord> = db
+        .create(("mosques", "downtown"))
+        .content(CreateMosque {
+            location: Geometry::Point((-83.0458, 42.3314).into()),
+            name: "downtown".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque");
+
+    let response = client
+        .get(format!("{}/mosques/fetch-mosques-in-bounds", addr))
+        .query(&FetchMosquesInBoundsParams {
+            south: 42.32,
+            west: -83.24,
+            north: 42.35,
+            east: -83.20,
+        })
+        .send()
+        .await
+        .expect("Failed to fetch mosques");
+    assert_eq!(response.status(), 200);
+    let in_bounds = response
+        .json::<ApiResponse<Vec<MosqueResponse>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No mosques data");
+
+    let around_center = fetch_mosques_with_radius(
+        &client,
+        &addr,
+        &FetchMosqueWithRadiusParams {
+            lat: 42.335,
+            lon: -83.22,
+            radius_meters: None,
+        },
+    )
+    .await;
+
+    let mut in_bounds: Vec<String> = in_bounds.into_iter().map(|mosque| mosque.id).collect();
+    let mut around_center: Vec<String> =
+        around_center.into_iter().map(|mosque| mosque.id).collect();
+    in_bounds.sort();
+    around_center.sort();
+    assert_eq!(in_bounds.len(), 3);
+    assert_eq!(in_bounds, around_center);
+}
+
+#[rstest]
+#[case::south_above_north(42.35, -83.24, 42.32, -83.20)]
+#[case::west_beyond_east(42.32, -83.20, 42.35, -83.24)]
+#[case::too_large(40.0, -85.0, 44.0, -81.0)]
+#[tokio::test]
+async fn test_fetch_mosques_in_bounds_rejects_invalid_boxes(
+    #[case] south: f64,
+    #[case] west: f64,
+    #[case] north: f64,
+    #[case] east: f64,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db);
+
+    let response = Client::new()
+        .get(format!("{}/mosques/fetch-mosques-in-bounds", addr))
+        .query(&FetchMosquesInBoundsParams {
+            south,
+            west,
+            north,
+            east,
+        })
+        .send()
+        .await
+        .expect("Failed to fetch mosques");
+    assert_eq!(response.status(), 400);
+}
+
+fn geojson_mosque(id: Option<GeoJsonId>, lon: f64, lat: f64, name: &str) -> GeoJsonFeature {
+    GeoJsonFeature {
+        feature_type: "Feature".to_string(),
+        id,
+        geometry: GeoJsonGeometry {
+            geometry_type: "Point".to_string(),
+            coordinates: vec![lon, lat],
+        },
+        properties: GeoJsonMosqueProperties {
+            name: Some(name.to_string()),
+            street: Some("Main Street".to_string()),
+            city: Some("Lucknow".to_string()),
+            has_imam: None,
+            has_muazzin: None,
+        },
+    }
+}
+
+async fn create_app_admin_session(
+    db: &surrealdb::Surreal<surrealdb::engine::remote::ws::Client>,
+) -> String {
+    let app_admin: User = db
+        .create("users")
+        .content(SeedUser {
+            id: RecordId::from(("users", "geojson_admin")),
+            created_at: Datetime::default(),
+            display_name: "GeoJSON Admin".to_string(),
+            password_hash: "hash".to_string(),
+            role: "app_admin".to_string(),
+            updated_at: Datetime::default(),
+            verified: true,
+        })
+        .await
+        .expect("Failed to create app admin")
+        .expect("User not returned");
+
+    create_session(app_admin.id, db)
+        .await
+        .expect("Failed to create admin session")
+}
+
+#[tokio::test]
+async fn test_import_mosques_from_geojson() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let admin_session = create_app_admin_session(&db).await;
+
+    let collection = GeoJsonFeatureCollection {
+        collection_type: "FeatureCollection".to_string(),
+        features: vec![
+            geojson_mosque(
+                Some(GeoJsonId::Number(9001)),
+                80.9462,
+                26.8467,
+                "Tila Wali Masjid",
+            ),
+            geojson_mosque(None, 80.9500, 26.8500, "Asafi Masjid"),
+        ],
+    };
+    let params = ImportGeoJsonParams {
+        body: serde_json::to_string(&collection).expect("Failed to serialize GeoJSON"),
+    };
+
+    let import_url = format!("{}/mosques/import-mosques-from-geojson", addr);
+    for expected in [
+        "Imported 2 new mosques, skipped 0 existing mosques",
+        "Imported 0 new mosques, skipped 2 existing mosques",
+    ] {
+        let response = client
+            .post(&import_url)
+            .json(&params)
+            .header("Authorization", format!("Bearer {}", admin_session))
+            .send()
+            .await
+            .expect("Failed to import mosques");
+
+        assert_eq!(response.status(), 200);
+        let api_response = response
+            .json::<ApiResponse<String>>()
+            .await
+            .expect("Failed to deserialize");
+        assert_eq!(api_response.data.as_deref(), Some(expected));
+    }
+
+    let fetch_params = FetchMosqueParams {
+        lat: 26.8480,
+        lon: 80.9480,
+    };
+    let mosques = client
+        .get(format!("{}/mosques/fetch-mosques-for-location", addr))
+        .query(&fetch_params)
+        .send()
+        .await
+        .expect("Failed to fetch mosques")
+        .json::<ApiResponse<Vec<MosqueResponse>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No mosques data");
+
+    assert_eq!(mosques.len(), 2);
+    assert!(mosques.iter().any(|m| m.id == "mosques:9001"));
+    assert!(
+        mosques
+            .iter()
+            .any(|m| m.name.as_deref() == Some("Asafi Masjid"))
+    );
+}
+
+#[tokio::test]
+async fn test_import_mosques_from_geojson_rejects_invalid_geometry() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let admin_session = create_app_admin_session(&db).await;
+
+    let mut invalid = geojson_mosque(None, 80.9462, 26.8467, "Broken Masjid");
+    invalid.geometry.geometry_type = "Polygon".to_string();
+
+    let collection = GeoJsonFeatureCollection {
+        collection_type: "FeatureCollection".to_string(),
+        features: vec![geojson_mosque(None, 80.9, 26.8, "Valid Masjid"), invalid],
+    };
+    let params = ImportGeoJsonParams {
+        body: serde_json::to_string(&collection).expect("Failed to serialize GeoJSON"),
+    };
+
+    let response = client
+        .post(format!("{}/mosques/import-mosques-from-geojson", addr))
+        .json(&params)
+        .header("Authorization", format!("Bearer {}", admin_session))
+        .send()
+        .await
+        .expect("Failed to import mosques");
+
+    assert_eq!(response.status(), 400);
+    let api_response = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize");
+    assert!(api_response.error.unwrap().contains("index 1"));
+
+    let mosques: Vec<MosqueRecord> = db.select("mosques").await.expect("Failed to select");
+    assert!(
+        mosques.is_empty(),
+        "Nothing should be imported from an invalid file"
+    );
+}
+
+#[tokio::test]
+async fn test_export_mosques_geojson_round_trip() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let admin_session = create_app_admin_session(&db).await;
+
+    let inside = vec![
+        geojson_mosque(
+            Some(GeoJsonId::Number(4242)),
+            80.9462,
+            26.8467,
+            "Tila Wali Masjid",
+        ),
+        geojson_mosque(
+            Some(GeoJsonId::Text("asafi".to_string())),
+            80.9500,
+            26.8500,
+            "Asafi Masjid",
+        ),
+    ];
+    let outside = geojson_mosque(Some(GeoJsonId::Number(5353)), 77.2, 28.6, "Delhi Masjid");
+
+    let mut features = inside.clone();
+    features.push(outside);
+    let params = ImportGeoJsonParams {
+        body: serde_json::to_string(&GeoJsonFeatureCollection {
+            collection_type: "FeatureCollection".to_string(),
+            features,
+        })
+        .expect("Failed to serialize GeoJSON"),
+    };
+
+    let import_response = client
+        .post(format!("{}/mosques/import-mosques-from-geojson", addr))
+        .json(&params)
+        .header("Authorization", format!("Bearer {}", admin_session))
+        .send()
+        .await
+        .expect("Failed to import mosques");
+    assert_eq!(import_response.status(), 200);
+
+    let export_params = ExportGeoJsonParams {
+        south: 26.80,
+        west: 80.90,
+        north: 26.90,
+        east: 81.00,
+    };
+    let export_response = client
+        .post(format!("{}/mosques/export-mosques-geojson", addr))
+        .json(&export_params)
+        .header("Authorization", format!("Bearer {}", admin_session))
+        .send()
+        .await
+        .expect("Failed to export mosques");
+    assert_eq!(export_response.status(), 200);
+
+    let mut exported = export_response
+        .json::<ApiResponse<GeoJsonFeatureCollection>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No GeoJSON returned");
+
+    assert_eq!(exported.collection_type, "FeatureCollection");
+    assert_eq!(exported.features.len(), inside.len());
+
+    exported
+        .features
+        .sort_by(|a, b| a.properties.name.cmp(&b.properties.name));
+    let mut expected = inside;
+    expected.sort_by(|a, b| a.properties.name.cmp(&b.properties.name));
+
+    for (exported, imported) in exported.features.iter().zip(expected.iter()) {
+        assert_eq!(exported.id, imported.id);
+        assert_eq!(exported.geometry, imported.geometry);
+        assert_eq!(exported.properties.name, imported.properties.name);
+        assert_eq!(exported.properties.street, imported.properties.street);
+        assert_eq!(exported.properties.city, imported.properties.city);
+        assert_eq!(exported.properties.has_imam, Some(false));
+        assert_eq!(exported.properties.has_muazzin, Some(false));
+    }
+}
+
+#[tokio::test]
+async fn test_export_mosques_geojson_is_forbidden_for_regular_users() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let user: User = db
+        .create("users")
+        .content(SeedUser {
+            id: RecordId::from(("users", "geojson_regular")),
+            created_at: Datetime::default(),
+            display_name: "Regular User".to_string(),
+            password_hash: "hash".to_string(),
+            role: "regular".to_string(),
+            updated_at: Datetime::default(),
+            verified: true,
+        })
+        .await
+        .expect("Failed to create user")
+        .expect("User not returned");
+    let session = create_session(user.id, &db)
+        .await
+        .expect("Failed to create session");
+
+    let response = client
+        .post(format!("{}/mosques/export-mosques-geojson", addr))
+        .json(&ExportGeoJsonParams {
+            south: 26.80,
+            west: 80.90,
+            north: 26.90,
+            east: 81.00,
+        })
+        .header("Authorization", format!("Bearer {}", session))
+        .send()
+        .await
+        .expect("Failed to export mosques");
+    assert_eq!(response.status(), 403);
+}
+
+#[rstest]
+#[case::without_prefix("noor", Some("Masjid Al-Noor"))]
+#[case::different_generic_word("Noor Mosque", Some("Masjid Al-Noor"))]
+#[case::typo("Al Nour", Some("Masjid Al-Noor"))]
+#[case::hyphenated("jama masjid e ahle hadees", Some("Jama Masjid-e-Ahle Hadees"))]
+#[case::no_match("bilal", None)]
+#[tokio::test]
+async fn test_search_mosques_by_name(#[case] query: &str, #[case] expected: Option<&str>) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let admin_session = create_app_admin_session(&db).await;
+
+    let params = ImportGeoJsonParams {
+        body: serde_json::to_string(&GeoJsonFeatureCollection {
+            collection_type: "FeatureCollection".to_string(),
+            features: vec![
+                geojson_mosque(None, 80.9462, 26.8467, "Masjid Al-Noor"),
+                geojson_mosque(None, 80.9500, 26.8500, "Jama Masjid-e-Ahle Hadees"),
+            ],
+        })
+        .expect("Failed to serialize GeoJSON"),
+    };
+    let import_response = client
+        .post(format!("{}/mosques/import-mosques-from-geojson", addr))
+        .json(&params)
+        .header("Authorization", format!("Bearer {}", admin_session))
+        .send()
+        .await
+        .expect("Failed to import mosques");
+    assert_eq!(import_response.status(), 200);
+
+    let search_params = SearchByNameParams {
+        query: query.to_string(),
+        limit: None,
+    };
+    let response = client
+        .get(format!("{}/mosques/search-mosques-by-name", addr))
+        .query(&search_params)
+        .send()
+        .await
+        .expect("Failed to search mosques");
+    assert_eq!(response.status(), 200);
+
+    let mosques = response
+        .json::<ApiResponse<Vec<MosqueResponse>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No search results");
+
+    match expected {
+        Some(name) => {
+            assert_eq!(mosques.len(), 1);
+            assert_eq!(mosques[0].name.as_deref(), Some(name));
+        }
+        None => assert!(mosques.is_empty()),
+    }
+}
+
+#[tokio::test]
+async fn test_search_finds_mosques_saved_before_names_were_normalized() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let _: Option<MosqueRecord> = db
+        .create(("mosques", "unnormalized_noor"))
+        .content(CreateMosque {
+            location: Geometry::Point((80.9462, 26.8467).into()),
+            name: "Masjid Al-Noor".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque");
+
+    let updated = backfill_normalized_names(&db)
+        .await
+        .expect("Failed to backfill the names");
+    assert_eq!(updated, 1);
+    let updated_again = backfill_normalized_names(&db)
+        .await
+        .expect("Failed to backfill the names");
+    assert_eq!(updated_again, 0, "Normalized names should be left alone");
+
+    let response = client
+        .get(format!("{}/mosques/search-mosques-by-name", addr))
+        .query(&SearchByNameParams {
+            query: "noor".to_string(),
+            limit: None,
+        })
+        .send()
+        .await
+        .expect("Failed to search mosques");
+    assert_eq!(response.status(), 200);
+
+    let mosques = response
+        .json::<ApiResponse<Vec<MosqueResponse>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No search results");
+    assert_eq!(mosques.len(), 1);
+    assert_eq!(mosques[0].name.as_deref(), Some("Masjid Al-Noor"));
+}
+
+#[tokio::test]
+async fn test_create_mosque_records_creator() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let admin_session = create_app_admin_session(&db).await;
+
+    let params = CreateMosqueParams {
+        name: "Masjid Al-Falah".to_string(),
+        lat: 26.85,
+        lon: 80.95,
+        street: Some("Station Road".to_string()),
+        city: Some("Lucknow".to_string()),
+    };
+    let response = client
+        .post(format!("{}/mosques/create-mosque", addr))
+        .json(&params)
+        .header("Authorization", format!("Bearer {}", admin_session))
+        .send()
+        .await
+        .expect("Failed to create mosque");
+    assert_eq!(response.status(), 201);
+
+    let mosque_id = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No mosque id returned");
+
+    let record: Option<MosqueCreatedBy> = db
+        .query("SELECT created_by FROM ONLY <record> $mosque_id")
+        .bind(("mosque_id", mosque_id.clone()))
+        .await
+        .expect("Failed to query mosque")
+        .take(0)
+        .expect("Take failed");
+    assert_eq!(
+        record.and_then(|r| r.created_by),
+        Some(RecordId::from(("users", "geojson_admin")))
+    );
+
+    let response = client
+        .post(format!("{}/mosques/fetch-mosque-creator", addr))
+        .json(&MosqueIdParams { mosque_id })
+        .header("Authorization", format!("Bearer {}", admin_session))
+        .send()
+        .await
+        .expect("Failed to fetch mosque creator");
+    assert_eq!(response.status(), 200);
+
+    let creator = response
+        .json::<ApiResponse<Option<UserOnClient>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .flatten()
+        .expect("Creator should be recorded");
+    assert_eq!(creator.display_name, "GeoJSON Admin");
+}
+
+#[tokio::test]
+async fn test_fetch_favorite_mosques() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    for (key, name) in [
+        ("favorite_one", "Favorite One"),
+        ("favorite_two", "Favorite Two"),
+        ("not_a_favorite", "Not A Favorite"),
+    ] {
+        let _: Option<MosqueRecord> = db
+            .create(("mosques", key))
+            .content(CreateMosque {
+                location: Geometry::Point((77.2100, 28.6100).into()),
+                name: name.to_string(),
+            })
+            .await
+            .expect("Failed to create mosque");
+    }
+
+    let user: User = db
+        .create("users")
+        .content(SeedUser {
+            id: RecordId::from(("users", "favorites_user")),
+            created_at: Datetime::default(),
+            display_name: "Favorites User".to_string(),
+            password_hash: "hash".to_string(),
+            role: "regular".to_string(),
+            updated_at: Datetime::default(),
+            verified: true,
+        })
+        .await
+        .expect("Failed to create user")
+        .expect("User not returned");
+    let session = create_session(user.id, &db)
+        .await
+        .expect("Failed to create session");
+
+    let fetch_favorites = || async {
+        client
+            .post(format!("{}/mosques/fetch-favorite-mosques", addr))
+            .json(&NoParams {})
+            .header("Authorization", format!("Bearer {}", session))
+            .send()
+            .await
+            .expect("Failed to fetch favorite mosques")
+            .json::<ApiResponse<Vec<MosqueResponse>>>()
+            .await
+            .expect("Failed to deserialize")
+            .data
+            .expect("No favorite mosques data")
+    };
+
+    assert!(fetch_favorites().await.is_empty());
+
+    for key in ["favorite_one", "favorite_two"] {
+        let response = client
+            .post(format!("{}/mosques/add-favorite", addr))
+            .json(&AddFavoriteParams {
+                mosque_id: RecordId::from(("mosques", key)).to_string(),
+            })
+            .header("Authorization", format!("Bearer {}", session))
+            .send()
+            .await
+            .expect("Failed to favorite mosque");
+        assert_eq!(response.status(), 200);
+    }
+
+    let mut names: Vec<String> = fetch_favorites()
+        .await
+        .into_iter()
+        .filter_map(|mosque| mosque.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["Favorite One", "Favorite Two"]);
+}
+
+#[tokio::test]
+async fn test_toggling_a_favorite_rapidly_engages_the_cooldown() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    for key in ["toggled", "untouched"] {
+        let _: Option<MosqueRecord> = db
+            .create(("mosques", key))
+            .content(CreateMosque {
+                location: Geometry::Point((77.2100, 28.6100).into()),
+                name: format!("{key} mosque"),
+            })
+            .await
+            .expect("Failed to create mosque");
+    }
+    let user: User = db
+        .create("users")
+        .content(SeedUser {
+            id: RecordId::from(("users", "toggling_user")),
+            created_at: Datetime::default(),
+            display_name: "Toggling User".to_string(),
+            password_hash: "hash".to_string(),
+            role: "regular".to_string(),
+            updated_at: Datetime::default(),
+            verified: true,
+        })
+        .await
+        .expect("Failed to create user")
+        .expect("User not returned");
+    let session = create_session(user.id, &db)
+        .await
+        .expect("Failed to create session");
+
+    let toggle = |key: &'static str, favorite: bool| {
+        let mosque_id = RecordId::from(("mosques", key)).to_string();
+        let request = if favorite {
+            client
+                .post(format!("{}/mosques/add-favorite", addr))
+                .json(&AddFavoriteParams { mosque_id })
+        } else {
+            client
+                .delete(format!("{}/mosques/remove-favorite", addr))
+                .query(&RemoveFavoriteParams { mosque_id })
+        };
+        request
+            .header("Authorization", format!("Bearer {}", session))
+            .send()
+    };
+
+    let mut statuses = Vec::new();
+    for favorite in [true, false, true, false, true] {
+        let response = toggle("toggled", favorite)
+            .await
+            .expect("Failed to toggle the favorite");
+        statuses.push(response.status().as_u16());
+        if response.status() == 429 {
+            assert!(response.headers().get("retry-after").is_some());
+        }
+    }
+    assert_eq!(statuses, vec![200, 200, 200, 200, 429]);
+
+    let response = toggle("untouched", true)
+        .await
+        .expect("Failed to favorite the other mosque");
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_list_all_mosques_without_admin() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let admin_session = create_app_admin_session(&db).await;
+
+    for (key, name) in [
+        ("handled_mosque", "Handled Mosque"),
+        ("orphan_mosque_a", "Orphan Mosque A"),
+        ("orphan_mosque_b", "Orphan Mosque B"),
+    ] {
+        let _: Option<MosqueRecord> = db
+            .create(("mosques", key))
+            .content(CreateMosque {
+                location: Geometry::Point((77.2100, 28.6100).into()),
+                name: name.to_string(),
+            })
+            .await
+            .expect("Failed to create mosque");
+    }
+
+    db.query("RELATE $user -> handles -> $mosque SET granted_by = $user")
+        .bind(("user", RecordId::from(("users", "geojson_admin"))))
+        .bind(("mosque", RecordId::from(("mosques", "handled_mosque"))))
+        .await
+        .expect("Failed to relate admin to mosque")
+        .check()
+        .expect("Failed to relate admin to mosque");
+
+    let list_mosques = |per_page: u32| {
+        let params = ListAllMosquesParams {
+            filter: MosqueListFilter {
+                has_admin: Some(false),
+                ..Default::default()
+            },
+            page: MosquePage { page: 1, per_page },
+        };
+        let request = client
+            .post(format!("{}/mosques/list-all-mosques", addr))
+            .json(&params)
+            .header("Authorization", format!("Bearer {}", admin_session));
+
+        async move {
+            let response = request.send().await.expect("Failed to list mosques");
+            assert_eq!(response.status(), 200);
+            response
+                .json::<ApiResponse<MosqueListPage>>()
+                .await
+                .expect("Failed to deserialize")
+                .data
+                .expect("No mosque list returned")
+        }
+    };
+
+    let listed = list_mosques(20).await;
+    assert_eq!(listed.total, 2);
+    let names: Vec<Option<String>> = listed.mosques.into_iter().map(|m| m.name).collect();
+    assert_eq!(
+        names,
+        vec![
+            Some("Orphan Mosque A".to_string()),
+            Some("Orphan Mosque B".to_string())
+        ]
+    );
+
+    let first_page = list_mosques(1).await;
+    assert_eq!(first_page.total, 2);
+    assert_eq!(first_page.mosques.len(), 1);
+    assert_eq!(
+        first_page.mosques[0].name.as_deref(),
+        Some("Orphan Mosque A")
+    );
+}
+
+#[derive(Serialize)]
+struct UpdateMosqueDetailsParams {
+    mosque_id: String,
+    name: Option<String>,
+    street: Option<String>,
+    city: Option<String>,
+}
+
+#[rstest]
+#[case::app_admin("app_admin", 200)]
+#[case::regular_user("regular", 403)]
+#[tokio::test]
+async fn update_mosque_details_only_touches_the_given_fields(
+    #[case] role: &str,
+    #[case] expected_status: u16,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let mosque: MosqueRecord = db
+        .create("mosques")
+        .content(CreateMosque {
+            location: Geometry::Point((77.2, 28.6).into()),
+            name: "Masjid Imported".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Not returned");
+
+    let user_id = RecordId::from(("users", format!("user_{}", uuid::Uuid::new_v4())));
+    let user: User = db
+        .create(user_id.clone())
+        .content(SeedUser {
+            id: user_id.clone(),
+            created_at: Datetime::default(),
+            display_name: "Acting User".to_string(),
+            password_hash: "hash".to_string(),
+            role: role.to_string(),
+            updated_at: Datetime::default(),
+            verified: true,
+        })
+        .await
+        .expect("Failed to create user")
+        .expect("Not returned");
+    let session = create_session(user.id, &db)
+        .await
+        .expect("Failed to create session");
+
+    let response = client
+        .patch(format!("{}/mosques/update-details", addr))
+        .json(&UpdateMosqueDetailsParams {
+            mosque_id: mosque.id.to_string(),
+            name: Some("Masjid Corrected".to_string()),
+            street: None,
+            city: None,
+        })
+        .header("Authorization", format!("Bearer {}", session))
+        .send()
+        .await
+        .expect("Failed to send update");
+    assert_eq!(response.status().as_u16(), expected_status);
+
+    let updated: MosqueRecord = db
+        .select(mosque.id)
+        .await
+        .expect("Failed to select mosque")
+        .expect("Mosque not found");
+    let expected_name = if expected_status == 200 {
+        "Masjid Corrected"
+    } else {
+        "Masjid Imported"
+    };
+    assert_eq!(updated.name.as_deref(), Some(expected_name));
+    assert_eq!(updated.location, mosque.location);
+    assert_eq!(updated.street, None);
+    assert_eq!(updated.city, None);
+}
+
+#[rstest]
+#[case::partial_name("NOOR", vec![Some("Masjid Al-Noor")])]
+#[case::city("kanpur", vec![None, Some("Bilal Masjid")])]
+#[case::no_match("hadees", vec![])]
+#[tokio::test]
+async fn test_search_mosques_by_keyword(#[case] query: &str, #[case] expected: Vec<Option<&str>>) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let admin_session = create_app_admin_session(&db).await;
+
+    for (name, city) in [("Masjid Al-Noor", "Lucknow"), ("Bilal Masjid", "Kanpur")] {
+        let params = CreateMosqueParams {
+            name: name.to_string(),
+            lat: 26.85,
+            lon: 80.95,
+            street: None,
+            city: Some(city.to_string()),
+        };
+        let response = client
+            .post(format!("{}/mosques/create-mosque", addr))
+            .json(&params)
+            .header("Authorization", format!("Bearer {}", admin_session))
+            .send()
+            .await
+            .expect("Failed to create mosque");
+        assert_eq!(response.status(), 201);
+    }
+    db.query("CREATE mosques SET location = (80.3, 26.4), city = 'Kanpur'")
+        .await
+        .expect("Failed to create an unnamed mosque")
+        .check()
+        .expect("Failed to create an unnamed mosque");
+
+    let response = client
+        .get(format!("{}/mosques/search-mosques", addr))
+        .query(&SearchMosquesParams {
+            query: query.to_string(),
+            limit: None,
+        })
+        .send()
+        .await
+        .expect("Failed to search mosques");
+    assert_eq!(response.status(), 200);
+
+    let mosques = response
+        .json::<ApiResponse<Vec<MosqueResponse>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No search results");
+    let names: Vec<Option<&str>> = mosques.iter().map(|m| m.name.as_deref()).collect();
+    assert_eq!(names, expected);
+}
+
+#[rstest]
+#[case::empty("")]
+#[case::single_character(" n ")]
+#[tokio::test]
+async fn test_search_mosques_rejects_short_queries(#[case] query: &str) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let response = client
+        .get(format!("{}/mosques/search-mosques", addr))
+        .query(&SearchMosquesParams {
+            query: query.to_string(),
+            limit: None,
+        })
+        .send()
+        .await
+        .expect("Failed to search mosques");
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn reimporting_a_region_keeps_existing_mosques() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let admin_session = create_app_admin_session(&db).await;
+
+    let import_dearborn = || {
+        let request = client
+            .post(format!("{}/mosques/add-mosque-of-region", addr))
+            .json(&AddMosqueParams {
+                south: 42.32,
+                west: -83.24,
+                north: 42.35,
+                east: -83.20,
+            })
+            .header("Authorization", format!("Bearer {}", admin_session));
+
+        async move {
+            let response = request.send().await.expect("Failed to add mosques");
+            assert_eq!(response.status(), 200);
+            response
+                .json::<ApiResponse<AddMosquesResult>>()
+                .await
+                .expect("Failed to deserialize")
+                .data
+                .expect("No import result returned")
+        }
+    };
+
+    let first_import = import_dearborn().await;
+    let imported: Vec<MosqueRecord> = db
+        .select("mosques")
+        .await
+        .expect("Failed to select mosques");
+    assert!(
+        !imported.is_empty(),
+        "Should have found mosques in Dearborn"
+    );
+    assert_eq!(first_import.added, imported.len());
+
+    let imam_id = RecordId::from(("users", "dearborn_imam"));
+    let _: User = db
+        .create(imam_id.clone())
+        .content(SeedUser {
+            id: imam_id.clone(),
+            created_at: Datetime::default(),
+            display_name: "Dearborn Imam".to_string(),
+            password_hash: "hash".to_string(),
+            role: "regular".to_string(),
+            updated_at: Datetime::default(),
+            verified: true,
+        })
+        .await
+        .expect("Failed to create imam")
+        .expect("Not returned");
+    let mosque_id = imported[0].id.clone();
+    db.query("UPDATE $mosque SET imam = $imam")
+        .bind(("mosque", mosque_id.clone()))
+        .bind(("imam", imam_id.clone()))
+        .await
+        .expect("Failed to set the imam")
+        .check()
+        .expect("Failed to set the imam");
+
+    let second_import = import_dearborn().await;
+    assert_eq!(second_import.added, 0);
+    assert_eq!(second_import.skipped, imported.len());
+
+    let reimported: Vec<MosqueRecord> = db
+        .select("mosques")
+        .await
+        .expect("Failed to select mosques");
+    assert_eq!(reimported.len(), imported.len());
+
+    let mosque: Option<MosqueSearchResult> = db
+        .query("SELECT * FROM $mosque FETCH imam, muazzin")
+        .bind(("mosque", mosque_id))
+        .await
+        .expect("Failed to select")
+        .take(0)
+        .expect("Take failed");
+    assert_eq!(
+        mosque.expect("Mosque not found").imam.map(|imam| imam.id),
+        Some(imam_id)
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_mosques_for_location_includes_prayer_times() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let timed: MosqueRecord = db
+        .create("mosques")
+        .content(CreateMosque {
+            location: Geometry::Point((10.0, 10.0).into()),
+            name: "Timed Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Not returned");
+    let _untimed: MosqueRecord = db
+        .create("mosques")
+        .content(CreateMosque {
+            location: Geometry::Point((10.001, 10.001).into()),
+            name: "Untimed Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Not returned");
+
+    let app_admin: User = db
+        .create("users")
+        .content(SeedUser {
+            id: RecordId::from(("users", "prayer_times_admin")),
+            created_at: Datetime::default(),
+            display_name: "Prayer Times Admin".to_string(),
+            password_hash: "hash".to_string(),
+            role: "app_admin".to_string(),
+            updated_at: Datetime::default(),
+            verified: true,
+        })
+        .await
+        .expect("Failed to create app admin")
+        .expect("User not returned");
+    let admin_session = create_session(app_admin.id.clone(), &db)
+        .await
+        .expect("Failed to create admin session");
+
+    let adhan_times = PrayerTimes {
+        fajr: NaiveTime::from_hms_opt(5, 10, 0).unwrap(),
+        dhuhr: NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+        asr: NaiveTime::from_hms_opt(16, 45, 0).unwrap(),
+        maghrib: NaiveTime::from_hms_opt(19, 50, 0).unwrap(),
+        isha: NaiveTime::from_hms_opt(21, 15, 0).unwrap(),
+        jummah: NaiveTime::from_hms_opt(13, 30, 0).unwrap(),
+    };
+    let response = client
+        .patch(format!("{}/mosques/update-adhan-jamat-times", addr))
+        .json(&UpdatePrayerTimesParams {
+            mosque_admin: app_admin.id.to_string(),
+            mosque_id: timed.id.to_string(),
+            prayer_times: PrayerTimesUpdate {
+                adhan_times: Some(adhan_times.clone()),
+                jamat_times: None,
+            },
+        })
+        .header("Authorization", format!("Bearer {}", admin_session))
+        .send()
+        .await
+        .expect("Failed to update the prayer times");
+    assert_eq!(response.status(), 200);
+
+    let mosques = fetch_mosques_with_radius(
+        &client,
+        &addr,
+        &FetchMosqueWithRadiusParams {
+            lat: 10.0,
+            lon: 10.0,
+            radius_meters: None,
+        },
+    )
+    .await;
+    let find = |name: &str| {
+        mosques
+            .iter()
+            .find(|mosque| mosque.name.as_deref() == Some(name))
+            .unwrap_or_else(|| panic!("{name} was not fetched"))
+    };
+
+    let timed = find("Timed Mosque");
+    assert_eq!(timed.adhan_times, Some(adhan_times));
+    assert_eq!(timed.jamat_times, None);
+
+    let untimed = find("Untimed Mosque");
+    assert_eq!(untimed.adhan_times, None);
+    assert_eq!(untimed.jamat_times, None);
+}
+
+#[derive(Serialize)]
+struct CreateTimedMosque {
+    location: Geometry,
+    name: String,
+    adhan_times: PrayerTimes,
+    jamat_times: PrayerTimes,
+}
+
+async fn fetch_prayer_times(client: &Client, addr: &str, mosque_id: &str) -> reqwest::Response {
+    client
+        .get(format!("{}/mosques/fetch-prayer-times", addr))
+        .query(&MosqueIdParams {
+            mosque_id: mosque_id.to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to fetch the prayer times")
+}
+
+#[tokio::test]
+async fn test_fetch_prayer_times() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let adhan_times = PrayerTimes {
+        fajr: NaiveTime::from_hms_opt(5, 0, 0).unwrap(),
+        dhuhr: NaiveTime::from_hms_opt(12, 45, 0).unwrap(),
+        asr: NaiveTime::from_hms_opt(16, 30, 0).unwrap(),
+        maghrib: NaiveTime::from_hms_opt(19, 40, 0).unwrap(),
+        isha: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+        jummah: NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+    };
+    let jamat_times = PrayerTimes {
+        fajr: NaiveTime::from_hms_opt(5, 20, 0).unwrap(),
+        dhuhr: NaiveTime::from_hms_opt(13, 15, 0).unwrap(),
+        asr: NaiveTime::from_hms_opt(16, 50, 0).unwrap(),
+        maghrib: NaiveTime::from_hms_opt(19, 45, 0).unwrap(),
+        isha: NaiveTime::from_hms_opt(21, 15, 0).unwrap(),
+        jummah: NaiveTime::from_hms_opt(13, 30, 0).unwrap(),
+    };
+
+    let _: Option<MosqueRecord> = db
+        .create(("mosques", "timed_mosque"))
+        .content(CreateTimedMosque {
+            location: Geometry::Point((77.21, 28.61).into()),
+            name: "Timed Mosque".to_string(),
+            adhan_times: adhan_times.clone(),
+            jamat_times: jamat_times.clone(),
+        })
+        .await
+        .expect("Failed to create mosque");
+    let _: Option<MosqueRecord> = db
+        .create(("mosques", "untimed_mosque"))
+        .content(CreateMosque {
+            location: Geometry::Point((77.22, 28.62).into()),
+            name: "Untimed Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque");
+
+    let response = fetch_prayer_times(&client, &addr, "mosques:timed_mosque").await;
+    assert_eq!(response.status(), 200);
+    let times = response
+        .json::<ApiResponse<PrayerTimesResponse>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No prayer times returned");
+    assert_eq!(
+        times,
+        PrayerTimesResponse {
+            adhan_times: Some(adhan_times),
+            jamat_times: Some(jamat_times),
+        }
+    );
+
+    let response = fetch_prayer_times(&client, &addr, "mosques:untimed_mosque").await;
+    assert_eq!(response.status(), 200);
+    let times = response
+        .json::<ApiResponse<PrayerTimesResponse>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No prayer times returned");
+    assert_eq!(
+        times,
+        PrayerTimesResponse {
+            adhan_times: None,
+            jamat_times: None,
+        }
+    );
+
+    let response = fetch_prayer_times(&client, &addr, "mosques:missing_mosque").await;
+    assert_eq!(response.status(), 404);
+}
@@ -2,11 +2,15 @@ use crate::common::get_test_db;
 use merzah::auth::custom_auth::register_user;
 use merzah::auth::session::create_session;
 use merzah::{
+    messages,
     models::{
         api_responses::ApiResponse,
         auth::{Platform, RegistrationFormData},
-        mosque::{MosqueFromOverpass, MosqueSearchResult},
-        user::{Identifier, User},
+        mosque::{
+            AdoptionRequestOnClient, AdoptionRequestStatus, MosqueAdminOnClient,
+            MosqueFromOverpass, MosqueSearchResult,
+        },
+        user::{Identifier, User, UserOnClient},
     },
     spawn_app,
 };
@@ -22,6 +26,25 @@ struct AddAdminPayload {
     mosque_id: String,
 }
 
+#[derive(Serialize)]
+struct RemoveAdminQuery {
+    requested_user: String,
+    mosque_id: String,
+}
+
+#[derive(Serialize)]
+struct MosqueIdPayload {
+    mosque_id: String,
+}
+
+#[derive(Serialize)]
+struct RequestIdPayload {
+    request_id: String,
+}
+
+#[derive(Serialize)]
+struct NoPayload {}
+
 #[derive(Serialize)]
 struct Role {
     role: String,
@@ -71,6 +94,52 @@ async fn create_user(
     (user, session_token)
 }
 
+async fn create_mosque(db: &Surreal<SurrealClient>, key: &str) -> RecordId {
+    let mosque_id = RecordId::from(("mosques", key));
+    let _: Option<MosqueSearchResult> = db
+        .create("mosques")
+        .content(MosqueFromOverpass::new(
+            mosque_id.clone(),
+            Some(key.to_string()),
+            Geometry::Point((9.00, 8.00).into()),
+            None,
+            None,
+        ))
+        .await
+        .expect("failed to create a new mosque");
+
+    mosque_id
+}
+
+async fn post_as<T: Serialize>(
+    client: &Client,
+    url: String,
+    session: &str,
+    payload: &T,
+) -> reqwest::Response {
+    client
+        .post(url)
+        .header("Authorization", format!("Bearer {}", session))
+        .json(payload)
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn handles_of(
+    db: &Surreal<SurrealClient>,
+    user: &RecordId,
+    mosque: &RecordId,
+) -> Vec<Handle> {
+    db.query("SELECT * FROM handles WHERE in = $user AND out = $mosque")
+        .bind(("user", user.clone()))
+        .bind(("mosque", mosque.clone()))
+        .await
+        .expect("Query failed")
+        .take(0)
+        .expect("Take failed")
+}
+
 #[rstest]
 #[case::is_supervisor("mosque_supervisor", true, None)]
 #[case::not_supervisor("regular", false, Some("not a mosque_supervisor"))]
@@ -98,6 +167,7 @@ async fn test_add_admin_endpoint(
         .content(MosqueFromOverpass {
             id: RecordId::from(("mosque", "test_mosque_1")),
             name: Some("test_mosque_1".to_string()),
+            normalized_name: None,
             location: Geometry::Point((9.00, 8.00).into()),
             city: None,
             street: None,
@@ -134,7 +204,7 @@ async fn test_add_admin_endpoint(
         );
         assert_eq!(
             api_response.data,
-            Some("Elevated the user to a requested_user".to_string())
+            Some(messages::USER_ELEVATED_TO_REQUESTED_USER.to_string())
         );
 
         // Verify Relation in DB
@@ -177,3 +247,307 @@ async fn test_add_admin_endpoint(
         }
     }
 }
+
+#[tokio::test]
+async fn test_adoption_request_approval_lifecycle() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (supervisor, supervisor_session) = create_user(
+        &db,
+        "Supervisor",
+        "adoption_super@test.com",
+        Some("mosque_supervisor"),
+    )
+    .await;
+    let (requester, requester_session) =
+        create_user(&db, "Requester", "adoption_user@test.com", Some("regular")).await;
+    let mosque_id = create_mosque(&db, "adoption_approved_mosque").await;
+
+    let request_url = format!("{}/mosques/request-mosque-adoption", addr);
+    let payload = MosqueIdPayload {
+        mosque_id: mosque_id.to_string(),
+    };
+    let response = post_as(&client, request_url.clone(), &requester_session, &payload).await;
+    assert_eq!(response.status(), 201);
+    let request_id = response
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response")
+        .data
+        .expect("No request id returned");
+
+    // A second request for the same mosque is refused while the first one is pending
+    let response = post_as(&client, request_url, &requester_session, &payload).await;
+    assert_eq!(response.status(), 409);
+
+    // Regular users cannot see or review adoption requests
+    let list_url = format!("{}/mosques/list-adoption-requests", addr);
+    let response = post_as(&client, list_url.clone(), &requester_session, &NoPayload {}).await;
+    assert_eq!(response.status(), 403);
+
+    let response = post_as(
+        &client,
+        list_url.clone(),
+        &supervisor_session,
+        &NoPayload {},
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+    let pending = response
+        .json::<ApiResponse<Vec<AdoptionRequestOnClient>>>()
+        .await
+        .expect("Failed to deserialize response")
+        .data
+        .expect("No adoption requests returned");
+    let listed = pending
+        .iter()
+        .find(|request| request.id == request_id)
+        .expect("Pending request should be listed");
+    assert_eq!(listed.user.id, requester.id.to_string());
+    assert_eq!(listed.mosque_id, mosque_id.to_string());
+    assert_eq!(listed.status, AdoptionRequestStatus::Pending);
+
+    let approve_url = format!("{}/mosques/approve-adoption-request", addr);
+    let review_payload = RequestIdPayload {
+        request_id: request_id.clone(),
+    };
+    let response = post_as(
+        &client,
+        approve_url.clone(),
+        &supervisor_session,
+        &review_payload,
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+
+    let handles = handles_of(&db, &requester.id, &mosque_id).await;
+    assert_eq!(handles.len(), 1);
+    assert_eq!(handles[0].granted_by, supervisor.id);
+
+    // Reviewed requests leave the pending list and cannot be reviewed again
+    let response = post_as(&client, approve_url, &supervisor_session, &review_payload).await;
+    assert_eq!(response.status(), 409);
+
+    let response = post_as(&client, list_url, &supervisor_session, &NoPayload {}).await;
+    let pending = response
+        .json::<ApiResponse<Vec<AdoptionRequestOnClient>>>()
+        .await
+        .expect("Failed to deserialize response")
+        .data
+        .expect("No adoption requests returned");
+    assert!(pending.iter().all(|request| request.id != request_id));
+}
+
+#[tokio::test]
+async fn test_adoption_request_rejection() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (_, supervisor_session) = create_user(
+        &db,
+        "Supervisor",
+        "rejecting_super@test.com",
+        Some("mosque_supervisor"),
+    )
+    .await;
+    let (requester, requester_session) =
+        create_user(&db, "Requester", "rejected_user@test.com", Some("regular")).await;
+    let mosque_id = create_mosque(&db, "adoption_rejected_mosque").await;
+
+    let request_url = format!("{}/mosques/request-mosque-adoption", addr);
+    let payload = MosqueIdPayload {
+        mosque_id: mosque_id.to_string(),
+    };
+    let request_id = post_as(&client, request_url.clone(), &requester_session, &payload)
+        .await
+        .json::<ApiResponse<String>>()
+        .await
+        .expect("Failed to deserialize response")
+        .data
+        .expect("No request id returned");
+
+    let response = post_as(
+        &client,
+        format!("{}/mosques/reject-adoption-request", addr),
+        &supervisor_session,
+        &RequestIdPayload { request_id },
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+
+    assert!(handles_of(&db, &requester.id, &mosque_id).await.is_empty());
+
+    // Once rejected, the user is free to ask again
+    let response = post_as(&client, request_url, &requester_session, &payload).await;
+    assert_eq!(response.status(), 201);
+}
+
+#[rstest]
+#[case::supervisor_revokes("mosque_supervisor", true, 200, false)]
+#[case::app_admin_revokes("app_admin", true, 200, false)]
+#[case::nothing_to_revoke("mosque_supervisor", false, 404, false)]
+#[case::regular_user_cannot_revoke("regular", true, 403, true)]
+#[tokio::test]
+async fn test_remove_admin_revokes_the_handles_edge(
+    #[case] revoker_role: &str,
+    #[case] is_admin: bool,
+    #[case] expected_status: u16,
+    #[case] still_admin: bool,
+) {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+
+    let (supervisor, _) = create_user(
+        &db,
+        "Supervisor",
+        "super@test.com",
+        Some("mosque_supervisor"),
+    )
+    .await;
+    let (revoker, revoker_session) =
+        create_user(&db, "Revoker", "revoker@test.com", Some(revoker_role)).await;
+    let (admin, _) = create_user(&db, "Admin", "admin@test.com", None).await;
+    let mosque = create_mosque(&db, "revoked_mosque").await;
+
+    if is_admin {
+        db.query("RELATE $user -> handles -> $mosque SET granted_by = $supervisor")
+            .bind(("user", admin.id.clone()))
+            .bind(("mosque", mosque.clone()))
+            .bind(("supervisor", supervisor.id.clone()))
+            .await
+            .expect("Failed to grant the admin")
+            .check()
+            .expect("Failed to grant the admin");
+    }
+
+    let response = client
+        .delete(format!("{}/mosques/remove-admin", addr))
+        .query(&RemoveAdminQuery {
+            requested_user: admin.id.to_string(),
+            mosque_id: mosque.to_string(),
+        })
+        .header("Authorization", format!("Bearer {}", revoker_session))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status().as_u16(), expected_status);
+
+    if expected_status == 200 {
+        let api_response: ApiResponse<String> =
+            response.json().await.expect("Failed to deserialize");
+        assert_eq!(
+            api_response.data,
+            Some(messages::MOSQUE_ADMIN_REMOVED.to_string())
+        );
+    }
+    assert_eq!(
+        !handles_of(&db, &admin.id, &mosque).await.is_empty(),
+        still_admin
+    );
+}
+
+async fn grant_admin(
+    db: &Surreal<SurrealClient>,
+    user: &RecordId,
+    mosque: &RecordId,
+    granted_by: &RecordId,
+) {
+    db.query("RELATE $user -> handles -> $mosque SET granted_by = $granted_by")
+        .bind(("user", user.clone()))
+        .bind(("mosque", mosque.clone()))
+        .bind(("granted_by", granted_by.clone()))
+        .await
+        .expect("Failed to grant the admin")
+        .check()
+        .expect("Failed to grant the admin");
+}
+
+#[tokio::test]
+async fn test_list_mosque_admins() {
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let client = Client::new();
+    let url = format!("{}/mosques/list-mosque-admins", addr);
+
+    let (supervisor, supervisor_session) = create_user(
+        &db,
+        "Supervisor",
+        "super@test.com",
+        Some("mosque_supervisor"),
+    )
+    .await;
+    let (first_admin, _) = create_user(&db, "First Admin", "first@test.com", None).await;
+    let (second_admin, _) = create_user(&db, "Second Admin", "second@test.com", None).await;
+    let (other_admin, other_session) =
+        create_user(&db, "Other Admin", "other@test.com", None).await;
+    let mosque = create_mosque(&db, "listed_mosque").await;
+    let other_mosque = create_mosque(&db, "other_listed_mosque").await;
+    let empty_mosque = create_mosque(&db, "unadministered_mosque").await;
+
+    grant_admin(&db, &first_admin.id, &mosque, &supervisor.id).await;
+    grant_admin(&db, &second_admin.id, &mosque, &supervisor.id).await;
+    grant_admin(&db, &other_admin.id, &other_mosque, &supervisor.id).await;
+
+    let response = post_as(
+        &client,
+        url.clone(),
+        &supervisor_session,
+        &MosqueIdPayload {
+            mosque_id: mosque.to_string(),
+        },
+    )
+    .await;
+    assert_eq!(response.status().as_u16(), 200);
+    let admins = response
+        .json::<ApiResponse<Vec<MosqueAdminOnClient>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No admins returned");
+    assert_eq!(
+        admins,
+        vec![
+            MosqueAdminOnClient {
+                user: UserOnClient::from(first_admin),
+                granted_by: Some(UserOnClient::from(supervisor.clone())),
+            },
+            MosqueAdminOnClient {
+                user: UserOnClient::from(second_admin),
+                granted_by: Some(UserOnClient::from(supervisor)),
+            },
+        ]
+    );
+
+    let response = post_as(
+        &client,
+        url.clone(),
+        &supervisor_session,
+        &MosqueIdPayload {
+            mosque_id: empty_mosque.to_string(),
+        },
+    )
+    .await;
+    assert_eq!(response.status().as_u16(), 200);
+    let admins = response
+        .json::<ApiResponse<Vec<MosqueAdminOnClient>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No admins returned");
+    assert!(admins.is_empty());
+
+    let response = post_as(
+        &client,
+        url,
+        &other_session,
+        &MosqueIdPayload {
+            mosque_id: mosque.to_string(),
+        },
+    )
+    .await;
+    assert_eq!(response.status().as_u16(), 403);
+}
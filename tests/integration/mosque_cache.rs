@@ -0,0 +1,102 @@
+use crate::common::get_test_db;
+use chrono::NaiveTime;
+use merzah::{
+    models::mosque::{MosqueRecord, PrayerTimes},
+    services::mosque_cache::MosqueCache,
+};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use surrealdb::{RecordId, sql::Geometry};
+
+#[derive(Serialize)]
+struct CreateMosque {
+    location: Geometry,
+    name: String,
+}
+
+#[derive(Serialize)]
+struct AdhanTimes {
+    adhan_times: PrayerTimes,
+}
+
+fn prayer_times(fajr_hour: u32) -> PrayerTimes {
+    PrayerTimes {
+        fajr: NaiveTime::from_hms_opt(fajr_hour, 0, 0).unwrap(),
+        dhuhr: NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+        asr: NaiveTime::from_hms_opt(16, 30, 0).unwrap(),
+        maghrib: NaiveTime::from_hms_opt(19, 45, 0).unwrap(),
+        isha: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+        jummah: NaiveTime::from_hms_opt(13, 30, 0).unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn test_mosque_cache_serves_until_invalidated() {
+    let db = get_test_db().await;
+    let cache = MosqueCache::new(Duration::from_secs(60));
+    let mosque_id = RecordId::from(("mosques", "cached_mosque"));
+
+    let _: Option<MosqueRecord> = db
+        .create(mosque_id.clone())
+        .content(CreateMosque {
+            location: Geometry::Point((77.21, 28.61).into()),
+            name: "Cached Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque");
+
+    let cached = cache
+        .get(&mosque_id, &db)
+        .await
+        .expect("Failed to fetch the mosque")
+        .expect("Mosque not found");
+    assert_eq!(cached.adhan_times, None);
+
+    let _: Option<MosqueRecord> = db
+        .update(mosque_id.clone())
+        .merge(AdhanTimes {
+            adhan_times: prayer_times(5),
+        })
+        .await
+        .expect("Failed to update the mosque");
+
+    let stale = cache
+        .get(&mosque_id, &db)
+        .await
+        .expect("Failed to fetch the mosque")
+        .expect("Mosque not found");
+    assert_eq!(stale.adhan_times, None);
+
+    cache.invalidate(&mosque_id);
+
+    let fresh = cache
+        .get(&mosque_id, &db)
+        .await
+        .expect("Failed to fetch the mosque")
+        .expect("Mosque not found");
+    assert_eq!(fresh.adhan_times, Some(prayer_times(5)));
+}
+
+#[tokio::test]
+async fn test_mosque_cache_entries_expire() {
+    let db = get_test_db().await;
+    let ttl = Duration::from_secs(60);
+    let cache = MosqueCache::new(ttl);
+    let mosque_id = RecordId::from(("mosques", "expiring_mosque"));
+
+    let mosque: MosqueRecord = db
+        .create(mosque_id.clone())
+        .content(CreateMosque {
+            location: Geometry::Point((77.21, 28.61).into()),
+            name: "Expiring Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Mosque not returned");
+
+    let now = Instant::now();
+    cache.insert_at(mosque, now);
+
+    assert!(cache.get_at(&mosque_id, now + ttl / 2).is_some());
+    assert!(cache.get_at(&mosque_id, now + ttl).is_none());
+}
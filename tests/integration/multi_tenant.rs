@@ -0,0 +1,113 @@
+use crate::common::get_test_db;
+use merzah::{
+    database::tenants::{TENANT_HEADER, TenantConnections, TenantTarget, parse_tenant_entries},
+    models::{
+        api_responses::{ApiResponse, MosqueResponse},
+        mosque::MosqueRecord,
+    },
+    spawn_app_with_tenants,
+};
+use reqwest::Client;
+use rstest::rstest;
+use serde::Serialize;
+use surrealdb::sql::Geometry;
+
+#[derive(Serialize)]
+struct CreateMosque {
+    location: Geometry,
+    name: String,
+}
+
+#[derive(Serialize)]
+struct FetchMosqueParams {
+    lat: f64,
+    lon: f64,
+}
+
+#[rstest]
+#[case::north(Some("north"), 200, Some("North Mosque"))]
+#[case::south(Some("south"), 200, Some("South Mosque"))]
+#[case::header_is_case_insensitive(Some("NORTH"), 200, Some("North Mosque"))]
+#[case::no_tenant_uses_the_default_db(None, 200, None)]
+#[case::unknown_tenant(Some("east"), 400, None)]
+#[tokio::test]
+async fn test_requests_are_routed_to_their_tenants_database(
+    #[case] tenant: Option<&str>,
+    #[case] expected_status: u16,
+    #[case] expected_mosque: Option<&str>,
+) {
+    let client = Client::new();
+    let default_db = get_test_db().await;
+    let north_db = get_test_db().await;
+    let south_db = get_test_db().await;
+
+    for (db, name) in [(&north_db, "North Mosque"), (&south_db, "South Mosque")] {
+        let _: Option<MosqueRecord> = db
+            .create("mosques")
+            .content(CreateMosque {
+                location: Geometry::Point((77.2100, 28.6100).into()),
+                name: name.to_string(),
+            })
+            .await
+            .expect("Failed to create mosque");
+    }
+
+    let tenants = TenantConnections::new()
+        .with_tenant("north", north_db)
+        .with_tenant("south", south_db);
+    let addr = spawn_app_with_tenants(default_db, tenants);
+
+    let mut request = client
+        .get(format!("{}/mosques/fetch-mosques-for-location", addr))
+        .query(&FetchMosqueParams {
+            lat: 28.6200,
+            lon: 77.2150,
+        });
+    if let Some(tenant) = tenant {
+        request = request.header(TENANT_HEADER, tenant);
+    }
+    let response = request.send().await.expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), expected_status);
+    if expected_status != 200 {
+        return;
+    }
+    let mosques = response
+        .json::<ApiResponse<Vec<MosqueResponse>>>()
+        .await
+        .expect("Failed to deserialize")
+        .data
+        .expect("No mosques data");
+    let names: Vec<String> = mosques
+        .into_iter()
+        .filter_map(|mosque| mosque.name)
+        .collect();
+    let expected: Vec<String> = expected_mosque.into_iter().map(str::to_string).collect();
+    assert_eq!(names, expected);
+}
+
+#[rstest]
+#[case::well_formed(" north = merzah_north/merzah ,south=merzah_south/merzah", &["north", "south"])]
+#[case::missing_database("north=merzah_north,south=merzah_south/merzah", &["south"])]
+#[case::missing_tenant("=merzah_north/merzah,south=merzah_south/merzah", &["south"])]
+#[case::no_separator("north,south=merzah_south/merzah", &["south"])]
+#[case::empty("", &[])]
+fn test_malformed_tenant_entries_are_skipped(#[case] entries: &str, #[case] expected: &[&str]) {
+    let tenants: Vec<String> = parse_tenant_entries(entries)
+        .into_iter()
+        .map(|target| target.tenant)
+        .collect();
+    assert_eq!(tenants, expected);
+}
+
+#[test]
+fn test_tenant_entries_are_trimmed() {
+    assert_eq!(
+        parse_tenant_entries(" north = merzah_north / merzah "),
+        vec![TenantTarget {
+            tenant: "north".to_string(),
+            namespace: "merzah_north".to_string(),
+            database: "merzah".to_string(),
+        }]
+    );
+}
@@ -0,0 +1,115 @@
+use crate::common::{RecordingNotifier, get_test_db};
+use merzah::{
+    auth::{
+        custom_auth::{authenticate, register_user},
+        password_reset::create_password_reset,
+    },
+    messages,
+    models::{
+        api_responses::ApiResponse,
+        auth::{LoginFormData, Platform, RegistrationFormData},
+        user::Identifier,
+    },
+    spawn_app,
+};
+use reqwest::Client;
+use serde::Serialize;
+use surrealdb::{Surreal, engine::remote::ws::Client as SurrealClient};
+
+const RESET_URL: &str = "http://localhost:3000/reset-password";
+const OLD_PASSWORD: &str = "thisisasecret";
+const NEW_PASSWORD: &str = "anewsecretpassword";
+
+#[derive(Serialize)]
+struct ResetPasswordPayload {
+    token: String,
+    new_password: String,
+}
+
+async fn register_email_user(db: &Surreal<SurrealClient>) -> Identifier {
+    let identifier = Identifier::Email(format!("reset_{}@example.com", uuid::Uuid::new_v4()));
+    let form = RegistrationFormData::new(
+        "Armaan Ali".to_string(),
+        identifier.clone(),
+        OLD_PASSWORD.to_string(),
+        Platform::Web,
+    );
+    register_user(form, db)
+        .await
+        .expect("Failed to register user");
+    identifier
+}
+
+async fn can_log_in(identifier: &Identifier, password: &str, db: &Surreal<SurrealClient>) -> bool {
+    let form = LoginFormData {
+        identifier: identifier.clone(),
+        password: password.to_string(),
+        platform: Platform::Web,
+    };
+    authenticate(form, db).await.is_ok()
+}
+
+async fn reset(client: &Client, addr: &str, token: &str, new_password: &str) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/reset-password", addr))
+        .json(&ResetPasswordPayload {
+            token: token.to_string(),
+            new_password: new_password.to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to send a request")
+}
+
+#[tokio::test]
+async fn test_password_reset_sets_the_new_password_once() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let notifier = RecordingNotifier::default();
+    let identifier = register_email_user(&db).await;
+
+    create_password_reset(&identifier, RESET_URL, &notifier, &db)
+        .await
+        .expect("Failed to request a password reset");
+    let token = notifier.last_email_token();
+
+    let response = reset(&client, &addr, &token, NEW_PASSWORD).await;
+    assert_eq!(response.status().as_u16(), 200);
+    let api_response: ApiResponse<String> = response.json().await.expect("Failed to deserialize");
+    assert_eq!(
+        api_response.data,
+        Some(messages::PASSWORD_RESET.to_string())
+    );
+
+    assert!(can_log_in(&identifier, NEW_PASSWORD, &db).await);
+    assert!(!can_log_in(&identifier, OLD_PASSWORD, &db).await);
+
+    let response = reset(&client, &addr, &token, "yetanotherpassword").await;
+    assert_eq!(response.status().as_u16(), 400);
+    assert!(can_log_in(&identifier, NEW_PASSWORD, &db).await);
+}
+
+#[tokio::test]
+async fn test_expired_password_reset_is_rejected() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let notifier = RecordingNotifier::default();
+    let identifier = register_email_user(&db).await;
+
+    create_password_reset(&identifier, RESET_URL, &notifier, &db)
+        .await
+        .expect("Failed to request a password reset");
+    db.query("UPDATE password_resets SET expires_at = time::now() - 1m")
+        .await
+        .expect("Failed to expire the token");
+
+    let response = reset(&client, &addr, &notifier.last_email_token(), NEW_PASSWORD).await;
+    assert_eq!(response.status().as_u16(), 400);
+    let api_response: ApiResponse<String> = response.json().await.expect("Failed to deserialize");
+    assert!(api_response.error.is_some());
+
+    assert!(can_log_in(&identifier, OLD_PASSWORD, &db).await);
+    assert!(!can_log_in(&identifier, NEW_PASSWORD, &db).await);
+}
@@ -0,0 +1,115 @@
+use crate::common::get_test_db;
+use actix_web::{HttpRequest, test::TestRequest};
+use leptos::prelude::{Owner, ScopedFuture};
+use merzah::{
+    auth::{custom_auth::register_user, session::create_session},
+    database::instrumented::{CountingDb, QueryCounter},
+    models::{
+        api_responses::ApiResponse,
+        auth::{Platform, RegistrationFormData},
+        user::{Identifier, User, UserOnClient},
+    },
+    spawn_app_with_query_counter,
+    utils::ssr::authenticated_user_of,
+};
+use reqwest::Client;
+use rstest::rstest;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct NoArgs {}
+
+#[rstest]
+#[case::fresh_session(false, 1)]
+#[case::refreshed_session(true, 2)]
+#[tokio::test]
+async fn test_authenticated_request_looks_up_the_session_once(
+    #[case] about_to_expire: bool,
+    #[case] expected_queries: usize,
+) {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let counter = QueryCounter::default();
+    let addr = spawn_app_with_query_counter(db.clone(), counter.clone());
+
+    let form = RegistrationFormData::new(
+        "Counted User".to_string(),
+        Identifier::Email(format!("counted_{}@example.com", uuid::Uuid::new_v4())),
+        "thisisasecret".to_string(),
+        Platform::Mobile,
+    );
+    let user = register_user(form, &db)
+        .await
+        .expect("Failed to register user");
+    let session_token = create_session(user, &db)
+        .await
+        .expect("Failed to create a session");
+    if about_to_expire {
+        db.query("UPDATE sessions SET expires_at = time::now() + 5m")
+            .await
+            .expect("Failed to move the session's expiry");
+    }
+
+    counter.reset();
+    let response = client
+        .post(format!("{}/auth/me", addr))
+        .header("Authorization", format!("Bearer {}", session_token))
+        .json(&NoArgs {})
+        .send()
+        .await
+        .expect("Failed to send a request");
+
+    assert_eq!(response.status().as_u16(), 200);
+    let api_response = response
+        .json::<ApiResponse<UserOnClient>>()
+        .await
+        .expect("Failed to deserialize response");
+    assert_eq!(
+        api_response.data.map(|user| user.display_name),
+        Some("Counted User".to_string())
+    );
+    assert_eq!(counter.count(), expected_queries);
+}
+
+/// What a server function and a helper it calls both authenticating in one request look like.
+async fn authenticate_twice(req: &HttpRequest, db: &CountingDb) -> (Option<User>, Option<User>) {
+    let first = authenticated_user_of(req, db).await;
+    let second = authenticated_user_of(req, db).await;
+    (first, second)
+}
+
+#[tokio::test]
+async fn test_authenticating_twice_in_a_request_looks_up_the_session_once() {
+    let db = get_test_db().await;
+    let counter = QueryCounter::default();
+    let counted_db = CountingDb::new(db.clone(), Some(counter.clone()));
+
+    let form = RegistrationFormData::new(
+        "Memoized User".to_string(),
+        Identifier::Email(format!("memoized_{}@example.com", uuid::Uuid::new_v4())),
+        "thisisasecret".to_string(),
+        Platform::Mobile,
+    );
+    let user = register_user(form, &db)
+        .await
+        .expect("Failed to register user");
+    let session_token = create_session(user.clone(), &db)
+        .await
+        .expect("Failed to create a session");
+    let req = TestRequest::default()
+        .insert_header(("Authorization", format!("Bearer {}", session_token)))
+        .to_http_request();
+
+    let (first, second) = Owner::new()
+        .with(|| ScopedFuture::new(authenticate_twice(&req, &counted_db)))
+        .await;
+    assert_eq!(first.map(|user| user.id), Some(user.clone()));
+    assert_eq!(second.map(|user| user.id), Some(user.clone()));
+    assert_eq!(counter.count(), 1);
+
+    // The next request looks the session up again
+    Owner::new()
+        .with(|| ScopedFuture::new(authenticate_twice(&req, &counted_db)))
+        .await;
+    assert_eq!(counter.count(), 2);
+}
@@ -0,0 +1,56 @@
+use crate::common::get_test_db;
+use merzah::spawn_app;
+use reqwest::Client;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct NoArgs {}
+
+async fn call_current_user(client: &Client, addr: &str, request_id: Option<&str>) -> String {
+    let mut request = client
+        .post(format!("{}/auth/current-user", addr))
+        .json(&NoArgs {});
+    if let Some(request_id) = request_id {
+        request = request.header("X-Request-Id", request_id);
+    }
+
+    let response = request.send().await.expect("Failed to send a request");
+    assert_eq!(response.status().as_u16(), 401);
+    response
+        .headers()
+        .get("x-request-id")
+        .expect("The response has no X-Request-Id")
+        .to_str()
+        .expect("The X-Request-Id is not valid text")
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_response_carries_a_fresh_request_id() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db);
+
+    let first = call_current_user(&client, &addr, None).await;
+    let second = call_current_user(&client, &addr, None).await;
+
+    assert!(
+        uuid::Uuid::parse_str(&first).is_ok(),
+        "{first} is not a UUID"
+    );
+    assert_ne!(first, second);
+}
+
+#[tokio::test]
+async fn test_incoming_request_id_is_echoed_back() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db);
+
+    let request_id = call_current_user(&client, &addr, Some("mobile-7f3a9c")).await;
+    assert_eq!(request_id, "mobile-7f3a9c");
+
+    let request_id = call_current_user(&client, &addr, Some("has spaces")).await;
+    assert_ne!(request_id, "has spaces");
+    assert!(uuid::Uuid::parse_str(&request_id).is_ok());
+}
@@ -0,0 +1,59 @@
+use crate::common::get_test_db;
+use merzah::jobs::token_cleanup::{EXPIRING_TOKEN_TABLES, clear_expired_tokens};
+use surrealdb::{Surreal, engine::remote::ws::Client};
+
+async fn count_rows(db: &Surreal<Client>, table: &str) -> usize {
+    let count: Option<usize> = db
+        .query("SELECT VALUE count() FROM type::table($table) GROUP ALL")
+        .bind(("table", table.to_string()))
+        .await
+        .expect("Failed to count rows")
+        .take(0)
+        .expect("Failed to read the count");
+    count.unwrap_or(0)
+}
+
+#[tokio::test]
+async fn test_clear_expired_tokens_keeps_unexpired_ones() {
+    let db = get_test_db().await;
+
+    db.query(
+        r#"
+            CREATE sessions
+                SET user = users:expired, session_token = 'expired', expires_at = time::now() - 1h;
+            CREATE sessions
+                SET user = users:active, session_token = 'active', expires_at = time::now() + 1h;
+            CREATE password_resets
+                SET user = users:expired, token = 'expired_reset', expires_at = time::now() - 1m;
+            CREATE password_resets
+                SET user = users:expired, token = 'older_reset', expires_at = time::now() - 1d;
+            CREATE password_resets
+                SET user = users:active, token = 'active_reset', expires_at = time::now() + 15m;
+            CREATE mobile_otps
+                SET identifier = user_identifier:expired, code_hash = 'hash',
+                    expires_at = time::now() - 1m;
+            CREATE magic_links
+                SET user = users:active, token = 'active_link', expires_at = time::now() + 15m;
+        "#,
+    )
+    .await
+    .expect("Failed to insert tokens")
+    .check()
+    .expect("Failed to insert tokens");
+
+    let cleared = clear_expired_tokens(&db)
+        .await
+        .expect("Failed to clear expired tokens");
+
+    assert_eq!(cleared.len(), EXPIRING_TOKEN_TABLES.len());
+    assert_eq!(cleared["sessions"], 1);
+    assert_eq!(cleared["password_resets"], 2);
+    assert_eq!(cleared["verification_tokens"], 0);
+    assert_eq!(cleared["mobile_otps"], 1);
+    assert_eq!(cleared["magic_links"], 0);
+
+    assert_eq!(count_rows(&db, "sessions").await, 1);
+    assert_eq!(count_rows(&db, "password_resets").await, 1);
+    assert_eq!(count_rows(&db, "mobile_otps").await, 0);
+    assert_eq!(count_rows(&db, "magic_links").await, 1);
+}
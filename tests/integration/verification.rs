@@ -0,0 +1,189 @@
+use crate::common::{RecordingNotifier, get_test_db};
+use merzah::{
+    auth::{custom_auth::register_user, session::create_session, verification::send_verification},
+    errors::verification::VerificationError,
+    messages,
+    models::{
+        api_responses::ApiResponse,
+        auth::{Platform, RegistrationFormData},
+        mosque::MosqueRecord,
+        user::{Identifier, User},
+    },
+    spawn_app,
+};
+use reqwest::Client;
+use serde::Serialize;
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client as SurrealClient, sql::Geometry};
+
+const LINK_URL: &str = "http://localhost:3000/auth/verify";
+
+#[derive(Serialize)]
+struct ConfirmVerificationPayload {
+    token: String,
+}
+
+#[derive(Serialize)]
+struct MosqueIdPayload {
+    mosque_id: String,
+}
+
+#[derive(Serialize)]
+struct EventIdPayload {
+    event_id: String,
+}
+
+#[derive(Serialize)]
+struct CreateMosque {
+    location: Geometry,
+    name: String,
+}
+
+async fn register_email_user(db: &Surreal<SurrealClient>) -> (RecordId, Identifier, String) {
+    let identifier = Identifier::Email(format!("verify_{}@example.com", uuid::Uuid::new_v4()));
+    let form = RegistrationFormData::new(
+        "Armaan Ali".to_string(),
+        identifier.clone(),
+        "thisisasecret".to_string(),
+        Platform::Mobile,
+    );
+    let user = register_user(form, db)
+        .await
+        .expect("Failed to register user");
+    let session = create_session(user.clone(), db)
+        .await
+        .expect("Failed to create a session");
+    (user, identifier, session)
+}
+
+async fn is_verified(user: &RecordId, db: &Surreal<SurrealClient>) -> bool {
+    let user: Option<User> = db.select(user.clone()).await.expect("Failed to fetch user");
+    user.expect("User not found").is_verified()
+}
+
+async fn confirm(client: &Client, addr: &str, token: &str) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/confirm-verification", addr))
+        .json(&ConfirmVerificationPayload {
+            token: token.to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to send a request")
+}
+
+async fn add_favorite(
+    client: &Client,
+    addr: &str,
+    session: &str,
+    mosque_id: &RecordId,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/mosques/add-favorite", addr))
+        .header("Authorization", format!("Bearer {}", session))
+        .json(&MosqueIdPayload {
+            mosque_id: mosque_id.to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to send a request")
+}
+
+#[tokio::test]
+async fn test_confirming_a_verification_link_verifies_the_user_once() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let notifier = RecordingNotifier::default();
+    let (user, identifier, _session) = register_email_user(&db).await;
+    assert!(!is_verified(&user, &db).await);
+
+    send_verification(&user, &identifier, LINK_URL, &notifier, &db)
+        .await
+        .expect("Failed to send a verification link");
+    let token = notifier.last_email_token();
+
+    let response = confirm(&client, &addr, &token).await;
+    assert_eq!(response.status().as_u16(), 200);
+    let api_response: ApiResponse<String> = response.json().await.expect("Failed to deserialize");
+    assert_eq!(
+        api_response.data,
+        Some(messages::ACCOUNT_VERIFIED.to_string())
+    );
+    assert!(is_verified(&user, &db).await);
+
+    let response = confirm(&client, &addr, &token).await;
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[tokio::test]
+async fn test_expired_verification_link_is_rejected() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let notifier = RecordingNotifier::default();
+    let (user, identifier, _session) = register_email_user(&db).await;
+
+    send_verification(&user, &identifier, LINK_URL, &notifier, &db)
+        .await
+        .expect("Failed to send a verification link");
+    db.query("UPDATE verification_tokens SET expires_at = time::now() - 1m")
+        .await
+        .expect("Failed to expire the token");
+
+    let response = confirm(&client, &addr, &notifier.last_email_token()).await;
+    assert_eq!(response.status().as_u16(), 400);
+    assert!(!is_verified(&user, &db).await);
+}
+
+#[tokio::test]
+async fn test_unverified_users_cannot_favorite_or_rsvp() {
+    let client = Client::new();
+    let db = get_test_db().await;
+    let addr = spawn_app(db.clone());
+    let notifier = RecordingNotifier::default();
+    let (user, identifier, session) = register_email_user(&db).await;
+    let mosque: MosqueRecord = db
+        .create("mosques")
+        .content(CreateMosque {
+            location: Geometry::Point((0.0, 0.0).into()),
+            name: "Verification Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Not returned");
+
+    let response = add_favorite(&client, &addr, &session, &mosque.id).await;
+    assert_eq!(response.status().as_u16(), 403);
+
+    let response = client
+        .post(format!("{}/mosques/events/rsvp", addr))
+        .header("Authorization", format!("Bearer {}", session))
+        .json(&EventIdPayload {
+            event_id: "events:does_not_exist".to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to send a request");
+    assert_eq!(response.status().as_u16(), 403);
+
+    send_verification(&user, &identifier, LINK_URL, &notifier, &db)
+        .await
+        .expect("Failed to send a verification link");
+    let response = confirm(&client, &addr, &notifier.last_email_token()).await;
+    assert_eq!(response.status().as_u16(), 200);
+
+    let response = add_favorite(&client, &addr, &session, &mosque.id).await;
+    assert_eq!(response.status().as_u16(), 200);
+}
+
+#[tokio::test]
+async fn test_verification_is_only_sent_to_the_users_own_identifiers() {
+    let db = get_test_db().await;
+    let notifier = RecordingNotifier::default();
+    let (user, _identifier, _session) = register_email_user(&db).await;
+    let (_other_user, other_identifier, _other_session) = register_email_user(&db).await;
+
+    let result = send_verification(&user, &other_identifier, LINK_URL, &notifier, &db).await;
+    assert!(matches!(result, Err(VerificationError::IdentifierNotFound)));
+    assert!(notifier.emails.lock().unwrap().is_empty());
+}
@@ -0,0 +1,153 @@
+use crate::common::{RecordingNotifier, get_test_db};
+use chrono::{Duration, FixedOffset, Utc};
+use merzah::{
+    auth::custom_auth::register_user,
+    models::{
+        auth::{Platform, RegistrationFormData},
+        events::{Event, EventCategory, EventRecord},
+        mosque::MosqueRecord,
+        user::Identifier,
+    },
+    services::{
+        event_announcements::notify_promoted,
+        waitlist::{RsvpOutcome, cancel_rsvp, rsvp},
+    },
+};
+use serde::Serialize;
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client, sql::Geometry};
+
+#[derive(Serialize)]
+struct CreateMosque {
+    location: Geometry,
+    name: String,
+}
+
+async fn register(email: &str, db: &Surreal<Client>) -> RecordId {
+    let form = RegistrationFormData::new(
+        "Armaan Ali".to_string(),
+        Identifier::Email(email.to_string()),
+        "thisisasecret".to_string(),
+        Platform::Web,
+    );
+    register_user(form, db)
+        .await
+        .expect("Failed to register user")
+}
+
+async fn create_event(capacity: u32, db: &Surreal<Client>) -> Event {
+    let mosque: MosqueRecord = db
+        .create("mosques")
+        .content(CreateMosque {
+            location: Geometry::Point((0.0, 0.0).into()),
+            name: "Waitlist Mosque".to_string(),
+        })
+        .await
+        .expect("Failed to create mosque")
+        .expect("Not returned");
+
+    db.create("events")
+        .content(EventRecord {
+            title: "Tafsir Workshop".to_string(),
+            description: "Limited seating".to_string(),
+            category: EventCategory::Workshop,
+            date: Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()) + Duration::days(2),
+            mosque: mosque.id,
+            speaker: None,
+            recurrence_pattern: None,
+            recurrence_end_date: None,
+            recurrence_count: None,
+            timezone: None,
+            rsvp_deadline: None,
+            capacity: Some(capacity),
+        })
+        .await
+        .expect("Failed to create event")
+        .expect("Not returned")
+}
+
+async fn attendees(event: &Event, db: &Surreal<Client>) -> Vec<RecordId> {
+    db.query("SELECT VALUE in FROM attending WHERE out = $event")
+        .bind(("event", event.id.clone()))
+        .await
+        .expect("Failed to query the attendees")
+        .take(0)
+        .expect("Failed to read the attendees")
+}
+
+#[tokio::test]
+async fn test_cancelling_promotes_and_notifies_the_next_waitlisted_user() {
+    let db = get_test_db().await;
+    let notifier = RecordingNotifier::default();
+    let event = create_event(2, &db).await;
+
+    let first = register("first@example.com", &db).await;
+    let second = register("second@example.com", &db).await;
+    let waiting = register("waiting@example.com", &db).await;
+    let last = register("last@example.com", &db).await;
+    for (user, expected) in [
+        (&first, RsvpOutcome::Attending),
+        (&second, RsvpOutcome::Attending),
+        (&waiting, RsvpOutcome::Waitlisted),
+        (&last, RsvpOutcome::Waitlisted),
+    ] {
+        let outcome = rsvp(user.clone(), event.id.clone(), &db)
+            .await
+            .expect("Failed to RSVP");
+        assert_eq!(outcome, expected);
+    }
+
+    let cancelled = cancel_rsvp(first, event.id.clone(), &db)
+        .await
+        .expect("Failed to cancel the RSVP");
+    assert!(cancelled.cancelled);
+    assert_eq!(cancelled.promoted, vec![waiting.clone()]);
+
+    let mut attending = attendees(&event, &db).await;
+    attending.sort_by_key(|user| user.to_string());
+    let mut expected = vec![second, waiting];
+    expected.sort_by_key(|user| user.to_string());
+    assert_eq!(attending, expected);
+
+    let reached = notify_promoted(&event, cancelled.promoted, &notifier, &db)
+        .await
+        .expect("Failed to notify the promoted user");
+    assert_eq!(reached, 1);
+    let emails = notifier.emails.lock().unwrap();
+    assert_eq!(emails.len(), 1);
+    assert_eq!(emails[0].0, "waiting@example.com");
+}
+
+#[tokio::test]
+async fn test_simultaneous_cancellations_fill_every_place_once() {
+    let db = get_test_db().await;
+    let event = create_event(2, &db).await;
+
+    let mut users = Vec::new();
+    for name in ["first", "second", "third", "fourth", "fifth"] {
+        let user = register(&format!("{name}@example.com"), &db).await;
+        rsvp(user.clone(), event.id.clone(), &db)
+            .await
+            .expect("Failed to RSVP");
+        users.push(user);
+    }
+
+    let (first, second) = tokio::join!(
+        cancel_rsvp(users[0].clone(), event.id.clone(), &db),
+        cancel_rsvp(users[1].clone(), event.id.clone(), &db),
+    );
+    let mut promoted = first.expect("Failed to cancel the first RSVP").promoted;
+    promoted.extend(second.expect("Failed to cancel the second RSVP").promoted);
+    promoted.sort_by_key(|user| user.to_string());
+
+    let mut attending = attendees(&event, &db).await;
+    attending.sort_by_key(|user| user.to_string());
+    assert_eq!(attending.len(), 2, "The event should be back at capacity");
+    assert_eq!(
+        promoted, attending,
+        "Each open place should be promoted into once"
+    );
+    assert!(
+        !attending.contains(&users[4]),
+        "The last waitlisted user should still wait"
+    );
+}
@@ -1,9 +1,41 @@
+#[path = "unit/apple_oauth.rs"]
+mod apple_oauth;
 #[path = "unit/auth.rs"]
 mod auth;
 mod common;
+#[path = "unit/current_request.rs"]
+mod current_request;
+#[path = "unit/datetime.rs"]
+mod datetime;
+#[path = "unit/email_domain.rs"]
+mod email_domain;
+#[path = "unit/expiry.rs"]
+mod expiry;
+#[path = "unit/github_oauth.rs"]
+mod github_oauth;
+#[path = "unit/mosque_name.rs"]
+mod mosque_name;
+#[path = "unit/oauth_callback.rs"]
+mod oauth_callback;
+#[path = "unit/password.rs"]
+mod password;
+#[path = "unit/prayer_times.rs"]
+mod prayer_times;
+#[path = "unit/query.rs"]
+mod query;
+#[path = "unit/rate_limit.rs"]
+mod rate_limit;
 #[path = "unit/recurrence.rs"]
 mod recurrence;
+#[path = "unit/redacted.rs"]
+mod redacted;
+#[path = "unit/schedule.rs"]
+mod schedule;
 #[path = "unit/session.rs"]
 mod session;
+#[path = "unit/user.rs"]
+mod user;
 #[path = "unit/user_elevation.rs"]
 mod user_elevation;
+#[path = "unit/workos_oauth.rs"]
+mod workos_oauth;
@@ -0,0 +1,89 @@
+use chrono::Utc;
+use jsonwebtoken::{EncodingKey, Header, encode};
+use merzah::auth::oauth::apple::{AppleUserDetails, parse_apple_id_token};
+use rstest::rstest;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct IdTokenClaims {
+    iss: String,
+    aud: String,
+    sub: String,
+    email: Option<String>,
+    exp: i64,
+}
+
+fn id_token(aud: &str, exp: i64) -> String {
+    let claims = IdTokenClaims {
+        iss: "https://appleid.apple.com".to_string(),
+        aud: aud.to_string(),
+        sub: "001234.abcdef".to_string(),
+        email: Some("relay@privaterelay.appleid.com".to_string()),
+        exp,
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(b"test-secret"),
+    )
+    .expect("Failed to encode the ID token")
+}
+
+#[test]
+fn test_parse_apple_id_token_reads_subject_and_email() {
+    let token = id_token("com.merzah.web", Utc::now().timestamp() + 600);
+
+    let claims = parse_apple_id_token(&token, "com.merzah.web").expect("Token should be valid");
+
+    assert_eq!(claims.sub, "001234.abcdef");
+    assert_eq!(
+        claims.email.as_deref(),
+        Some("relay@privaterelay.appleid.com")
+    );
+}
+
+#[rstest]
+#[case::wrong_audience("com.other.app", 600)]
+#[case::expired("com.merzah.web", -600)]
+fn test_parse_apple_id_token_rejects_invalid_tokens(#[case] aud: &str, #[case] expires_in: i64) {
+    let token = id_token(aud, Utc::now().timestamp() + expires_in);
+
+    assert!(parse_apple_id_token(&token, "com.merzah.web").is_err());
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppleName {
+    first_name: Option<String>,
+    last_name: Option<String>,
+}
+
+#[derive(Serialize)]
+struct AppleUser {
+    name: Option<AppleName>,
+    email: Option<String>,
+}
+
+#[rstest]
+#[case::full_name(Some("Aisha"), Some("Khan"), Some("Aisha Khan"))]
+#[case::first_name_only(Some("Aisha"), None, Some("Aisha"))]
+#[case::blank_names(Some(" "), Some(""), None)]
+fn test_apple_user_details_full_name(
+    #[case] first_name: Option<&str>,
+    #[case] last_name: Option<&str>,
+    #[case] expected: Option<&str>,
+) {
+    let user = AppleUser {
+        name: Some(AppleName {
+            first_name: first_name.map(str::to_string),
+            last_name: last_name.map(str::to_string),
+        }),
+        email: Some("aisha@example.com".to_string()),
+    };
+    let user = serde_json::to_string(&user).expect("Failed to serialize user");
+
+    let details: AppleUserDetails = serde_json::from_str(&user).expect("Failed to parse user");
+
+    assert_eq!(details.full_name().as_deref(), expected);
+}
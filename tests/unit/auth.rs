@@ -1,7 +1,11 @@
 use crate::common::get_test_db;
-use merzah::auth::custom_auth::register_user;
-use merzah::models::auth::Platform;
-use merzah::models::{auth::RegistrationFormData, user::Identifier};
+use merzah::auth::custom_auth::{authenticate, register_user};
+use merzah::models::auth::{LoginFormData, Platform};
+use merzah::models::{
+    auth::RegistrationFormData,
+    user::{Identifier, normalize_mobile},
+};
+use rstest::rstest;
 
 #[tokio::test]
 async fn test_register_user_success() -> anyhow::Result<()> {
@@ -56,3 +60,73 @@ async fn test_register_user_duplicate_fail() -> anyhow::Result<()> {
     assert!(result2.is_err(), "Duplicate registration should fail");
     Ok(())
 }
+
+#[rstest]
+#[case::dashes("+91-123-4567890")]
+#[case::parentheses("+(91) 123 4567890")]
+#[case::no_separators("+911234567890")]
+#[tokio::test]
+async fn test_differently_formatted_mobiles_collide_on_registration(
+    #[case] other_format: &str,
+) -> anyhow::Result<()> {
+    let db = get_test_db().await;
+
+    let form = RegistrationFormData::new(
+        "Mobile User".to_string(),
+        Identifier::Mobile("+91 1234567890".to_string()),
+        "password123".to_string(),
+        Platform::Mobile,
+    );
+    let user_id = register_user(form, &db).await?;
+
+    let duplicate = RegistrationFormData::new(
+        "Mobile User".to_string(),
+        Identifier::Mobile(other_format.to_string()),
+        "password123".to_string(),
+        Platform::Mobile,
+    );
+    assert!(
+        duplicate.validate_uniqueness(&db).await.is_err(),
+        "{other_format} should be taken"
+    );
+    assert!(register_user(duplicate, &db).await.is_err());
+
+    let login = LoginFormData {
+        identifier: Identifier::Mobile(other_format.to_string()),
+        password: "password123".to_string(),
+        platform: Platform::Mobile,
+    };
+    assert_eq!(authenticate(login, &db).await?, user_id);
+    Ok(())
+}
+
+#[rstest]
+#[case::spaces("+91 1234567890", "+911234567890")]
+#[case::dashes_and_dots("+91-123.456-7890", "+911234567890")]
+#[case::parentheses(" (123) 456-7890 ", "1234567890")]
+fn test_normalize_mobile(#[case] mobile: &str, #[case] expected: &str) {
+    assert_eq!(normalize_mobile(mobile), expected);
+}
+
+#[tokio::test]
+async fn test_new_web_defaults_to_the_web_platform() -> anyhow::Result<()> {
+    let db = get_test_db().await;
+
+    let form = RegistrationFormData::new_web(
+        "Web Default User".to_string(),
+        Identifier::Email("web_default@example.com".to_string()),
+        "password123".to_string(),
+    );
+    assert_eq!(form.platform, Platform::Web);
+
+    register_user(form, &db).await?;
+    Ok(())
+}
+
+#[rstest]
+#[case::web("web", Some(Platform::Web))]
+#[case::mobile_mixed_case(" Mobile ", Some(Platform::Mobile))]
+#[case::unknown("desktop", None)]
+fn test_platform_from_client_header(#[case] header: &str, #[case] expected: Option<Platform>) {
+    assert_eq!(Platform::from_client_header(header), expected);
+}
@@ -0,0 +1,25 @@
+use futures::executor::block_on;
+use leptos::prelude::{Owner, provide_context};
+use leptos_actix::ResponseOptions;
+use merzah::utils::ssr::current_request;
+
+#[test]
+fn test_current_request_outside_a_request_returns_an_error_response() {
+    Owner::new().with(|| {
+        provide_context(ResponseOptions::default());
+
+        let response =
+            block_on(current_request::<String>()).expect_err("There is no request to extract");
+        assert!(response.data.is_none());
+        assert_eq!(response.error, Some("Internal Server Error".to_string()));
+    });
+}
+
+#[test]
+fn test_current_request_without_response_options_still_returns_an_error_response() {
+    Owner::new().with(|| {
+        let response =
+            block_on(current_request::<String>()).expect_err("There is no request to extract");
+        assert!(response.error.is_some());
+    });
+}
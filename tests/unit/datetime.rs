@@ -0,0 +1,26 @@
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use merzah::models::datetime::{to_datetime, to_fixed_offset};
+use rstest::rstest;
+use surrealdb::Datetime;
+
+#[test]
+fn test_datetime_round_trips_through_fixed_offset() {
+    let datetime = Datetime::from(Utc.with_ymd_and_hms(2024, 3, 10, 18, 30, 15).unwrap());
+
+    let fixed = to_fixed_offset(datetime.clone());
+    assert_eq!(fixed.offset(), &FixedOffset::east_opt(0).unwrap());
+    assert_eq!(to_datetime(fixed), datetime);
+}
+
+#[rstest]
+#[case::utc(0)]
+#[case::ahead_of_utc(5 * 3600 + 1800)]
+#[case::behind_utc(-4 * 3600)]
+fn test_fixed_offset_round_trips_through_datetime(#[case] offset_seconds: i32) {
+    let offset = FixedOffset::east_opt(offset_seconds).unwrap();
+    let date: DateTime<FixedOffset> = offset.with_ymd_and_hms(2024, 11, 3, 1, 30, 0).unwrap();
+
+    let round_tripped = to_fixed_offset(to_datetime(date));
+    assert_eq!(round_tripped, date);
+    assert_eq!(round_tripped.naive_utc(), date.naive_utc());
+}
@@ -0,0 +1,54 @@
+use merzah::utils::email_domain::{EmailDomainPolicy, email_domain, is_valid_email_domain};
+use rstest::rstest;
+
+#[rstest]
+#[case::plain("user@example.com", Some("example.com"))]
+#[case::quoted_at("\"odd@name\"@example.com", Some("example.com"))]
+#[case::missing_domain("user@", None)]
+#[case::missing_at("user.example.com", None)]
+fn test_email_domain(#[case] email: &str, #[case] expected: Option<&str>) {
+    assert_eq!(email_domain(email), expected);
+}
+
+#[rstest]
+#[case::common("gmail.com", true)]
+#[case::subdomain("mail.example.co.uk", true)]
+#[case::hyphenated("my-mosque.org", true)]
+#[case::no_dot("localhost", false)]
+#[case::numeric_tld("example.123", false)]
+#[case::empty_label("example..com", false)]
+#[case::leading_hyphen("-example.com", false)]
+#[case::ip_address("127.0.0.1", false)]
+fn test_is_valid_email_domain(#[case] domain: &str, #[case] expected: bool) {
+    assert_eq!(is_valid_email_domain(domain), expected);
+}
+
+#[rstest]
+#[case::builtin("mailinator.com", true)]
+#[case::uppercase("YopMail.com", true)]
+#[case::subdomain_of_blocked("inbox.mailinator.com", true)]
+#[case::extra_domain("burner.example", true)]
+#[case::regular("gmail.com", false)]
+#[case::lookalike("notmailinator.com", false)]
+fn test_email_domain_policy_blocks_disposable_domains(
+    #[case] domain: &str,
+    #[case] expected: bool,
+) {
+    let policy = EmailDomainPolicy {
+        block_disposable: true,
+        extra_blocked_domains: vec!["burner.example".to_string()],
+    };
+
+    assert_eq!(policy.is_blocked(domain), expected);
+}
+
+#[test]
+fn test_email_domain_policy_can_be_turned_off() {
+    let policy = EmailDomainPolicy {
+        block_disposable: false,
+        extra_blocked_domains: vec!["burner.example".to_string()],
+    };
+
+    assert!(!policy.is_blocked("mailinator.com"));
+    assert!(!policy.is_blocked("burner.example"));
+}
@@ -0,0 +1,94 @@
+use crate::common::get_test_db;
+use merzah::{
+    auth::session::cleanup_expired_sessions,
+    database::expiry::{Expirable, delete_expired},
+    models::{password_reset::PasswordReset, session::Session},
+};
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
+
+async fn remaining_ids(db: &Surreal<Client>, table: &str) -> Vec<RecordId> {
+    db.query("SELECT VALUE id FROM type::table($table)")
+        .bind(("table", table.to_string()))
+        .await
+        .expect("Failed to query the table")
+        .take(0)
+        .expect("Failed to read the ids")
+}
+
+async fn seed(db: &Surreal<Client>) {
+    db.query(
+        r#"
+            CREATE sessions:expired
+                SET user = users:one, session_token = 'expired', expires_at = time::now() - 1h;
+            CREATE sessions:active
+                SET user = users:one, session_token = 'active', expires_at = time::now() + 1h;
+            CREATE password_resets:expired
+                SET user = users:one, token = 'expired', expires_at = time::now() - 1m;
+            CREATE password_resets:older
+                SET user = users:two, token = 'older', expires_at = time::now() - 1d;
+            CREATE password_resets:active
+                SET user = users:two, token = 'active', expires_at = time::now() + 15m;
+        "#,
+    )
+    .await
+    .expect("Failed to insert the records")
+    .check()
+    .expect("Failed to insert the records");
+}
+
+#[tokio::test]
+async fn test_delete_expired_only_touches_expired_rows_of_the_table() {
+    let db = get_test_db().await;
+    seed(&db).await;
+
+    let sessions: Vec<Session> = delete_expired("sessions", &db)
+        .await
+        .expect("Failed to delete the expired sessions");
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].id, RecordId::from(("sessions", "expired")));
+    assert!(sessions[0].is_expired());
+    assert_eq!(
+        remaining_ids(&db, "sessions").await,
+        vec![RecordId::from(("sessions", "active"))]
+    );
+    assert_eq!(remaining_ids(&db, "password_resets").await.len(), 3);
+
+    let mut resets: Vec<PasswordReset> = delete_expired("password_resets", &db)
+        .await
+        .expect("Failed to delete the expired password resets");
+    resets.sort_by_key(|reset| reset.id.to_string());
+    let deleted: Vec<RecordId> = resets.iter().map(|reset| reset.id.clone()).collect();
+    assert_eq!(
+        deleted,
+        vec![
+            RecordId::from(("password_resets", "expired")),
+            RecordId::from(("password_resets", "older")),
+        ]
+    );
+    assert!(resets.iter().all(Expirable::is_expired));
+    assert_eq!(
+        remaining_ids(&db, "password_resets").await,
+        vec![RecordId::from(("password_resets", "active"))]
+    );
+
+    let nothing_left: Vec<Session> = delete_expired("sessions", &db)
+        .await
+        .expect("Failed to delete the expired sessions");
+    assert!(nothing_left.is_empty());
+}
+
+#[tokio::test]
+async fn test_cleanup_expired_sessions_keeps_active_sessions() {
+    let db = get_test_db().await;
+    seed(&db).await;
+
+    cleanup_expired_sessions(&db)
+        .await
+        .expect("Failed to clean up the sessions");
+
+    assert_eq!(
+        remaining_ids(&db, "sessions").await,
+        vec![RecordId::from(("sessions", "active"))]
+    );
+    assert_eq!(remaining_ids(&db, "password_resets").await.len(), 3);
+}
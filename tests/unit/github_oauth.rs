@@ -0,0 +1,44 @@
+use merzah::auth::oauth::github::{GitHubEmail, primary_verified_email};
+use merzah::errors::oauth::OAuthError;
+
+fn email(address: &str, primary: bool, verified: bool) -> GitHubEmail {
+    GitHubEmail {
+        email: address.to_string(),
+        primary,
+        verified,
+    }
+}
+
+#[test]
+fn test_primary_verified_email_is_selected() {
+    let emails = vec![
+        email("secondary@example.com", false, true),
+        email("primary@example.com", true, true),
+    ];
+
+    assert_eq!(
+        primary_verified_email(emails).unwrap(),
+        "primary@example.com"
+    );
+}
+
+#[test]
+fn test_unverified_primary_email_is_rejected() {
+    let emails = vec![
+        email("primary@example.com", true, false),
+        email("secondary@example.com", false, true),
+    ];
+
+    assert!(matches!(
+        primary_verified_email(emails),
+        Err(OAuthError::InvalidResponse)
+    ));
+}
+
+#[test]
+fn test_no_emails_is_rejected() {
+    assert!(matches!(
+        primary_verified_email(Vec::new()),
+        Err(OAuthError::InvalidResponse)
+    ));
+}
@@ -0,0 +1,32 @@
+use merzah::utils::mosque_name::{levenshtein, name_match_score, normalize_mosque_name};
+use rstest::rstest;
+
+#[rstest]
+#[case::strips_prefix("Masjid Al-Noor", "noor")]
+#[case::strips_suffix("Noor Mosque", "noor")]
+#[case::collapses_punctuation("Jama  Masjid-e-Ahle.Hadees", "ahle hadees")]
+#[case::keeps_generic_only_names("Masjid", "masjid")]
+#[case::lowercases("MAKKAH MASJID", "makkah")]
+fn test_normalize_mosque_name(#[case] name: &str, #[case] expected: &str) {
+    assert_eq!(normalize_mosque_name(name), expected);
+}
+
+#[rstest]
+#[case("noor", "noor", 0)]
+#[case("nooor", "noor", 1)]
+#[case("kitten", "sitting", 3)]
+#[case("", "abc", 3)]
+fn test_levenshtein(#[case] a: &str, #[case] b: &str, #[case] expected: usize) {
+    assert_eq!(levenshtein(a, b), expected);
+}
+
+#[rstest]
+#[case::exact("noor", "noor", Some(0))]
+#[case::prefix("noo", "noor", Some(0))]
+#[case::single_typo("nour", "noor", Some(1))]
+#[case::too_many_typos("nrr", "noor", None)]
+#[case::all_words_must_match("ahle hadees", "ahle sunnat", None)]
+#[case::multi_word("hadess ahle", "ahle hadees", Some(1))]
+fn test_name_match_score(#[case] query: &str, #[case] name: &str, #[case] expected: Option<usize>) {
+    assert_eq!(name_match_score(query, name), expected);
+}
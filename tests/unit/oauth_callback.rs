@@ -0,0 +1,52 @@
+use merzah::auth::oauth::google::GoogleProvider;
+use merzah::auth::oauth::helpers::OAuthCallback;
+use merzah::auth::oauth::provider::OAuthProvider;
+use merzah::errors::oauth::OAuthError;
+use merzah::models::auth::Platform;
+use rstest::rstest;
+
+#[test]
+fn test_mobile_login_returns_the_token_without_cookies() {
+    let login = OAuthCallback::login_response(
+        Platform::Mobile,
+        "session-token",
+        "__Host-google_oauth_state",
+        "google",
+    );
+
+    assert_eq!(login.data, "session-token");
+    assert!(login.cookies.is_empty());
+}
+
+#[test]
+fn test_web_login_sets_the_session_cookie_and_clears_the_state() {
+    let login = OAuthCallback::login_response(
+        Platform::Web,
+        "session-token",
+        "__Host-google_oauth_state",
+        "google",
+    );
+
+    assert_eq!(login.data, "Successfully authenticated with google");
+    assert_eq!(login.cookies.len(), 2);
+    assert!(login.cookies[0].starts_with("__Host-session=session-token;"));
+    assert!(login.cookies[0].contains("HttpOnly"));
+    assert!(login.cookies[1].starts_with("__Host-google_oauth_state=;"));
+    assert!(login.cookies[1].contains("Max-Age=0"));
+}
+
+#[rstest]
+#[case::web(Platform::Web, "GOOGLE_REDIRECT_URI")]
+#[case::mobile(Platform::Mobile, "GOOGLE_MOBILE_REDIRECT_URI")]
+fn test_google_redirect_uri_follows_the_platform(
+    #[case] platform: Platform,
+    #[case] expected_var: &str,
+) {
+    let provider = GoogleProvider::for_platform(platform);
+
+    match (provider.redirect_uri(), std::env::var(expected_var)) {
+        (Ok(uri), Ok(expected)) => assert_eq!(uri, expected),
+        (Err(OAuthError::MissingEnvVar(var)), Err(_)) => assert_eq!(var, expected_var),
+        (result, _) => panic!("Unexpected redirect URI for {expected_var}: {result:?}"),
+    }
+}
@@ -0,0 +1,45 @@
+use argon2::{
+    Argon2,
+    password_hash::{PasswordHash, PasswordVerifier},
+};
+use merzah::auth::password::{argon2_with_params, hash_password_with};
+use rstest::rstest;
+
+#[rstest]
+#[case::light(8 * 1024, 1, 1)]
+#[case::heavy(32 * 1024, 3, 2)]
+fn test_hash_with_custom_params_verifies(
+    #[case] memory_kib: u32,
+    #[case] iterations: u32,
+    #[case] parallelism: u32,
+) {
+    let argon2 =
+        argon2_with_params(memory_kib, iterations, parallelism).expect("The params are valid");
+    let password_hash =
+        hash_password_with(&argon2, "thisisasecret").expect("Failed to hash the password");
+    let parsed_hash = PasswordHash::new(&password_hash).expect("Failed to parse the hash");
+
+    assert!(password_hash.contains(&format!("m={memory_kib},t={iterations},p={parallelism}")));
+    assert!(
+        argon2
+            .verify_password(b"thisisasecret", &parsed_hash)
+            .is_ok()
+    );
+    assert!(
+        argon2
+            .verify_password(b"notthesecret", &parsed_hash)
+            .is_err()
+    );
+    // The params are read from the hash, so other settings still verify it.
+    assert!(
+        Argon2::default()
+            .verify_password(b"thisisasecret", &parsed_hash)
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_invalid_params_are_rejected() {
+    assert!(argon2_with_params(8 * 1024, 0, 1).is_err());
+    assert!(argon2_with_params(8 * 1024, 1, 0).is_err());
+}
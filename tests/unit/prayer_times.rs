@@ -0,0 +1,73 @@
+use chrono::NaiveTime;
+use merzah::models::mosque::{PrayerTimes, PrayerTimesUpdate};
+use rstest::rstest;
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+fn ordered_times() -> PrayerTimes {
+    PrayerTimes {
+        fajr: time(5, 15),
+        dhuhr: time(13, 0),
+        asr: time(16, 45),
+        maghrib: time(19, 30),
+        isha: time(21, 0),
+        jummah: time(13, 30),
+    }
+}
+
+#[rstest]
+#[case::ordered(ordered_times())]
+#[case::jummah_before_fajr(PrayerTimes { jummah: time(4, 0), ..ordered_times() })]
+#[case::jummah_after_isha(PrayerTimes { jummah: time(23, 0), ..ordered_times() })]
+fn test_prayer_times_in_order_are_accepted(#[case] times: PrayerTimes) {
+    assert_eq!(times.check_order(), Ok(()));
+}
+
+#[rstest]
+#[case::fajr_after_dhuhr(
+    PrayerTimes { fajr: time(13, 30), ..ordered_times() },
+    "fajr must be before dhuhr"
+)]
+#[case::dhuhr_after_asr(
+    PrayerTimes { dhuhr: time(17, 0), ..ordered_times() },
+    "dhuhr must be before asr"
+)]
+#[case::asr_equal_to_maghrib(
+    PrayerTimes { asr: time(19, 30), ..ordered_times() },
+    "asr must be before maghrib"
+)]
+#[case::isha_before_maghrib(
+    PrayerTimes { isha: time(19, 0), ..ordered_times() },
+    "maghrib must be before isha"
+)]
+fn test_prayer_times_out_of_order_are_rejected(#[case] times: PrayerTimes, #[case] expected: &str) {
+    assert_eq!(times.check_order(), Err(expected.to_string()));
+}
+
+#[rstest]
+#[case::both_ordered(Some(ordered_times()), Some(ordered_times()), Ok(()))]
+#[case::nothing_to_check(None, None, Ok(()))]
+#[case::bad_adhan(
+    Some(PrayerTimes { fajr: time(14, 0), ..ordered_times() }),
+    None,
+    Err("adhan_times: fajr must be before dhuhr")
+)]
+#[case::bad_jamat(
+    Some(ordered_times()),
+    Some(PrayerTimes { isha: time(18, 0), ..ordered_times() }),
+    Err("jamat_times: maghrib must be before isha")
+)]
+fn test_prayer_times_update_names_the_invalid_times(
+    #[case] adhan_times: Option<PrayerTimes>,
+    #[case] jamat_times: Option<PrayerTimes>,
+    #[case] expected: Result<(), &str>,
+) {
+    let update = PrayerTimesUpdate {
+        adhan_times,
+        jamat_times,
+    };
+
+    assert_eq!(update.check_order(), expected.map_err(str::to_string));
+}
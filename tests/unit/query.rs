@@ -0,0 +1,96 @@
+use crate::common::get_test_db;
+use merzah::{
+    database::query::{TRANSACTION_RESULT, take_checked},
+    errors::app::AppError,
+};
+use rstest::rstest;
+use surrealdb::{Error, error::Api};
+
+#[tokio::test]
+async fn test_take_checked_returns_the_result_at_the_index() {
+    let db = get_test_db().await;
+    let response = db
+        .query("RETURN 1; RETURN 'two';")
+        .await
+        .expect("Failed to run the query");
+
+    let value: Option<String> = take_checked(response, 1).expect("Failed to take the result");
+    assert_eq!(value.as_deref(), Some("two"));
+}
+
+#[tokio::test]
+async fn test_take_checked_reports_failed_statements() {
+    let db = get_test_db().await;
+    let response = db
+        .query("RETURN 1; THROW 'boom';")
+        .await
+        .expect("Failed to run the query");
+
+    let result = take_checked::<Option<i64>>(response, 0);
+    assert!(matches!(result, Err(AppError::QueryFailed(_))));
+}
+
+#[tokio::test]
+async fn test_take_checked_rejects_an_index_past_the_last_statement() {
+    let db = get_test_db().await;
+    let response = db
+        .query("RETURN 1; RETURN 2;")
+        .await
+        .expect("Failed to run the query");
+
+    let result = take_checked::<Option<i64>>(response, 2);
+    assert!(matches!(
+        result,
+        Err(AppError::MissingStatement {
+            index: 2,
+            statements: 2
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_take_checked_reports_results_of_the_wrong_type() {
+    let db = get_test_db().await;
+    let response = db
+        .query("RETURN 'not a number';")
+        .await
+        .expect("Failed to run the query");
+
+    let result = take_checked::<Option<i64>>(response, 0);
+    assert!(matches!(
+        result,
+        Err(AppError::UnexpectedResult { index: 0, .. })
+    ));
+}
+
+#[rstest]
+#[case::one_statement("LET $event = 'event'; $event")]
+#[case::extra_statement("LET $other = 'other'; LET $event = 'event'; $event")]
+#[tokio::test]
+async fn test_transaction_result_survives_added_statements(#[case] statements: &str) {
+    let db = get_test_db().await;
+    let transaction = format!(
+        "BEGIN TRANSACTION;
+        RETURN {{ {statements} }};
+        COMMIT TRANSACTION;"
+    );
+    let response = db
+        .query(transaction)
+        .await
+        .expect("Failed to run the transaction");
+
+    let value: Option<String> =
+        take_checked(response, TRANSACTION_RESULT).expect("Failed to take the result");
+    assert_eq!(value.as_deref(), Some("event"));
+}
+
+#[rstest]
+#[case::conflict(
+    "Failed to commit transaction due to a read or write conflict. This transaction can be retried",
+    true
+)]
+#[case::failed_statement("An error occurred: boom", false)]
+fn test_only_transaction_conflicts_are_retryable(#[case] message: &str, #[case] expected: bool) {
+    let error = AppError::QueryFailed(Error::Api(Api::Query(message.to_string())));
+    assert_eq!(error.is_transaction_conflict(), expected);
+}
@@ -0,0 +1,79 @@
+use merzah::models::user::Identifier;
+use merzah::utils::rate_limit::{RateLimiter, identifier_rate_limit_key};
+use rstest::rstest;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_rate_limiter_blocks_after_max_requests() {
+    let limiter = RateLimiter::new(3, Duration::from_secs(60));
+    let now = Instant::now();
+
+    for _ in 0..3 {
+        assert!(limiter.check_at("10.0.0.1", now).is_ok());
+    }
+
+    let retry_after = limiter
+        .check_at("10.0.0.1", now + Duration::from_secs(20))
+        .expect_err("The fourth request should be throttled");
+    assert_eq!(retry_after, Duration::from_secs(40));
+}
+
+#[test]
+fn test_rate_limiter_tracks_keys_separately() {
+    let limiter = RateLimiter::new(1, Duration::from_secs(60));
+    let now = Instant::now();
+
+    assert!(limiter.check_at("10.0.0.1", now).is_ok());
+    assert!(limiter.check_at("10.0.0.1", now).is_err());
+    assert!(limiter.check_at("10.0.0.2", now).is_ok());
+}
+
+#[test]
+fn test_rate_limiter_allows_requests_once_window_passes() {
+    let limiter = RateLimiter::new(2, Duration::from_secs(60));
+    let now = Instant::now();
+
+    assert!(limiter.check_at("10.0.0.1", now).is_ok());
+    assert!(
+        limiter
+            .check_at("10.0.0.1", now + Duration::from_secs(30))
+            .is_ok()
+    );
+    assert!(
+        limiter
+            .check_at("10.0.0.1", now + Duration::from_secs(59))
+            .is_err()
+    );
+    assert!(
+        limiter
+            .check_at("10.0.0.1", now + Duration::from_secs(60))
+            .is_ok()
+    );
+}
+
+#[rstest]
+#[case::email_case(
+    Identifier::Email(" Someone@Example.com".to_string()),
+    Identifier::Email("someone@example.com".to_string())
+)]
+#[case::mobile_formatting(
+    Identifier::Mobile("+91 123-456-7890".to_string()),
+    Identifier::Mobile("+911234567890".to_string())
+)]
+fn test_identifier_rate_limit_key_ignores_formatting(
+    #[case] typed: Identifier,
+    #[case] stored: Identifier,
+) {
+    assert_eq!(
+        identifier_rate_limit_key(&typed),
+        identifier_rate_limit_key(&stored)
+    );
+}
+
+#[test]
+fn test_identifier_rate_limit_key_tells_identifier_types_apart() {
+    assert_ne!(
+        identifier_rate_limit_key(&Identifier::Email("1234567890".to_string())),
+        identifier_rate_limit_key(&Identifier::Mobile("1234567890".to_string()))
+    );
+}
@@ -1,6 +1,12 @@
-use chrono::{Datelike, Duration, FixedOffset, TimeZone, Utc};
-use merzah::models::events::EventRecurrence;
-use merzah::services::recurrence::calculate_next_date;
+use chrono::{DateTime, Datelike, Duration, FixedOffset, TimeZone, Timelike, Utc};
+use chrono_tz::{America::Detroit, Tz};
+use merzah::models::events::{
+    CreateEvent, EventCategory, EventRecord, EventRecurrence, Interval, RecurrenceUnit,
+};
+use merzah::services::recurrence::{
+    calculate_next_date, calculate_next_date_in_zone, end_date_after_occurrences,
+    end_date_after_occurrences_in_zone, next_occurrences,
+};
 use rstest::rstest;
 
 #[test]
@@ -199,6 +205,46 @@ fn test_calculate_next_date_monthly_30_day_month_to_31_day() {
     assert_eq!(next.date_naive().day(), 30);
 }
 
+#[rstest]
+#[case::every_ten_days(RecurrenceUnit::Day, 10, (2024, 1, 25), (2024, 2, 4))]
+#[case::every_three_weeks(RecurrenceUnit::Week, 3, (2024, 1, 1), (2024, 1, 22))]
+#[case::every_two_months(RecurrenceUnit::Month, 2, (2024, 1, 31), (2024, 3, 31))]
+#[case::every_two_months_clamped(RecurrenceUnit::Month, 2, (2023, 12, 31), (2024, 2, 29))]
+#[case::every_two_years(RecurrenceUnit::Year, 2, (2024, 2, 29), (2026, 2, 28))]
+fn test_calculate_next_date_custom(
+    #[case] unit: RecurrenceUnit,
+    #[case] every: u32,
+    #[case] start: (i32, u32, u32),
+    #[case] expected: (i32, u32, u32),
+) {
+    let (year, month, day) = start;
+    let (next_year, next_month, next_day) = expected;
+    let dt = Utc
+        .with_ymd_and_hms(year, month, day, 10, 0, 0)
+        .unwrap()
+        .with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let expected = Utc
+        .with_ymd_and_hms(next_year, next_month, next_day, 10, 0, 0)
+        .unwrap()
+        .with_timezone(&FixedOffset::east_opt(0).unwrap());
+
+    let next = calculate_next_date(dt, EventRecurrence::Custom { unit, every }).unwrap();
+    assert_eq!(next, expected);
+}
+
+#[test]
+fn test_calculate_next_date_custom_every_zero() {
+    let dt = Utc
+        .with_ymd_and_hms(2024, 1, 1, 10, 0, 0)
+        .unwrap()
+        .with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let pattern = EventRecurrence::Custom {
+        unit: RecurrenceUnit::Week,
+        every: 0,
+    };
+    assert!(calculate_next_date(dt, pattern).is_none());
+}
+
 #[rstest]
 #[case::monday(2024, 1, 1, 5, "Monday -> Saturday")]
 #[case::tuesday(2024, 1, 2, 4, "Tuesday -> Saturday")]
@@ -221,3 +267,142 @@ fn test_calculate_next_date_weekends(
     let next = calculate_next_date(dt, EventRecurrence::Weekends).unwrap();
     assert_eq!(next, dt + Duration::days(expected_days), "{}", description);
 }
+
+#[rstest]
+#[case(EventRecurrence::Weekly, 8, Duration::weeks(7))]
+#[case(EventRecurrence::Daily, 3, Duration::days(2))]
+#[case(EventRecurrence::Biweekly, 1, Duration::zero())]
+fn test_end_date_after_occurrences(
+    #[case] pattern: EventRecurrence,
+    #[case] occurrences: u32,
+    #[case] expected_offset: Duration,
+) {
+    let dt = Utc
+        .with_ymd_and_hms(2024, 1, 1, 10, 0, 0)
+        .unwrap()
+        .with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let end = end_date_after_occurrences(dt, pattern, occurrences).unwrap();
+    assert_eq!(end, dt + expected_offset);
+}
+
+#[test]
+fn test_end_date_after_zero_occurrences() {
+    let dt = Utc
+        .with_ymd_and_hms(2024, 1, 1, 10, 0, 0)
+        .unwrap()
+        .with_timezone(&FixedOffset::east_opt(0).unwrap());
+    assert!(end_date_after_occurrences(dt, EventRecurrence::Weekly, 0).is_none());
+}
+
+#[test]
+fn test_event_record_ends_a_counted_series_on_its_last_occurrence() {
+    let dt = Utc
+        .with_ymd_and_hms(2030, 1, 7, 18, 0, 0)
+        .unwrap()
+        .with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let create = CreateEvent {
+        title: "Weekly Halaqa".to_string(),
+        description: "A weekly study circle".to_string(),
+        category: EventCategory::Lecture,
+        date: dt,
+        mosque: "mosques:counted".to_string(),
+        speaker: None,
+        recurrence_pattern: Some(EventRecurrence::Weekly),
+        recurrence_duration: Some(Interval::Count(8)),
+        timezone: None,
+        rsvp_deadline: None,
+        capacity: None,
+    };
+
+    let record = EventRecord::try_from(create).unwrap();
+    assert_eq!(record.recurrence_end_date, Some(dt + Duration::weeks(7)));
+    assert_eq!(record.recurrence_count, Some(8));
+}
+
+fn detroit(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<FixedOffset> {
+    Detroit
+        .with_ymd_and_hms(2024, month, day, hour, minute, 0)
+        .earliest()
+        .unwrap()
+        .fixed_offset()
+}
+
+#[rstest]
+#[case::into_daylight_saving(detroit(3, 8, 18, 0), EventRecurrence::Weekly, (3, 15, 18, 0), -4)]
+#[case::out_of_daylight_saving(detroit(11, 1, 18, 0), EventRecurrence::Weekly, (11, 8, 18, 0), -5)]
+#[case::monthly_across_the_change(detroit(2, 20, 18, 0), EventRecurrence::Monthly, (3, 20, 18, 0), -4)]
+#[case::skipped_time_moves_past_the_gap(detroit(3, 9, 2, 30), EventRecurrence::Daily, (3, 10, 3, 30), -4)]
+#[case::repeated_time_takes_the_first(detroit(11, 2, 1, 30), EventRecurrence::Daily, (11, 3, 1, 30), -4)]
+fn test_calculate_next_date_in_zone_keeps_the_local_time(
+    #[case] dt: DateTime<FixedOffset>,
+    #[case] pattern: EventRecurrence,
+    #[case] expected_local: (u32, u32, u32, u32),
+    #[case] expected_offset_hours: i32,
+) {
+    let next = calculate_next_date_in_zone(dt, pattern, Detroit).unwrap();
+    let local = next.with_timezone(&Detroit);
+
+    let (month, day, hour, minute) = expected_local;
+    assert_eq!(
+        (local.month(), local.day(), local.hour(), local.minute()),
+        (month, day, hour, minute)
+    );
+    assert_eq!(
+        next.offset().local_minus_utc(),
+        expected_offset_hours * 3600
+    );
+}
+
+#[test]
+fn test_fixed_offset_drifts_across_daylight_saving() {
+    let next = calculate_next_date(detroit(3, 8, 18, 0), EventRecurrence::Weekly).unwrap();
+    assert_eq!(next.with_timezone(&Detroit).hour(), 19);
+}
+
+#[test]
+fn test_end_date_after_occurrences_in_zone_keeps_the_local_time() {
+    let zone: Tz = "America/Detroit".parse().unwrap();
+    let end = end_date_after_occurrences_in_zone(
+        detroit(3, 1, 18, 0),
+        EventRecurrence::Weekly,
+        3,
+        Some(zone),
+    )
+    .unwrap();
+    assert_eq!(end, detroit(3, 15, 18, 0));
+}
+
+#[rstest]
+#[case::weekly(EventRecurrence::Weekly, vec![(1, 5), (1, 12), (1, 19), (1, 26)])]
+#[case::monthly(EventRecurrence::Monthly, vec![(1, 5), (2, 5), (3, 5), (4, 5)])]
+fn test_next_occurrences_preview(
+    #[case] pattern: EventRecurrence,
+    #[case] expected: Vec<(u32, u32)>,
+) {
+    let dt = Utc
+        .with_ymd_and_hms(2024, 1, 5, 18, 0, 0)
+        .unwrap()
+        .with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let occurrences = next_occurrences(dt, pattern, None, 4);
+    let dates: Vec<(u32, u32)> = occurrences.iter().map(|d| (d.month(), d.day())).collect();
+    assert_eq!(dates, expected);
+}
+
+#[rstest]
+#[case::stops_at_the_end_date(Duration::days(15), 10, 3)]
+#[case::includes_an_occurrence_on_the_end_date(Duration::weeks(2), 10, 3)]
+#[case::count_comes_first(Duration::days(365), 2, 2)]
+#[case::zero_count(Duration::days(365), 0, 0)]
+fn test_next_occurrences_truncation(
+    #[case] until_end: Duration,
+    #[case] count: usize,
+    #[case] expected_len: usize,
+) {
+    let dt = Utc
+        .with_ymd_and_hms(2024, 1, 5, 18, 0, 0)
+        .unwrap()
+        .with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let occurrences = next_occurrences(dt, EventRecurrence::Weekly, Some(dt + until_end), count);
+    assert_eq!(occurrences.len(), expected_len);
+    assert!(occurrences.iter().all(|date| *date <= dt + until_end));
+}
@@ -0,0 +1,22 @@
+use merzah::models::redacted::Redacted;
+
+#[test]
+fn test_redacted_hides_value_in_debug_and_display() {
+    let token = Redacted::new("super_secret_session_token".to_string());
+
+    assert_eq!(format!("{:?}", token), "***");
+    assert_eq!(format!("{}", token), "***");
+    assert_eq!(token.expose(), "super_secret_session_token");
+}
+
+#[test]
+fn test_redacted_serializes_transparently() {
+    let token: Redacted<String> = "super_secret_session_token".to_string().into();
+
+    let json = serde_json::to_string(&token).expect("Failed to serialize token");
+    assert_eq!(json, "\"super_secret_session_token\"");
+
+    let parsed: Redacted<String> =
+        serde_json::from_str(&json).expect("Failed to deserialize token");
+    assert_eq!(parsed.into_inner(), "super_secret_session_token");
+}
@@ -0,0 +1,28 @@
+use merzah::jobs::{
+    event_rotation::HOURLY, schedule::schedule_or_default, token_cleanup::DEFAULT_CLEANUP_SCHEDULE,
+};
+use rstest::rstest;
+
+#[rstest]
+#[case::unset(None, HOURLY)]
+#[case::valid(Some("0 */15 * * * *"), "0 */15 * * * *")]
+#[case::invalid(Some("not a cron"), HOURLY)]
+#[case::out_of_range(Some("0 0 25 * * *"), HOURLY)]
+fn test_schedule_or_default(#[case] schedule: Option<&str>, #[case] expected: &str) {
+    assert_eq!(
+        schedule_or_default("ROTATION_CRON", schedule.map(str::to_string), HOURLY),
+        expected
+    );
+}
+
+#[test]
+fn test_invalid_token_cleanup_schedule_keeps_its_own_default() {
+    assert_eq!(
+        schedule_or_default(
+            "TOKEN_CLEANUP_CRON",
+            Some("every half hour".to_string()),
+            DEFAULT_CLEANUP_SCHEDULE
+        ),
+        DEFAULT_CLEANUP_SCHEDULE
+    );
+}
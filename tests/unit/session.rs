@@ -1,6 +1,7 @@
 use crate::common::get_test_db;
 use merzah::auth::custom_auth::register_user;
-use merzah::auth::session::{create_session, delete_session, get_user_by_session};
+use merzah::auth::session::{create_session, delete_session, get_user_by_session, resolve_session};
+use merzah::database::instrumented::CountingDb;
 use merzah::models::auth::Platform;
 use merzah::models::{auth::RegistrationFormData, user::Identifier};
 
@@ -53,3 +54,23 @@ async fn test_delete_non_existent_session_token_should_be_successful() -> anyhow
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_fresh_session_is_not_refreshed() -> anyhow::Result<()> {
+    let db = get_test_db().await;
+
+    let form = RegistrationFormData::new(
+        "Fresh Session User".to_string(),
+        Identifier::Email("fresh_session@example.com".to_string()),
+        "password123".to_string(),
+        Platform::Web,
+    );
+    let user_id = register_user(form, &db).await?;
+    let token = create_session(user_id.clone(), &db).await?;
+
+    let session = resolve_session(&token, &CountingDb::uncounted(db.clone())).await?;
+    assert_eq!(session.user.id, user_id);
+    assert!(!session.refreshed);
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use merzah::models::user::User;
+use surrealdb::{Datetime, RecordId};
+
+#[test]
+fn test_user_serialization_omits_password_hash() {
+    let user = User {
+        id: RecordId::from(("users", "serialization_user")),
+        created_at: Datetime::default(),
+        display_name: "Serialization User".to_string(),
+        password_hash: "$argon2id$v=19$secret_hash".to_string(),
+        role: "regular".to_string(),
+        updated_at: Datetime::default(),
+        verified: false,
+    };
+
+    let json = serde_json::to_string(&user).expect("Failed to serialize user");
+
+    assert!(!json.contains("password_hash"));
+    assert!(!json.contains("secret_hash"));
+    assert!(json.contains("Serialization User"));
+}
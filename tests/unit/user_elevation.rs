@@ -1,18 +1,19 @@
 use crate::common::get_test_db;
 use merzah::auth::custom_auth::register_user;
 use merzah::{
+    errors::user_elevation::UserElevationError,
     models::{
         auth::{Platform, RegistrationFormData},
-        user::{Identifier, User},
+        user::{Identifier, Role, User},
     },
-    utils::user_elevation::elevate_user,
+    utils::user_elevation::{demote_to_regular, elevate_user},
 };
 use rstest::rstest;
 use serde::Serialize;
-use surrealdb::{Surreal, engine::remote::ws::Client};
+use surrealdb::{RecordId, Surreal, engine::remote::ws::Client};
 
 #[derive(Serialize)]
-struct Role {
+struct RoleUpdate {
     role: String,
 }
 
@@ -34,7 +35,7 @@ async fn create_user(db: &Surreal<Client>, name: &str, email: &str, role: Option
         // Manually update role for setup
         let _: Option<User> = db
             .update(user_id.clone())
-            .merge(Role {
+            .merge(RoleUpdate {
                 role: r.to_string(),
             })
             .await
@@ -45,26 +46,47 @@ async fn create_user(db: &Surreal<Client>, name: &str, email: &str, role: Option
 }
 
 #[rstest]
-#[case::success("app_admin", "regular", "mosque_supervisor", true, None)]
+#[case::success("app_admin", "regular", Role::MosqueSupervisor, true, None)]
 #[case::unauthorized_requester(
     "regular",
     "regular",
-    "mosque_supervisor",
+    Role::MosqueSupervisor,
     false,
     Some("The user attempting the elevation is not authorized to elevate")
 )]
 #[case::already_elevated(
     "app_admin",
     "mosque_supervisor",
-    "mosque_supervisor",
+    Role::MosqueSupervisor,
     false,
     Some("The user is already an mosque supervisor")
 )]
+#[case::app_admin_target(
+    "app_admin",
+    "regular",
+    Role::AppAdmin,
+    false,
+    Some("Users can't be elevated to app_admin this way")
+)]
+#[case::regular_target(
+    "app_admin",
+    "mosque_supervisor",
+    Role::Regular,
+    false,
+    Some("Users can't be elevated to regular this way")
+)]
+#[case::educator_target(
+    "app_admin",
+    "educator",
+    Role::MosqueSupervisor,
+    false,
+    Some("The user's educator role can't be changed this way")
+)]
 #[tokio::test]
 async fn test_elevate_user(
     #[case] admin_role: &str,
     #[case] target_user_initial_role: &str,
-    #[case] elevation_degree: &str,
+    #[case] elevation_degree: Role,
     #[case] should_succeed: bool,
     #[case] expected_error_part: Option<&str>,
 ) {
@@ -81,7 +103,7 @@ async fn test_elevate_user(
     let result = elevate_user(
         admin.id.clone(),
         target_user.id.clone(),
-        elevation_degree.to_string(),
+        elevation_degree,
         &db,
     )
     .await;
@@ -94,12 +116,12 @@ async fn test_elevate_user(
         );
         assert_eq!(
             result.unwrap(),
-            format!("Elevated the user to {}", elevation_degree)
+            format!("Elevated the user to {}", elevation_degree.as_str())
         );
 
         // Verify DB update
         let updated_user: User = db.select(target_user.id).await.unwrap().unwrap();
-        assert_eq!(updated_user.role, elevation_degree);
+        assert_eq!(updated_user.role, elevation_degree.as_str());
     } else {
         assert!(result.is_err(), "Elevation should have failed");
         let err_msg = result.unwrap_err().to_string();
@@ -114,20 +136,55 @@ async fn test_elevate_user(
     }
 }
 
+#[rstest]
+#[case("banana")]
+#[case("App_Admin")]
+#[case("")]
+fn test_unknown_role_is_rejected(#[case] role: &str) {
+    assert_eq!(Role::parse(role), None);
+    let deserialized: Result<Role, _> = serde_json::from_value(serde_json::Value::from(role));
+    assert!(deserialized.is_err());
+}
+
 #[tokio::test]
-async fn test_elevate_user_target_not_found() {
+async fn test_unknown_stored_role_is_not_privileged() {
+    let db = get_test_db().await;
+    let user = create_user(&db, "Banana", "banana@test.com", Some("banana")).await;
+
+    assert_eq!(user.parsed_role(), None);
+    assert!(!user.is_app_admin());
+    assert!(!user.is_mosque_supervisor());
+}
+
+#[tokio::test]
+async fn test_elevate_user_rejects_self_elevation() {
     let db = get_test_db().await;
     let admin = create_user(&db, "Admin", "admin@test.com", Some("app_admin")).await;
-    let fake_user_id = surrealdb::RecordId::from(("users", "nonexistent"));
 
     let result = elevate_user(
         admin.id.clone(),
-        fake_user_id,
-        "mosque_supervisor".to_string(),
+        admin.id.clone(),
+        Role::MosqueSupervisor,
         &db,
     )
     .await;
 
+    assert!(matches!(
+        result,
+        Err(UserElevationError::SelfElevationNotAllowed)
+    ));
+    let admin_after: User = db.select(admin.id).await.unwrap().unwrap();
+    assert_eq!(admin_after.role, "app_admin");
+}
+
+#[tokio::test]
+async fn test_elevate_user_target_not_found() {
+    let db = get_test_db().await;
+    let admin = create_user(&db, "Admin", "admin@test.com", Some("app_admin")).await;
+    let fake_user_id = surrealdb::RecordId::from(("users", "nonexistent"));
+
+    let result = elevate_user(admin.id.clone(), fake_user_id, Role::MosqueSupervisor, &db).await;
+
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(
@@ -146,7 +203,7 @@ async fn test_elevate_user_admin_not_found() {
     let result = elevate_user(
         fake_admin_id,
         target_user.id.clone(),
-        "mosque_supervisor".to_string(),
+        Role::MosqueSupervisor,
         &db,
     )
     .await;
@@ -159,3 +216,115 @@ async fn test_elevate_user_admin_not_found() {
             || err.to_string().contains("AdminNotFound")
     );
 }
+
+async fn handles_count(db: &Surreal<Client>, user: &RecordId) -> usize {
+    let handles: Vec<RecordId> = db
+        .query("SELECT VALUE id FROM handles WHERE in = $user")
+        .bind(("user", user.clone()))
+        .await
+        .expect("Query failed")
+        .take(0)
+        .expect("Take failed");
+    handles.len()
+}
+
+#[rstest]
+#[case::keeps_admin_rights("app_admin", "mosque_supervisor", false, None, 1)]
+#[case::removes_admin_rights("app_admin", "mosque_supervisor", true, None, 0)]
+#[case::unauthorized_requester(
+    "mosque_supervisor",
+    "mosque_supervisor",
+    true,
+    Some("The user attempting the elevation is not authorized to elevate"),
+    1
+)]
+#[case::app_admins_are_not_demoted(
+    "app_admin",
+    "app_admin",
+    true,
+    Some("The user attempting the elevation is not authorized to elevate"),
+    1
+)]
+#[tokio::test]
+async fn test_demote_user(
+    #[case] admin_role: &str,
+    #[case] target_user_initial_role: &str,
+    #[case] remove_admin_rights: bool,
+    #[case] expected_error_part: Option<&str>,
+    #[case] expected_handles: usize,
+) {
+    let db = get_test_db().await;
+    let admin = create_user(&db, "Admin", "admin@test.com", Some(admin_role)).await;
+    let target_user = create_user(
+        &db,
+        "Target",
+        "target@test.com",
+        Some(target_user_initial_role),
+    )
+    .await;
+
+    db.query("CREATE mosques:demoted_mosque SET name = 'Demoted Mosque', location = (8.0, 9.0)")
+        .await
+        .expect("Failed to create mosque")
+        .check()
+        .expect("Failed to create mosque");
+    db.query("RELATE $user -> handles -> mosques:demoted_mosque SET granted_by = $admin")
+        .bind(("user", target_user.id.clone()))
+        .bind(("admin", admin.id.clone()))
+        .await
+        .expect("Failed to grant the admin")
+        .check()
+        .expect("Failed to grant the admin");
+
+    let result = demote_to_regular(
+        admin.id.clone(),
+        target_user.id.clone(),
+        remove_admin_rights,
+        &db,
+    )
+    .await;
+
+    let updated_user: User = db.select(target_user.id.clone()).await.unwrap().unwrap();
+    match expected_error_part {
+        None => {
+            assert_eq!(
+                result.expect("Demotion should have succeeded"),
+                "Demoted the user to regular"
+            );
+            assert_eq!(updated_user.role, "regular");
+        }
+        Some(expected_part) => {
+            let err_msg = result.expect_err("Demotion should have failed").to_string();
+            assert!(
+                err_msg.contains(expected_part),
+                "Error message '{}' did not contain expected part '{}'",
+                err_msg,
+                expected_part
+            );
+            assert_eq!(updated_user.role, target_user_initial_role);
+        }
+    }
+    assert_eq!(handles_count(&db, &target_user.id).await, expected_handles);
+}
+
+#[tokio::test]
+async fn test_demote_user_target_not_found() {
+    let db = get_test_db().await;
+    let admin = create_user(&db, "Admin", "admin@test.com", Some("app_admin")).await;
+
+    let result = demote_to_regular(
+        admin.id.clone(),
+        RecordId::from(("users", "nonexistent")),
+        false,
+        &db,
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("The user to be elevated was not found")
+    );
+}
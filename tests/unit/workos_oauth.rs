@@ -0,0 +1,28 @@
+use merzah::auth::oauth::{provider::ProviderUser, workos::WorkosProfile};
+use rstest::rstest;
+
+fn profile(first_name: Option<&str>, last_name: Option<&str>) -> WorkosProfile {
+    WorkosProfile {
+        id: "prof_01HXYZ".to_string(),
+        email: "member@example.org".to_string(),
+        first_name: first_name.map(str::to_string),
+        last_name: last_name.map(str::to_string),
+    }
+}
+
+#[rstest]
+#[case::full_name(Some("Armaan"), Some("Ali"), Some("Armaan Ali"))]
+#[case::first_name_only(Some("Armaan"), None, Some("Armaan"))]
+#[case::blank_names(Some(" "), None, None)]
+#[case::no_names(None, None, None)]
+fn test_workos_profile_to_provider_user(
+    #[case] first_name: Option<&str>,
+    #[case] last_name: Option<&str>,
+    #[case] expected_name: Option<&str>,
+) {
+    let user = ProviderUser::from(profile(first_name, last_name));
+
+    assert_eq!(user.id, "prof_01HXYZ");
+    assert_eq!(user.email, "member@example.org");
+    assert_eq!(user.name.as_deref(), expected_name);
+}